@@ -12,6 +12,10 @@
 //! This module contains structures that wrap unsigned integer parameters of
 //! concrete, like the ciphertext dimension or the polynomial degree.
 //!
+//! # Parameter sets
+//! This module contains a registry of named sets of cryptographic parameters,
+//! which can be looked up by name and validated.
+//!
 //! # Numeric
 //! This module contains types and traits used to handle numeric types in a
 //! unified manner in concrete: it defines methods that can be used on custom
@@ -23,4 +27,5 @@
 pub mod dispersion;
 pub mod key_kinds;
 pub mod numeric;
+pub mod parameter_sets;
 pub mod parameters;
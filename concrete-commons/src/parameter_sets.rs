@@ -0,0 +1,350 @@
+//! A registry of named sets of cryptographic parameters.
+//!
+//! A [`ParameterSet`] gathers all the parameters needed to evaluate the usual atomic pattern of
+//! TFHE: a keyswitch from the GLWE secret key seen as an LWE secret key to a smaller LWE secret
+//! key, followed by a programmable bootstrapping. The sets of the registry can be looked up by
+//! name with [`find_parameter_set`], and, with the `serde_serialize` feature, any set can be
+//! loaded from a configuration file.
+//!
+//! # Example
+//!
+//! ```
+//! use concrete_commons::parameter_sets::{find_parameter_set, TFHE_LIB_PARAMETERS};
+//! use concrete_commons::parameters::PolynomialSize;
+//! let parameters = find_parameter_set("TFHE_LIB_PARAMETERS").unwrap();
+//! assert_eq!(parameters, &TFHE_LIB_PARAMETERS);
+//! assert_eq!(parameters.polynomial_size, PolynomialSize(1024));
+//! assert!(parameters.validate::<u32>().is_ok());
+//! ```
+use crate::dispersion::{DispersionParameter, StandardDev};
+use crate::numeric::UnsignedInteger;
+use crate::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DecompositionParameters, GlweDimension,
+    LweDimension, ParameterError, PolynomialSize,
+};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A set of parameters for a keyswitch followed by a programmable bootstrapping.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct ParameterSet {
+    /// The dimension of the LWE secret key, i.e. of the inputs of the bootstraps.
+    pub lwe_dimension: LweDimension,
+    /// The dimension of the GLWE secret key.
+    pub glwe_dimension: GlweDimension,
+    /// The size of the polynomials of the GLWE secret key.
+    pub polynomial_size: PolynomialSize,
+    /// The standard deviation of the noise of the encryptions under the LWE secret key, e.g. in
+    /// the keyswitching key.
+    pub lwe_noise: StandardDev,
+    /// The standard deviation of the noise of the encryptions under the GLWE secret key, e.g. in
+    /// the bootstrapping key.
+    pub glwe_noise: StandardDev,
+    /// The base logarithm of the decomposition of the bootstrapping key.
+    pub pbs_base_log: DecompositionBaseLog,
+    /// The number of levels of the decomposition of the bootstrapping key.
+    pub pbs_level: DecompositionLevelCount,
+    /// The base logarithm of the decomposition of the keyswitching key.
+    pub ks_base_log: DecompositionBaseLog,
+    /// The number of levels of the decomposition of the keyswitching key.
+    pub ks_level: DecompositionLevelCount,
+    /// The number of bits of the messages.
+    pub message_bits: usize,
+    /// The number of bits of carry above the messages.
+    pub carry_bits: usize,
+    /// The targeted security level, in bits.
+    pub security_bits: usize,
+}
+
+/// An error occurring when a set of parameters is invalid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParameterSetError {
+    /// A parameter of the set is invalid.
+    InvalidParameter(ParameterError),
+    /// A standard deviation is not in $]0, 1[$.
+    InvalidNoise,
+    /// The messages and their carry, along with a padding bit, do not fit in the integers.
+    MessageTooLarge,
+}
+
+impl From<ParameterError> for ParameterSetError {
+    fn from(error: ParameterError) -> Self {
+        ParameterSetError::InvalidParameter(error)
+    }
+}
+
+impl fmt::Display for ParameterSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParameterSetError::InvalidParameter(error) => write!(f, "{}", error),
+            ParameterSetError::InvalidNoise => {
+                write!(f, "A standard deviation is not in ]0, 1[.")
+            }
+            ParameterSetError::MessageTooLarge => write!(
+                f,
+                "The messages, their carry and the padding bit do not fit in the integers."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParameterSetError {}
+
+impl ParameterSet {
+    /// Returns the dimension of the GLWE secret key seen as an LWE secret key, i.e. of the
+    /// outputs of the bootstraps.
+    pub fn glwe_key_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.glwe_dimension.0 * self.polynomial_size.0)
+    }
+
+    /// Checks that the parameters are consistent, for ciphertexts of type `T`.
+    ///
+    /// The security of the parameters is not checked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameter_sets::{
+    ///     ParameterSet, ParameterSetError, TFHE_LIB_PARAMETERS,
+    /// };
+    /// use concrete_commons::parameters::{ParameterError, PolynomialSize};
+    /// assert_eq!(TFHE_LIB_PARAMETERS.validate::<u64>(), Ok(()));
+    /// let invalid = ParameterSet {
+    ///     polynomial_size: PolynomialSize(1000),
+    ///     ..TFHE_LIB_PARAMETERS
+    /// };
+    /// assert_eq!(
+    ///     invalid.validate::<u64>(),
+    ///     Err(ParameterSetError::InvalidParameter(
+    ///         ParameterError::PolynomialSizeNotPowerOfTwo
+    ///     ))
+    /// );
+    /// ```
+    pub fn validate<T: UnsignedInteger>(&self) -> Result<(), ParameterSetError> {
+        LweDimension::try_new(self.lwe_dimension.0)?;
+        GlweDimension::try_new(self.glwe_dimension.0)?;
+        PolynomialSize::try_new(self.polynomial_size.0)?;
+        DecompositionParameters::try_new::<T>(self.pbs_base_log, self.pbs_level)?;
+        DecompositionParameters::try_new::<T>(self.ks_base_log, self.ks_level)?;
+        for noise in [self.lwe_noise, self.glwe_noise].iter() {
+            let std_dev = noise.get_standard_dev();
+            if !(std_dev > 0. && std_dev < 1.) {
+                return Err(ParameterSetError::InvalidNoise);
+            }
+        }
+        // One bit of padding is needed above the carry.
+        let total_bits = self
+            .message_bits
+            .checked_add(self.carry_bits)
+            .and_then(|bits| bits.checked_add(1));
+        match total_bits {
+            Some(bits) if self.message_bits != 0 && bits <= T::BITS => {}
+            _ => return Err(ParameterSetError::MessageTooLarge),
+        }
+        Ok(())
+    }
+}
+
+/// A set of parameters registered under a name.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NamedParameterSet {
+    /// The name of the set.
+    pub name: &'static str,
+    /// A short description of the set.
+    pub description: &'static str,
+    /// The parameters of the set.
+    pub parameters: &'static ParameterSet,
+}
+
+/// The default parameters of the gate bootstrapping of the TFHE library, for boolean messages.
+///
+/// The security level is the one claimed at the time of their publication.
+pub const TFHE_LIB_PARAMETERS: ParameterSet = ParameterSet {
+    lwe_dimension: LweDimension(630),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(1024),
+    lwe_noise: StandardDev(0.000_030_517_578_125),
+    glwe_noise: StandardDev(0.000_000_029_802_322_387_695_312),
+    pbs_base_log: DecompositionBaseLog(7),
+    pbs_level: DecompositionLevelCount(3),
+    ks_base_log: DecompositionBaseLog(2),
+    ks_level: DecompositionLevelCount(8),
+    message_bits: 1,
+    carry_bits: 0,
+    security_bits: 128,
+};
+
+/// Parameters for messages of one bit with one bit of carry, encrypted in 64 bits integers.
+///
+/// They minimize the complexity of a keyswitch followed by a PBS for a failure probability of
+/// $2^{-40}$, according to the optimizer of `concrete-npe`.
+pub const MESSAGE_1_CARRY_1_PARAMETERS: ParameterSet = ParameterSet {
+    lwe_dimension: LweDimension(634),
+    glwe_dimension: GlweDimension(3),
+    polynomial_size: PolynomialSize(512),
+    lwe_noise: StandardDev(3.727_093_506_827_566_5e-5),
+    glwe_noise: StandardDev(2.591_281_775_875_202_5e-12),
+    pbs_base_log: DecompositionBaseLog(19),
+    pbs_level: DecompositionLevelCount(1),
+    ks_base_log: DecompositionBaseLog(3),
+    ks_level: DecompositionLevelCount(4),
+    message_bits: 1,
+    carry_bits: 1,
+    security_bits: 128,
+};
+
+/// Parameters for messages of two bits with two bits of carry, encrypted in 64 bits integers.
+///
+/// They minimize the complexity of a keyswitch followed by a PBS for a failure probability of
+/// $2^{-40}$, according to the optimizer of `concrete-npe`.
+pub const MESSAGE_2_CARRY_2_PARAMETERS: ParameterSet = ParameterSet {
+    lwe_dimension: LweDimension(700),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    lwe_noise: StandardDev(1.115_898_653_220_884_7e-5),
+    glwe_noise: StandardDev(2.241_397_966_581_602_5e-16),
+    pbs_base_log: DecompositionBaseLog(25),
+    pbs_level: DecompositionLevelCount(1),
+    ks_base_log: DecompositionBaseLog(2),
+    ks_level: DecompositionLevelCount(7),
+    message_bits: 2,
+    carry_bits: 2,
+    security_bits: 128,
+};
+
+/// Parameters for messages of three bits with three bits of carry, encrypted in 64 bits integers.
+///
+/// They minimize the complexity of a keyswitch followed by a PBS for a failure probability of
+/// $2^{-40}$, according to the optimizer of `concrete-npe`.
+pub const MESSAGE_3_CARRY_3_PARAMETERS: ParameterSet = ParameterSet {
+    lwe_dimension: LweDimension(846),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(8192),
+    lwe_noise: StandardDev(7.745_262_569_800_723e-7),
+    glwe_noise: StandardDev(2.168_404_344_971_009e-19),
+    pbs_base_log: DecompositionBaseLog(30),
+    pbs_level: DecompositionLevelCount(1),
+    ks_base_log: DecompositionBaseLog(3),
+    ks_level: DecompositionLevelCount(6),
+    message_bits: 3,
+    carry_bits: 3,
+    security_bits: 128,
+};
+
+/// The registered sets of parameters.
+pub const PARAMETER_SETS: &[NamedParameterSet] = &[
+    NamedParameterSet {
+        name: "TFHE_LIB_PARAMETERS",
+        description: "Gate bootstrapping parameters of the TFHE library.",
+        parameters: &TFHE_LIB_PARAMETERS,
+    },
+    NamedParameterSet {
+        name: "MESSAGE_1_CARRY_1_PARAMETERS",
+        description: "One bit of message and one bit of carry, in 64 bits integers.",
+        parameters: &MESSAGE_1_CARRY_1_PARAMETERS,
+    },
+    NamedParameterSet {
+        name: "MESSAGE_2_CARRY_2_PARAMETERS",
+        description: "Two bits of message and two bits of carry, in 64 bits integers.",
+        parameters: &MESSAGE_2_CARRY_2_PARAMETERS,
+    },
+    NamedParameterSet {
+        name: "MESSAGE_3_CARRY_3_PARAMETERS",
+        description: "Three bits of message and three bits of carry, in 64 bits integers.",
+        parameters: &MESSAGE_3_CARRY_3_PARAMETERS,
+    },
+];
+
+/// Returns the registered set of parameters with a given name, if any.
+pub fn find_parameter_set(name: &str) -> Option<&'static ParameterSet> {
+    PARAMETER_SETS
+        .iter()
+        .find(|set| set.name == name)
+        .map(|set| set.parameters)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_registered_sets_are_valid() {
+        for set in PARAMETER_SETS.iter() {
+            assert_eq!(set.parameters.validate::<u64>(), Ok(()), "{}", set.name);
+            assert_eq!(find_parameter_set(set.name), Some(set.parameters));
+        }
+        assert_eq!(TFHE_LIB_PARAMETERS.validate::<u32>(), Ok(()));
+        assert_eq!(find_parameter_set("UNKNOWN_PARAMETERS"), None);
+    }
+
+    #[test]
+    fn test_invalid_sets() {
+        let parameters = MESSAGE_2_CARRY_2_PARAMETERS;
+        assert_eq!(
+            ParameterSet {
+                pbs_base_log: DecompositionBaseLog(33),
+                ..parameters
+            }
+            .validate::<u32>(),
+            Err(ParameterSetError::InvalidParameter(
+                ParameterError::DecompositionTooLarge
+            ))
+        );
+        assert_eq!(
+            ParameterSet {
+                lwe_dimension: LweDimension(0),
+                ..parameters
+            }
+            .validate::<u64>(),
+            Err(ParameterSetError::InvalidParameter(
+                ParameterError::NullLweDimension
+            ))
+        );
+        assert_eq!(
+            ParameterSet {
+                glwe_noise: StandardDev(0.),
+                ..parameters
+            }
+            .validate::<u64>(),
+            Err(ParameterSetError::InvalidNoise)
+        );
+        assert_eq!(
+            ParameterSet {
+                ks_level: DecompositionLevelCount(0),
+                ..parameters
+            }
+            .validate::<u64>(),
+            Err(ParameterSetError::InvalidParameter(
+                ParameterError::NullDecompositionLevelCount
+            ))
+        );
+        assert_eq!(
+            ParameterSet {
+                carry_bits: 63,
+                ..parameters
+            }
+            .validate::<u64>(),
+            Err(ParameterSetError::MessageTooLarge)
+        );
+        assert_eq!(
+            ParameterSet {
+                carry_bits: usize::MAX,
+                ..parameters
+            }
+            .validate::<u64>(),
+            Err(ParameterSetError::MessageTooLarge)
+        );
+    }
+
+    #[cfg(feature = "serde_serialize")]
+    #[test]
+    fn test_serde_round_trip() {
+        for set in PARAMETER_SETS.iter() {
+            let serialized = serde_json::to_string(set.parameters).unwrap();
+            let deserialized: ParameterSet = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(&deserialized, set.parameters, "{}", set.name);
+        }
+    }
+}
@@ -1,6 +1,53 @@
 #![allow(deprecated)]
+use crate::numeric::UnsignedInteger;
 #[cfg(feature = "serde_serialize")]
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An error occurring when a parameter is created from an invalid value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParameterError {
+    /// The LWE dimension is zero.
+    NullLweDimension,
+    /// The GLWE dimension is zero.
+    NullGlweDimension,
+    /// The polynomial size is zero.
+    NullPolynomialSize,
+    /// The polynomial size is not a power of two.
+    PolynomialSizeNotPowerOfTwo,
+    /// The decomposition base log is zero.
+    NullDecompositionBaseLog,
+    /// The decomposition level count is zero.
+    NullDecompositionLevelCount,
+    /// The decomposition precision (base log * level count) exceeds the integer precision.
+    DecompositionTooLarge,
+}
+
+impl fmt::Display for ParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ParameterError::NullLweDimension => "The LWE dimension must be greater than zero.",
+            ParameterError::NullGlweDimension => "The GLWE dimension must be greater than zero.",
+            ParameterError::NullPolynomialSize => "The polynomial size must be greater than zero.",
+            ParameterError::PolynomialSizeNotPowerOfTwo => {
+                "The polynomial size must be a power of two."
+            }
+            ParameterError::NullDecompositionBaseLog => {
+                "The decomposition base log must be greater than zero."
+            }
+            ParameterError::NullDecompositionLevelCount => {
+                "The decomposition level count must be greater than zero."
+            }
+            ParameterError::DecompositionTooLarge => {
+                "The decomposition precision (base log * level count) must not exceed the \
+                 precision of the integers."
+            }
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for ParameterError {}
 
 /// The number plaintexts in a plaintext list.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
@@ -76,6 +123,14 @@ impl LweSize {
 pub struct LweDimension(pub usize);
 
 impl LweDimension {
+    /// Creates an LWE dimension, checking that it is not zero.
+    pub fn try_new(dimension: usize) -> Result<LweDimension, ParameterError> {
+        if dimension == 0 {
+            return Err(ParameterError::NullLweDimension);
+        }
+        Ok(LweDimension(dimension))
+    }
+
     /// Returns the associated [`LweSize`].
     pub fn to_lwe_size(&self) -> LweSize {
         LweSize(self.0 + 1)
@@ -101,6 +156,14 @@ impl GlweSize {
 pub struct GlweDimension(pub usize);
 
 impl GlweDimension {
+    /// Creates a GLWE dimension, checking that it is not zero.
+    pub fn try_new(dimension: usize) -> Result<GlweDimension, ParameterError> {
+        if dimension == 0 {
+            return Err(ParameterError::NullGlweDimension);
+        }
+        Ok(GlweDimension(dimension))
+    }
+
     /// Returns the associated [`GlweSize`].
     pub fn to_glwe_size(&self) -> GlweSize {
         GlweSize(self.0 + 1)
@@ -115,6 +178,28 @@ impl GlweDimension {
 pub struct PolynomialSize(pub usize);
 
 impl PolynomialSize {
+    /// Creates a polynomial size, checking that it is a non-zero power of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{ParameterError, PolynomialSize};
+    /// assert_eq!(PolynomialSize::try_new(1024), Ok(PolynomialSize(1024)));
+    /// assert_eq!(
+    ///     PolynomialSize::try_new(1000),
+    ///     Err(ParameterError::PolynomialSizeNotPowerOfTwo)
+    /// );
+    /// ```
+    pub fn try_new(size: usize) -> Result<PolynomialSize, ParameterError> {
+        if size == 0 {
+            return Err(ParameterError::NullPolynomialSize);
+        }
+        if !size.is_power_of_two() {
+            return Err(ParameterError::PolynomialSizeNotPowerOfTwo);
+        }
+        Ok(PolynomialSize(size))
+    }
+
     /// Returns the associated [`PolynomialSizeLog`].
     pub fn log2(&self) -> PolynomialSizeLog {
         PolynomialSizeLog((self.0 as f64).log2().ceil() as usize)
@@ -162,6 +247,16 @@ pub struct MonomialIndex(pub usize);
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct DecompositionBaseLog(pub usize);
 
+impl DecompositionBaseLog {
+    /// Creates a decomposition base log, checking that it is not zero.
+    pub fn try_new(base_log: usize) -> Result<DecompositionBaseLog, ParameterError> {
+        if base_log == 0 {
+            return Err(ParameterError::NullDecompositionBaseLog);
+        }
+        Ok(DecompositionBaseLog(base_log))
+    }
+}
+
 /// The number of levels used in a decomposition.
 ///
 /// When decomposing an integer over the $l$ largest powers of the basis, this type represents
@@ -170,6 +265,79 @@ pub struct DecompositionBaseLog(pub usize);
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct DecompositionLevelCount(pub usize);
 
+impl DecompositionLevelCount {
+    /// Creates a decomposition level count, checking that it is not zero.
+    pub fn try_new(level_count: usize) -> Result<DecompositionLevelCount, ParameterError> {
+        if level_count == 0 {
+            return Err(ParameterError::NullDecompositionLevelCount);
+        }
+        Ok(DecompositionLevelCount(level_count))
+    }
+}
+
+/// The parameters of a decomposition of integers of a given type.
+///
+/// Contrarily to a pair of [`DecompositionBaseLog`] and [`DecompositionLevelCount`], a value of
+/// this type is always valid for the integer type it was created for: both parameters are
+/// non-zero, and the decomposition does not use more bits than the integers have.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct DecompositionParameters {
+    base_log: DecompositionBaseLog,
+    level_count: DecompositionLevelCount,
+}
+
+impl DecompositionParameters {
+    /// Creates the parameters of a decomposition of integers of type `Scalar`, checking that they
+    /// are valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DecompositionParameters, ParameterError,
+    /// };
+    /// let parameters = DecompositionParameters::try_new::<u32>(
+    ///     DecompositionBaseLog(8),
+    ///     DecompositionLevelCount(4),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(parameters.base_log(), DecompositionBaseLog(8));
+    /// assert_eq!(parameters.level_count(), DecompositionLevelCount(4));
+    /// assert_eq!(
+    ///     DecompositionParameters::try_new::<u32>(
+    ///         DecompositionBaseLog(8),
+    ///         DecompositionLevelCount(5),
+    ///     ),
+    ///     Err(ParameterError::DecompositionTooLarge)
+    /// );
+    /// ```
+    pub fn try_new<Scalar: UnsignedInteger>(
+        base_log: DecompositionBaseLog,
+        level_count: DecompositionLevelCount,
+    ) -> Result<DecompositionParameters, ParameterError> {
+        let base_log = DecompositionBaseLog::try_new(base_log.0)?;
+        let level_count = DecompositionLevelCount::try_new(level_count.0)?;
+        match base_log.0.checked_mul(level_count.0) {
+            Some(precision) if precision <= Scalar::BITS => {}
+            _ => return Err(ParameterError::DecompositionTooLarge),
+        }
+        Ok(DecompositionParameters {
+            base_log,
+            level_count,
+        })
+    }
+
+    /// Returns the logarithm of the base of the decomposition.
+    pub fn base_log(&self) -> DecompositionBaseLog {
+        self.base_log
+    }
+
+    /// Returns the number of levels of the decomposition.
+    pub fn level_count(&self) -> DecompositionLevelCount {
+        self.level_count
+    }
+}
+
 /// The logarithm of the number of LUT evaluated in a PBS.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
@@ -182,3 +350,48 @@ pub struct LutCountLog(pub usize);
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct ModulusSwitchOffset(pub usize);
+
+/// The master seed from which keys are derived.
+///
+/// Every key of a given kind (e.g. LWE secret key, or bootstrap key) derived from the same master
+/// seed and the same [`KeyDerivationLabel`] is identical.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct MasterSeed(pub u128);
+
+/// The label identifying a key derived from a [`MasterSeed`].
+///
+/// Keys derived from the same master seed are independent as soon as their kinds or their labels
+/// differ. In particular, an LWE secret key and a GLWE secret key derived with the same label are
+/// independent.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct KeyDerivationLabel(pub usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decomposition_parameters_overflow() {
+        assert_eq!(
+            DecompositionParameters::try_new::<u64>(
+                DecompositionBaseLog(1 << (usize::BITS - 1)),
+                DecompositionLevelCount(2),
+            ),
+            Err(ParameterError::DecompositionTooLarge)
+        );
+        assert_eq!(
+            DecompositionParameters::try_new::<u64>(
+                DecompositionBaseLog(usize::MAX),
+                DecompositionLevelCount(usize::MAX),
+            ),
+            Err(ParameterError::DecompositionTooLarge)
+        );
+        assert!(DecompositionParameters::try_new::<u64>(
+            DecompositionBaseLog(16),
+            DecompositionLevelCount(4),
+        )
+        .is_ok());
+    }
+}
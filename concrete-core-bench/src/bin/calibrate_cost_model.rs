@@ -0,0 +1,183 @@
+//! Calibrates the cost model of `concrete-npe` on the local machine.
+//!
+//! The keyswitch and the programmable bootstrapping of the core backend are timed for a few sets
+//! of parameters, and the coefficients of a [`CostModel`] are fitted to the measured run times.
+use concrete_core::prelude::*;
+use concrete_npe::{estimate_keyswitch_cost, estimate_pbs_cost, CostModel, OperationCost};
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+/// The number of timed executions for every set of parameters.
+const REPETITIONS: u32 = 10;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut engine = CoreEngine::new(())?;
+    let mut measurements = Vec::new();
+
+    for &(input_dimension, output_dimension, level) in [
+        (1024, 512, 3),
+        (1024, 630, 5),
+        (2048, 750, 3),
+        (2048, 750, 8),
+    ]
+    .iter()
+    {
+        let (input_dimension, output_dimension, level) = (
+            LweDimension(input_dimension),
+            LweDimension(output_dimension),
+            DecompositionLevelCount(level),
+        );
+        let time = time_keyswitch(&mut engine, input_dimension, output_dimension, level)?;
+        let cost = estimate_keyswitch_cost::<u64>(input_dimension, output_dimension, level);
+        measurements.push((
+            format!(
+                "keyswitch n_in={} n_out={} l={}",
+                input_dimension.0, output_dimension.0, level.0
+            ),
+            cost,
+            time,
+        ));
+    }
+
+    for &(lwe_dimension, glwe_dimension, polynomial_size, level) in [
+        (64, 1, 512, 2),
+        (64, 1, 1024, 3),
+        (64, 2, 1024, 2),
+        (64, 1, 2048, 4),
+    ]
+    .iter()
+    {
+        let (lwe_dimension, glwe_dimension, polynomial_size, level) = (
+            LweDimension(lwe_dimension),
+            GlweDimension(glwe_dimension),
+            PolynomialSize(polynomial_size),
+            DecompositionLevelCount(level),
+        );
+        let time = time_pbs(
+            &mut engine,
+            lwe_dimension,
+            glwe_dimension,
+            polynomial_size,
+            level,
+        )?;
+        let cost = estimate_pbs_cost::<u64>(lwe_dimension, glwe_dimension, polynomial_size, level);
+        measurements.push((
+            format!(
+                "pbs n={} k={} N={} l={}",
+                lwe_dimension.0, glwe_dimension.0, polynomial_size.0, level.0
+            ),
+            cost,
+            time,
+        ));
+    }
+
+    let costs: Vec<(OperationCost, Duration)> = measurements
+        .iter()
+        .map(|(_, cost, time)| (*cost, *time))
+        .collect();
+    let model = CostModel::calibrate(&costs).ok_or("the measurements can not be used")?;
+    println!(
+        "| {:<36} | {:>12} | {:>12} |",
+        "operator", "measured", "estimated"
+    );
+    for (name, cost, time) in measurements.iter() {
+        println!(
+            "| {:<36} | {:>12.3?} | {:>12.3?} |",
+            name,
+            time,
+            model.estimate_time(cost)
+        );
+    }
+    println!();
+    println!("{:?}", model);
+    Ok(())
+}
+
+fn time_keyswitch(
+    engine: &mut CoreEngine,
+    input_dimension: LweDimension,
+    output_dimension: LweDimension,
+    level: DecompositionLevelCount,
+) -> Result<Duration, Box<dyn Error>> {
+    let noise = Variance(2_f64.powi(-50));
+    let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_dimension)?;
+    let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_dimension)?;
+    let keyswitch_key: LweKeyswitchKey64 = engine.create_lwe_keyswitch_key(
+        &input_key,
+        &output_key,
+        level,
+        DecompositionBaseLog(4),
+        noise,
+    )?;
+    let plaintext = engine.create_plaintext(&(1_u64 << 60))?;
+    let input = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    let mut output = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+
+    // A first execution warms the caches up, and is not timed.
+    engine.discard_keyswitch_lwe_ciphertext(&mut output, &input, &keyswitch_key)?;
+    let start = Instant::now();
+    for _ in 0..REPETITIONS {
+        engine.discard_keyswitch_lwe_ciphertext(&mut output, &input, &keyswitch_key)?;
+    }
+    let time = start.elapsed() / REPETITIONS;
+
+    engine.destroy(input_key)?;
+    engine.destroy(output_key)?;
+    engine.destroy(keyswitch_key)?;
+    engine.destroy(plaintext)?;
+    engine.destroy(input)?;
+    engine.destroy(output)?;
+    Ok(time)
+}
+
+fn time_pbs(
+    engine: &mut CoreEngine,
+    lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    level: DecompositionLevelCount,
+) -> Result<Duration, Box<dyn Error>> {
+    let noise = Variance(2_f64.powi(-50));
+    let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    let glwe_key: GlweSecretKey64 =
+        engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    let bootstrap_key: FourierLweBootstrapKey64 = engine.create_lwe_bootstrap_key(
+        &lwe_key,
+        &glwe_key,
+        DecompositionBaseLog(4),
+        level,
+        noise,
+    )?;
+    let output_key: LweSecretKey64 =
+        engine.create_lwe_secret_key(LweDimension(glwe_dimension.0 * polynomial_size.0))?;
+    let plaintext = engine.create_plaintext(&(1_u64 << 60))?;
+    let lut = engine.create_plaintext_vector(&vec![1_u64 << 60; polynomial_size.0])?;
+    let accumulator =
+        engine.trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &lut)?;
+    let input = engine.encrypt_lwe_ciphertext(&lwe_key, &plaintext, noise)?;
+    let mut output = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+
+    // A first execution warms the caches up and allocates the FFT buffers, and is not timed.
+    engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &accumulator, &bootstrap_key)?;
+    let start = Instant::now();
+    for _ in 0..REPETITIONS {
+        engine.discard_bootstrap_lwe_ciphertext(
+            &mut output,
+            &input,
+            &accumulator,
+            &bootstrap_key,
+        )?;
+    }
+    let time = start.elapsed() / REPETITIONS;
+
+    engine.destroy(lwe_key)?;
+    engine.destroy(glwe_key)?;
+    engine.destroy(bootstrap_key)?;
+    engine.destroy(output_key)?;
+    engine.destroy(plaintext)?;
+    engine.destroy(lut)?;
+    engine.destroy(accumulator)?;
+    engine.destroy(input)?;
+    engine.destroy(output)?;
+    Ok(time)
+}
@@ -68,6 +68,16 @@ bench! {
     (GlweCiphertextConsumingRetrievalFixture, (GlweCiphertext, Vec)),
     (GlweCiphertextConsumingRetrievalFixture, (GlweCiphertextView, Slice)),
     (GlweCiphertextConsumingRetrievalFixture, (GlweCiphertextMutView, MutSlice)),
+    (GlweCiphertextVectorCreationFixture, (GlweCiphertextVector, Vec)),
+    (GlweCiphertextVectorCreationFixture, (GlweCiphertextVectorView, Slice)),
+    (GlweCiphertextVectorCreationFixture, (GlweCiphertextVectorMutView, MutSlice)),
+    (GlweCiphertextVectorConsumingRetrievalFixture, (GlweCiphertextVector, Vec)),
+    (GlweCiphertextVectorConsumingRetrievalFixture, (GlweCiphertextVectorView, Slice)),
+    (GlweCiphertextVectorConsumingRetrievalFixture, (GlweCiphertextVectorMutView, MutSlice)),
+    (GlweSecretKeyFromContainerCreationFixture, (GlweSecretKey, Vec)),
+    (GlweSecretKeyConsumingRetrievalFixture, (GlweSecretKey, Vec)),
+    (LweSecretKeyFromContainerCreationFixture, (LweSecretKey, Vec)),
+    (LweSecretKeyConsumingRetrievalFixture, (LweSecretKey, Vec)),
     (LweCiphertextEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
     (LweCiphertextZeroEncryptionFixture, (LweSecretKey, LweCiphertext)),
     (LweCiphertextTrivialEncryptionFixture, (Plaintext, LweCiphertext)),
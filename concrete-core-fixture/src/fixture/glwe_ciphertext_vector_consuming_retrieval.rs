@@ -0,0 +1,144 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{PrototypesContainer, PrototypesGlweCiphertextVector};
+use crate::generation::synthesizing::{SynthesizesContainer, SynthesizesGlweCiphertextVector};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+
+use concrete_core::prelude::{
+    GlweCiphertextVectorConsumingRetrievalEngine, GlweCiphertextVectorEntity,
+};
+
+#[derive(Debug)]
+pub struct GlweCiphertextVectorConsumingRetrievalParameters {
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub glwe_ciphertext_count: GlweCiphertextCount,
+}
+
+/// A fixture for the types implementing the `GlweCiphertextVectorConsumingRetrievalEngine` trait
+/// with GLWE ciphertext vectors.
+pub struct GlweCiphertextVectorConsumingRetrievalFixture;
+
+impl<Precision, Engine, GlweCiphertextVector, Container>
+    Fixture<Precision, Engine, (GlweCiphertextVector, Container)>
+    for GlweCiphertextVectorConsumingRetrievalFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GlweCiphertextVectorConsumingRetrievalEngine<GlweCiphertextVector, Container>,
+    GlweCiphertextVector: GlweCiphertextVectorEntity,
+    Maker: SynthesizesGlweCiphertextVector<Precision, GlweCiphertextVector>
+        + SynthesizesContainer<Precision, Container>,
+{
+    type Parameters = GlweCiphertextVectorConsumingRetrievalParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (
+        <Maker as PrototypesGlweCiphertextVector<
+            Precision,
+            GlweCiphertextVector::KeyDistribution,
+        >>::GlweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (GlweCiphertextVector,);
+    type PostExecutionContext = (Container,);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextVectorConsumingRetrievalParameters {
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                    glwe_ciphertext_count: GlweCiphertextCount(1),
+                },
+                GlweCiphertextVectorConsumingRetrievalParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                    glwe_ciphertext_count: GlweCiphertextCount(10),
+                },
+                GlweCiphertextVectorConsumingRetrievalParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(2048),
+                    glwe_ciphertext_count: GlweCiphertextCount(3),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let num_elements = parameters.glwe_dimension.to_glwe_size().0
+            * parameters.polynomial_size.0
+            * parameters.glwe_ciphertext_count.0;
+        let proto_ciphertext_vector = maker.transform_raw_vec_to_glwe_ciphertext_vector(
+            &Precision::Raw::uniform_vec(num_elements),
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        (proto_ciphertext_vector,)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_ciphertext_vector,) = sample_proto;
+        (maker.synthesize_glwe_ciphertext_vector(proto_ciphertext_vector),)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext_vector,) = context;
+        let raw_ciphertext_vector =
+            unsafe { engine.consume_retrieve_glwe_ciphertext_vector_unchecked(ciphertext_vector) };
+        (raw_ciphertext_vector,)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (proto_ciphertext_vector,) = sample_proto;
+        let (raw_ciphertext_vector,) = context;
+        let proto_container = maker.unsynthesize_container(raw_ciphertext_vector);
+        (
+            maker.transform_glwe_ciphertext_vector_to_raw_vec(proto_ciphertext_vector),
+            maker.transform_container_to_raw_vec(&proto_container),
+        )
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (Variance(0.),)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+}
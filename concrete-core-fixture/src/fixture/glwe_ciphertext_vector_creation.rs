@@ -0,0 +1,138 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{PrototypesContainer, PrototypesGlweCiphertextVector};
+use crate::generation::synthesizing::{SynthesizesContainer, SynthesizesGlweCiphertextVector};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+
+use concrete_core::prelude::{GlweCiphertextVectorCreationEngine, GlweCiphertextVectorEntity};
+
+#[derive(Debug)]
+pub struct GlweCiphertextVectorCreationParameters {
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub glwe_ciphertext_count: GlweCiphertextCount,
+}
+
+/// A fixture for the types implementing the `GlweCiphertextVectorCreationEngine` trait with GLWE
+/// ciphertext vectors.
+pub struct GlweCiphertextVectorCreationFixture;
+
+impl<Precision, Engine, GlweCiphertextVector, Container>
+    Fixture<Precision, Engine, (GlweCiphertextVector, Container)>
+    for GlweCiphertextVectorCreationFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GlweCiphertextVectorCreationEngine<Container, GlweCiphertextVector>,
+    GlweCiphertextVector: GlweCiphertextVectorEntity,
+    Maker: SynthesizesGlweCiphertextVector<Precision, GlweCiphertextVector>
+        + SynthesizesContainer<Precision, Container>,
+{
+    type Parameters = GlweCiphertextVectorCreationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (<Maker as PrototypesContainer<Precision>>::ContainerProto,);
+    type PreExecutionContext = (Container,);
+    type PostExecutionContext = (GlweCiphertextVector,);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextVectorCreationParameters {
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                    glwe_ciphertext_count: GlweCiphertextCount(1),
+                },
+                GlweCiphertextVectorCreationParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                    glwe_ciphertext_count: GlweCiphertextCount(10),
+                },
+                GlweCiphertextVectorCreationParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(2048),
+                    glwe_ciphertext_count: GlweCiphertextCount(3),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let num_elements = parameters.glwe_dimension.to_glwe_size().0
+            * parameters.polynomial_size.0
+            * parameters.glwe_ciphertext_count.0;
+        (maker.transform_raw_vec_to_container(&Precision::Raw::uniform_vec(num_elements)),)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_container,) = sample_proto;
+        (maker.synthesize_container(proto_container),)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (underlying_container,) = context;
+        let glwe_ciphertext_vector = unsafe {
+            engine.create_glwe_ciphertext_vector_unchecked(
+                underlying_container,
+                parameters.glwe_dimension,
+                parameters.polynomial_size,
+            )
+        };
+        (glwe_ciphertext_vector,)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (glwe_ciphertext_vector,) = context;
+        let (proto_container,) = sample_proto;
+        let ciphertext_vector_proto =
+            maker.unsynthesize_glwe_ciphertext_vector(glwe_ciphertext_vector);
+        (
+            maker.transform_container_to_raw_vec(proto_container),
+            maker.transform_glwe_ciphertext_vector_to_raw_vec(&ciphertext_vector_proto),
+        )
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (Variance(0.),)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+}
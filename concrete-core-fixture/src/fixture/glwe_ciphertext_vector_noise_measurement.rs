@@ -0,0 +1,182 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+use concrete_core::prelude::{
+    GlweCiphertextVectorEntity, GlweCiphertextVectorNoiseMeasurementEngine, GlweSecretKeyEntity,
+    PlaintextVectorEntity,
+};
+
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertextVector, PrototypesGlweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertextVector, SynthesizesGlweSecretKey, SynthesizesPlaintextVector,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::measured_noise::MeasuredNoise;
+use crate::raw::statistical_test::{assert_measured_noise, torus_modular_distance};
+
+/// A fixture for the types implementing the `GlweCiphertextVectorNoiseMeasurementEngine` trait.
+pub struct GlweCiphertextVectorNoiseMeasurementFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextVectorNoiseMeasurementParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub count: GlweCiphertextCount,
+}
+
+impl<Precision, Engine, SecretKey, CiphertextVector, PlaintextVector, Noise>
+    Fixture<Precision, Engine, (SecretKey, CiphertextVector, PlaintextVector, Noise)>
+    for GlweCiphertextVectorNoiseMeasurementFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GlweCiphertextVectorNoiseMeasurementEngine<
+        SecretKey,
+        CiphertextVector,
+        PlaintextVector,
+        Noise,
+    >,
+    SecretKey: GlweSecretKeyEntity,
+    CiphertextVector: GlweCiphertextVectorEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    PlaintextVector: PlaintextVectorEntity,
+    Noise: MeasuredNoise<Precision::Raw>,
+    Maker: SynthesizesGlweSecretKey<Precision, SecretKey>
+        + SynthesizesGlweCiphertextVector<Precision, CiphertextVector>
+        + SynthesizesPlaintextVector<Precision, PlaintextVector>,
+{
+    type Parameters = GlweCiphertextVectorNoiseMeasurementParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, SecretKey::KeyDistribution>>::GlweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertextVector<Precision,
+            SecretKey::KeyDistribution>>::GlweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (SecretKey, CiphertextVector, PlaintextVector);
+    type PostExecutionContext = (SecretKey, CiphertextVector, PlaintextVector, Noise);
+    type Criteria = ();
+    type Outcome = (Vec<f64>, Vec<f64>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextVectorNoiseMeasurementParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(2),
+                    count: GlweCiphertextCount(1),
+                },
+                GlweCiphertextVectorNoiseMeasurementParameters {
+                    noise: Variance(0.00000001),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    count: GlweCiphertextCount(10),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector =
+            Precision::Raw::uniform_vec(parameters.polynomial_size.0 * parameters.count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_ciphertext_vector = maker.encrypt_plaintext_vector_to_glwe_ciphertext_vector(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        (proto_plaintext_vector, proto_ciphertext_vector)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector, proto_ciphertext_vector) = sample_proto;
+        (
+            maker.synthesize_glwe_secret_key(proto_secret_key),
+            maker.synthesize_glwe_ciphertext_vector(proto_ciphertext_vector),
+            maker.synthesize_plaintext_vector(proto_plaintext_vector),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, ciphertext_vector, plaintext_vector) = context;
+        let noise = unsafe {
+            engine.measure_glwe_ciphertext_vector_noise_unchecked(
+                &secret_key,
+                &ciphertext_vector,
+                &plaintext_vector,
+            )
+        };
+        (secret_key, ciphertext_vector, plaintext_vector, noise)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (secret_key, ciphertext_vector, plaintext_vector, noise) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector, proto_ciphertext_vector) = sample_proto;
+        let proto_decrypted_plaintext_vector = maker
+            .decrypt_glwe_ciphertext_vector_to_plaintext_vector(
+                proto_secret_key,
+                proto_ciphertext_vector,
+            );
+        maker.destroy_plaintext_vector(plaintext_vector);
+        maker.destroy_glwe_ciphertext_vector(ciphertext_vector);
+        maker.destroy_glwe_secret_key(secret_key);
+        let expected_noise = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_decrypted_plaintext_vector)
+            .into_iter()
+            .zip(maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector))
+            .map(|(decrypted, expected)| torus_modular_distance(decrypted, expected))
+            .collect();
+        (noise.torus_values(), expected_noise)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (measured, expected): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let measured: Vec<f64> = measured.into_iter().flatten().collect();
+        let expected: Vec<f64> = expected.into_iter().flatten().collect();
+        assert_measured_noise::<Precision::Raw>(&measured, &expected)
+    }
+}
@@ -0,0 +1,169 @@
+use crate::fixture::lwe_secret_key_derivation::{
+    are_independent, are_uniform_binary, random_master_seed_and_label,
+};
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{PrototypesGlweSecretKey, PrototypesLweSecretKey};
+use crate::generation::synthesizing::{SynthesizesGlweSecretKey, SynthesizesLweSecretKey};
+use crate::generation::{IntegerPrecision, Maker};
+use concrete_commons::parameters::{
+    GlweDimension, KeyDerivationLabel, LweDimension, MasterSeed, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweSecretKeyDerivationEngine, GlweSecretKeyEntity, LweSecretKeyDerivationEngine,
+    LweSecretKeyEntity,
+};
+
+/// A fixture for the types implementing the `GlweSecretKeyDerivationEngine` trait.
+///
+/// The LWE secret key derived with the same label is used to check that keys of different kinds
+/// are independent.
+pub struct GlweSecretKeyDerivationFixture;
+
+#[derive(Debug)]
+pub struct GlweSecretKeyDerivationParameters {
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+}
+
+impl<Precision, Engine, GlweSecretKey, LweSecretKey>
+    Fixture<Precision, Engine, (GlweSecretKey, LweSecretKey)> for GlweSecretKeyDerivationFixture
+where
+    Precision: IntegerPrecision,
+    Engine:
+        GlweSecretKeyDerivationEngine<GlweSecretKey> + LweSecretKeyDerivationEngine<LweSecretKey>,
+    GlweSecretKey: GlweSecretKeyEntity,
+    LweSecretKey: LweSecretKeyEntity<KeyDistribution = GlweSecretKey::KeyDistribution>,
+    Maker: SynthesizesGlweSecretKey<Precision, GlweSecretKey>
+        + SynthesizesLweSecretKey<Precision, LweSecretKey>,
+{
+    type Parameters = GlweSecretKeyDerivationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (MasterSeed, KeyDerivationLabel);
+    type PreExecutionContext = (MasterSeed, KeyDerivationLabel);
+    type PostExecutionContext = (GlweSecretKey, GlweSecretKey, GlweSecretKey, LweSecretKey);
+    type Criteria = ();
+    // The key, the same key derived again, the key derived with another label, and the LWE key
+    // derived with the same label.
+    type Outcome = (
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+    );
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweSecretKeyDerivationParameters {
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweSecretKeyDerivationParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        random_master_seed_and_label()
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        *sample_proto
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (master_seed, label) = context;
+        let other_label = KeyDerivationLabel(label.0.wrapping_add(1));
+        let glwe_dimension = parameters.glwe_dimension;
+        let polynomial_size = parameters.polynomial_size;
+        let lwe_dimension = LweDimension(glwe_dimension.0 * polynomial_size.0);
+        unsafe {
+            (
+                engine.derive_glwe_secret_key_unchecked(
+                    master_seed,
+                    label,
+                    glwe_dimension,
+                    polynomial_size,
+                ),
+                engine.derive_glwe_secret_key_unchecked(
+                    master_seed,
+                    label,
+                    glwe_dimension,
+                    polynomial_size,
+                ),
+                engine.derive_glwe_secret_key_unchecked(
+                    master_seed,
+                    other_label,
+                    glwe_dimension,
+                    polynomial_size,
+                ),
+                engine.derive_lwe_secret_key_unchecked(master_seed, label, lwe_dimension),
+            )
+        }
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (key, same_key, other_key, lwe_key) = context;
+        let mut to_raw = |key| {
+            let proto_key = maker.unsynthesize_glwe_secret_key(key);
+            maker.transform_glwe_secret_key_to_raw_vec(&proto_key)
+        };
+        let raw_keys = (to_raw(key), to_raw(same_key), to_raw(other_key));
+        let proto_lwe_key = maker.unsynthesize_lwe_secret_key(lwe_key);
+        (
+            raw_keys.0,
+            raw_keys.1,
+            raw_keys.2,
+            maker.transform_lwe_secret_key_to_raw_vec(&proto_lwe_key),
+        )
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let keys: Vec<_> = outputs.iter().map(|(key, ..)| key.as_slice()).collect();
+        let other_keys: Vec<_> = outputs
+            .iter()
+            .map(|(_, _, other, _)| other.as_slice())
+            .collect();
+        let lwe_keys: Vec<_> = outputs.iter().map(|(.., lwe)| lwe.as_slice()).collect();
+        outputs.iter().all(|(key, same_key, ..)| key == same_key)
+            && are_uniform_binary(keys.as_slice())
+            && are_independent(keys.as_slice(), other_keys.as_slice())
+            && are_independent(keys.as_slice(), lwe_keys.as_slice())
+    }
+}
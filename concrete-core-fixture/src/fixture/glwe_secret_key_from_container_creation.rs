@@ -0,0 +1,132 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{PrototypesContainer, PrototypesGlweSecretKey};
+use crate::generation::synthesizing::{SynthesizesContainer, SynthesizesGlweSecretKey};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+use concrete_core::prelude::{GlweSecretKeyEntity, GlweSecretKeyFromContainerCreationEngine};
+
+#[derive(Debug)]
+pub struct GlweSecretKeyFromContainerCreationParameters {
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+}
+
+/// A fixture for the types implementing the `GlweSecretKeyFromContainerCreationEngine` trait with
+/// GLWE secret keys.
+pub struct GlweSecretKeyFromContainerCreationFixture;
+
+impl<Precision, Engine, SecretKey, Container> Fixture<Precision, Engine, (SecretKey, Container)>
+    for GlweSecretKeyFromContainerCreationFixture
+where
+    Precision: IntegerPrecision,
+    Engine: GlweSecretKeyFromContainerCreationEngine<Container, SecretKey>,
+    SecretKey: GlweSecretKeyEntity,
+    Maker:
+        SynthesizesGlweSecretKey<Precision, SecretKey> + SynthesizesContainer<Precision, Container>,
+{
+    type Parameters = GlweSecretKeyFromContainerCreationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (<Maker as PrototypesContainer<Precision>>::ContainerProto,);
+    type PreExecutionContext = (Container,);
+    type PostExecutionContext = (SecretKey,);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweSecretKeyFromContainerCreationParameters {
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                },
+                GlweSecretKeyFromContainerCreationParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(1024),
+                },
+                GlweSecretKeyFromContainerCreationParameters {
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(2048),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_key = Precision::Raw::uniform_between_vec(
+            0..2,
+            parameters.glwe_dimension.0 * parameters.polynomial_size.0,
+        );
+        (maker.transform_raw_vec_to_container(&raw_key),)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_container,) = sample_proto;
+        (maker.synthesize_container(proto_container),)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (underlying_container,) = context;
+        let secret_key = unsafe {
+            engine.create_glwe_secret_key_from_container_unchecked(
+                underlying_container,
+                parameters.polynomial_size,
+            )
+        };
+        (secret_key,)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (secret_key,) = context;
+        let (proto_container,) = sample_proto;
+        let proto_secret_key = maker.unsynthesize_glwe_secret_key(secret_key);
+        (
+            maker.transform_container_to_raw_vec(proto_container),
+            maker.transform_glwe_secret_key_to_raw_vec(&proto_secret_key),
+        )
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (Variance(0.),)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+}
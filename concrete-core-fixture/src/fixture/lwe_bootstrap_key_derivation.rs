@@ -0,0 +1,165 @@
+use crate::fixture::lwe_secret_key_derivation::random_master_seed_and_label;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{PrototypesGlweSecretKey, PrototypesLweSecretKey};
+use crate::generation::synthesizing::{
+    SynthesizesGlweSecretKey, SynthesizesLweBootstrapKey, SynthesizesLweSecretKey,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, KeyDerivationLabel, LweDimension,
+    MasterSeed, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweSecretKeyEntity, LweBootstrapKeyDerivationEngine, LweBootstrapKeyEntity, LweSecretKeyEntity,
+};
+
+/// A fixture for the types implementing the `LweBootstrapKeyDerivationEngine` trait.
+pub struct LweBootstrapKeyDerivationFixture;
+
+#[derive(Debug)]
+pub struct LweBootstrapKeyDerivationParameters {
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub level: DecompositionLevelCount,
+    pub base_log: DecompositionBaseLog,
+    pub noise: Variance,
+}
+
+impl<Precision, Engine, LweSecretKey, GlweSecretKey, BootstrapKey>
+    Fixture<Precision, Engine, (LweSecretKey, GlweSecretKey, BootstrapKey)>
+    for LweBootstrapKeyDerivationFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweBootstrapKeyDerivationEngine<LweSecretKey, GlweSecretKey, BootstrapKey>,
+    LweSecretKey: LweSecretKeyEntity,
+    GlweSecretKey: GlweSecretKeyEntity,
+    BootstrapKey: LweBootstrapKeyEntity<
+            InputKeyDistribution = LweSecretKey::KeyDistribution,
+            OutputKeyDistribution = GlweSecretKey::KeyDistribution,
+        > + PartialEq,
+    Maker: SynthesizesLweSecretKey<Precision, LweSecretKey>
+        + SynthesizesGlweSecretKey<Precision, GlweSecretKey>
+        + SynthesizesLweBootstrapKey<Precision, BootstrapKey>,
+{
+    type Parameters = LweBootstrapKeyDerivationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, LweSecretKey::KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, GlweSecretKey::KeyDistribution>>::GlweSecretKeyProto,
+    );
+    type SamplePrototypes = (MasterSeed, KeyDerivationLabel);
+    type PreExecutionContext = (LweSecretKey, GlweSecretKey, MasterSeed, KeyDerivationLabel);
+    type PostExecutionContext = (
+        LweSecretKey,
+        GlweSecretKey,
+        BootstrapKey,
+        BootstrapKey,
+        BootstrapKey,
+    );
+    type Criteria = ();
+    // Whether the key derived again is the same, and whether the key derived with another label
+    // differs.
+    type Outcome = (bool, bool);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweBootstrapKeyDerivationParameters {
+                lwe_dimension: LweDimension(8),
+                glwe_dimension: GlweDimension(1),
+                polynomial_size: PolynomialSize(256),
+                level: DecompositionLevelCount(2),
+                base_log: DecompositionBaseLog(7),
+                noise: Variance(0.00000001),
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            LweSecretKey::KeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        (proto_lwe_secret_key, proto_glwe_secret_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        random_master_seed_and_label()
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_lwe_secret_key, proto_glwe_secret_key) = repetition_proto;
+        let (master_seed, label) = sample_proto;
+        (
+            maker.synthesize_lwe_secret_key(proto_lwe_secret_key),
+            maker.synthesize_glwe_secret_key(proto_glwe_secret_key),
+            *master_seed,
+            *label,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_key, output_key, master_seed, label) = context;
+        let mut derive = |label| unsafe {
+            engine.derive_lwe_bootstrap_key_unchecked(
+                master_seed,
+                label,
+                &input_key,
+                &output_key,
+                parameters.base_log,
+                parameters.level,
+                parameters.noise,
+            )
+        };
+        let (key, same_key) = (derive(label), derive(label));
+        let other_key = derive(KeyDerivationLabel(label.0.wrapping_add(1)));
+        (input_key, output_key, key, same_key, other_key)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_key, output_key, key, same_key, other_key) = context;
+        let outcome = (key == same_key, key != other_key);
+        maker.destroy_lwe_secret_key(input_key);
+        maker.destroy_glwe_secret_key(output_key);
+        maker.destroy_lwe_bootstrap_key(key);
+        maker.destroy_lwe_bootstrap_key(same_key);
+        maker.destroy_lwe_bootstrap_key(other_key);
+        outcome
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs.iter().all(|(same, different)| *same && *different)
+    }
+}
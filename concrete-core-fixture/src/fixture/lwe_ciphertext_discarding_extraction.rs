@@ -150,7 +150,9 @@ where
         _maker: &mut Maker,
         _repetition_proto: &Self::RepetitionPrototypes,
     ) -> Self::Criteria {
-        (parameters.noise,)
+        let predicted_variance: Variance =
+            concrete_npe::estimate_sample_extraction_noise::<Precision::Raw, _>(parameters.noise);
+        (predicted_variance,)
     }
 
     fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
@@ -205,19 +205,18 @@ where
         _maker: &mut Maker,
         _repetition_proto: &Self::RepetitionPrototypes,
     ) -> Self::Criteria {
-        let predicted_variance: Variance =
-            fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
-                Precision::Raw,
-                _,
-                _,
-                OutputCiphertext::KeyDistribution,
-            >(
-                parameters.input_lwe_dimension,
-                parameters.input_noise,
-                parameters.ksk_noise,
-                parameters.decomp_base_log,
-                parameters.decomp_level_count,
-            );
+        let predicted_variance: Variance = fix_estimate_keyswitch_noise_lwe_to_lwe::<
+            Precision::Raw,
+            _,
+            _,
+            InputCiphertext::KeyDistribution,
+        >(
+            parameters.input_lwe_dimension,
+            parameters.input_noise,
+            parameters.ksk_noise,
+            parameters.decomp_base_log,
+            parameters.decomp_level_count,
+        );
         (predicted_variance,)
     }
 
@@ -230,8 +229,8 @@ where
 // FIXME:
 // The current NPE does not use the key distribution markers of concrete-core. This function makes
 // the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
-pub(crate) fn fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms<T, D1, D2, K>(
-    lwe_mask_size: LweDimension,
+pub(crate) fn fix_estimate_keyswitch_noise_lwe_to_lwe<T, D1, D2, K>(
+    input_lwe_dimension: LweDimension,
     dispersion_lwe: D1,
     dispersion_ksk: D2,
     base_log: DecompositionBaseLog,
@@ -245,39 +244,24 @@ where
 {
     let k_type_id = TypeId::of::<K>();
     if k_type_id == TypeId::of::<BinaryKeyDistribution>() {
-        concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
-            T,
-            D1,
-            D2,
-            BinaryKeyKind,
-        >(
-            lwe_mask_size,
+        concrete_npe::estimate_keyswitch_noise_lwe_to_lwe::<T, D1, D2, BinaryKeyKind>(
+            input_lwe_dimension,
             dispersion_lwe,
             dispersion_ksk,
             base_log,
             level,
         )
     } else if k_type_id == TypeId::of::<TernaryKeyDistribution>() {
-        concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
-            T,
-            D1,
-            D2,
-            TernaryKeyKind,
-        >(
-            lwe_mask_size,
+        concrete_npe::estimate_keyswitch_noise_lwe_to_lwe::<T, D1, D2, TernaryKeyKind>(
+            input_lwe_dimension,
             dispersion_lwe,
             dispersion_ksk,
             base_log,
             level,
         )
     } else if k_type_id == TypeId::of::<GaussianKeyDistribution>() {
-        concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
-            T,
-            D1,
-            D2,
-            GaussianKeyKind,
-        >(
-            lwe_mask_size,
+        concrete_npe::estimate_keyswitch_noise_lwe_to_lwe::<T, D1, D2, GaussianKeyKind>(
+            input_lwe_dimension,
             dispersion_lwe,
             dispersion_ksk,
             base_log,
@@ -0,0 +1,154 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweDimension;
+use concrete_core::prelude::{
+    LweCiphertextEntity, LweCiphertextNoiseMeasurementEngine, LweSecretKeyEntity, PlaintextEntity,
+};
+
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCiphertext, SynthesizesLweSecretKey, SynthesizesPlaintext,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::measured_noise::MeasuredNoise;
+use crate::raw::statistical_test::{assert_measured_noise, torus_modular_distance};
+
+/// A fixture for the types implementing the `LweCiphertextNoiseMeasurementEngine` trait.
+pub struct LweCiphertextNoiseMeasurementFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextNoiseMeasurementParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+}
+
+impl<Precision, Engine, SecretKey, Ciphertext, Plaintext, Noise>
+    Fixture<Precision, Engine, (SecretKey, Ciphertext, Plaintext, Noise)>
+    for LweCiphertextNoiseMeasurementFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextNoiseMeasurementEngine<SecretKey, Ciphertext, Plaintext, Noise>,
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    Plaintext: PlaintextEntity,
+    Noise: MeasuredNoise<Precision::Raw>,
+    Maker: SynthesizesLweSecretKey<Precision, SecretKey>
+        + SynthesizesLweCiphertext<Precision, Ciphertext>
+        + SynthesizesPlaintext<Precision, Plaintext>,
+{
+    type Parameters = LweCiphertextNoiseMeasurementParameters;
+    type RepetitionPrototypes = (<Maker as PrototypesLweSecretKey<Precision, Ciphertext::KeyDistribution>>::LweSecretKeyProto, );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, Ciphertext::KeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (SecretKey, Ciphertext, Plaintext);
+    type PostExecutionContext = (SecretKey, Ciphertext, Plaintext, Noise);
+    type Criteria = ();
+    type Outcome = (Vec<f64>, Vec<f64>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextNoiseMeasurementParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                },
+                LweCiphertextNoiseMeasurementParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(1000),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext = Precision::Raw::uniform();
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        (proto_plaintext, proto_ciphertext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext, proto_ciphertext) = sample_proto;
+        let synth_secret_key = maker.synthesize_lwe_secret_key(proto_secret_key);
+        let synth_ciphertext = maker.synthesize_lwe_ciphertext(proto_ciphertext);
+        let synth_plaintext = maker.synthesize_plaintext(proto_plaintext);
+        (synth_secret_key, synth_ciphertext, synth_plaintext)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, ciphertext, plaintext) = context;
+        let noise = unsafe {
+            engine.measure_lwe_ciphertext_noise_unchecked(&secret_key, &ciphertext, &plaintext)
+        };
+        (secret_key, ciphertext, plaintext, noise)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (secret_key, ciphertext, plaintext, noise) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext, proto_ciphertext) = sample_proto;
+        let proto_decrypted_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, proto_ciphertext);
+        maker.destroy_plaintext(plaintext);
+        maker.destroy_lwe_ciphertext(ciphertext);
+        maker.destroy_lwe_secret_key(secret_key);
+        let expected_noise = torus_modular_distance(
+            maker.transform_plaintext_to_raw(&proto_decrypted_plaintext),
+            maker.transform_plaintext_to_raw(proto_plaintext),
+        );
+        (noise.torus_values(), vec![expected_noise])
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (measured, expected): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let measured: Vec<f64> = measured.into_iter().flatten().collect();
+        let expected: Vec<f64> = expected.into_iter().flatten().collect();
+        assert_measured_noise::<Precision::Raw>(&measured, &expected)
+    }
+}
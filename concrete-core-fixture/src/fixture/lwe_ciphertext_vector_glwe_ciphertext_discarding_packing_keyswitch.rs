@@ -1,4 +1,5 @@
-use crate::fixture::{fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms, Fixture};
+use crate::fixture::lwe_ciphertext_discarding_keyswitch::fix_estimate_keyswitch_noise_lwe_to_lwe;
+use crate::fixture::Fixture;
 use crate::generation::prototyping::{
     PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweCiphertextVector,
     PrototypesLweSecretKey, PrototypesPackingKeyswitchKey, PrototypesPlaintextVector,
@@ -10,9 +11,11 @@ use crate::generation::{IntegerPrecision, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
+use concrete_core::prelude::markers::KeyDistributionMarker;
 use concrete_core::prelude::{
     DispersionParameter, GlweCiphertextEntity, LogStandardDev, LweCiphertextCount,
     LweCiphertextVectorEntity, LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine,
@@ -258,22 +261,20 @@ where
         _maker: &mut Maker,
         _repetition_proto: &Self::RepetitionPrototypes,
     ) -> Self::Criteria {
-        let predicted_variance: Variance =
-            fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
-                Precision::Raw,
-                _,
-                _,
-                OutputCiphertext::KeyDistribution,
-            >(
-                parameters.input_lwe_dimension,
-                parameters.input_lwe_noise,
-                parameters.pksk_noise,
-                parameters.decomposition_base_log,
-                parameters.decomposition_level,
-            );
-        (Variance(
-            predicted_variance.0 * parameters.input_lwe_count.0 as f64,
-        ),)
+        let predicted_variance: Variance = fix_estimate_packing_keyswitch_noise::<
+            Precision::Raw,
+            _,
+            _,
+            InputCiphertextVector::KeyDistribution,
+        >(
+            parameters.input_lwe_dimension,
+            parameters.input_lwe_count,
+            parameters.input_lwe_noise,
+            parameters.pksk_noise,
+            parameters.decomposition_base_log,
+            parameters.decomposition_level,
+        );
+        (predicted_variance,)
     }
 
     fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
@@ -291,3 +292,42 @@ where
         assert_noise_distribution(&actual, means.as_slice(), criteria.0)
     }
 }
+
+// The noise of the packing keyswitch is computed as in `concrete_npe`: the constant terms of the
+// keyswitch of each input, i.e. the noise of an LWE to LWE keyswitch, to which add up the
+// non-constant terms of the keyswitches of the other inputs. The constant terms depend on the key
+// distribution, which is mapped to a key kind by the keyswitch fixture.
+fn fix_estimate_packing_keyswitch_noise<T, D1, D2, K>(
+    input_lwe_dimension: LweDimension,
+    input_lwe_count: LweCiphertextCount,
+    dispersion_lwe: D1,
+    dispersion_pksk: D2,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    K: KeyDistributionMarker,
+{
+    let constant_terms = fix_estimate_keyswitch_noise_lwe_to_lwe::<T, D1, D2, K>(
+        input_lwe_dimension,
+        dispersion_lwe,
+        dispersion_pksk,
+        base_log,
+        level,
+    );
+    let non_constant_terms =
+        concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_non_constant_terms::<T, D2>(
+            input_lwe_dimension,
+            dispersion_pksk,
+            base_log,
+            level,
+        );
+    Variance::from_modular_variance::<T>(
+        constant_terms.get_modular_variance::<T>()
+            + input_lwe_count.0.saturating_sub(1) as f64
+                * non_constant_terms.get_modular_variance::<T>(),
+    )
+}
@@ -0,0 +1,176 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+use concrete_core::prelude::{
+    LweCiphertextVectorEntity, LweCiphertextVectorNoiseMeasurementEngine, LweSecretKeyEntity,
+    PlaintextVectorEntity,
+};
+
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertextVector, PrototypesLweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCiphertextVector, SynthesizesLweSecretKey, SynthesizesPlaintextVector,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::measured_noise::MeasuredNoise;
+use crate::raw::statistical_test::{assert_measured_noise, torus_modular_distance};
+
+/// A fixture for the types implementing the `LweCiphertextVectorNoiseMeasurementEngine` trait.
+pub struct LweCiphertextVectorNoiseMeasurementFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextVectorNoiseMeasurementParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+}
+
+impl<Precision, Engine, SecretKey, CiphertextVector, PlaintextVector, Noise>
+    Fixture<Precision, Engine, (SecretKey, CiphertextVector, PlaintextVector, Noise)>
+    for LweCiphertextVectorNoiseMeasurementFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweCiphertextVectorNoiseMeasurementEngine<
+        SecretKey,
+        CiphertextVector,
+        PlaintextVector,
+        Noise,
+    >,
+    SecretKey: LweSecretKeyEntity,
+    CiphertextVector: LweCiphertextVectorEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    PlaintextVector: PlaintextVectorEntity,
+    Noise: MeasuredNoise<Precision::Raw>,
+    Maker: SynthesizesLweSecretKey<Precision, SecretKey>
+        + SynthesizesLweCiphertextVector<Precision, CiphertextVector>
+        + SynthesizesPlaintextVector<Precision, PlaintextVector>,
+{
+    type Parameters = LweCiphertextVectorNoiseMeasurementParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, CiphertextVector::KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<Precision, SecretKey::KeyDistribution>>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (SecretKey, CiphertextVector, PlaintextVector);
+    type PostExecutionContext = (SecretKey, CiphertextVector, PlaintextVector, Noise);
+    type Criteria = ();
+    type Outcome = (Vec<f64>, Vec<f64>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextVectorNoiseMeasurementParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                    lwe_ciphertext_count: LweCiphertextCount(1),
+                },
+                LweCiphertextVectorNoiseMeasurementParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(600),
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.lwe_ciphertext_count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_ciphertext_vector = maker.encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        (proto_plaintext_vector, proto_ciphertext_vector)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector, proto_ciphertext_vector) = sample_proto;
+        (
+            maker.synthesize_lwe_secret_key(proto_secret_key),
+            maker.synthesize_lwe_ciphertext_vector(proto_ciphertext_vector),
+            maker.synthesize_plaintext_vector(proto_plaintext_vector),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, ciphertext_vector, plaintext_vector) = context;
+        let noise = unsafe {
+            engine.measure_lwe_ciphertext_vector_noise_unchecked(
+                &secret_key,
+                &ciphertext_vector,
+                &plaintext_vector,
+            )
+        };
+        (secret_key, ciphertext_vector, plaintext_vector, noise)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (secret_key, ciphertext_vector, plaintext_vector, noise) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector, proto_ciphertext_vector) = sample_proto;
+        let proto_decrypted_plaintext_vector = maker
+            .decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                proto_secret_key,
+                proto_ciphertext_vector,
+            );
+        maker.destroy_plaintext_vector(plaintext_vector);
+        maker.destroy_lwe_ciphertext_vector(ciphertext_vector);
+        maker.destroy_lwe_secret_key(secret_key);
+        let expected_noise = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_decrypted_plaintext_vector)
+            .into_iter()
+            .zip(maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector))
+            .map(|(decrypted, expected)| torus_modular_distance(decrypted, expected))
+            .collect();
+        (noise.torus_values(), expected_noise)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (measured, expected): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let measured: Vec<f64> = measured.into_iter().flatten().collect();
+        let expected: Vec<f64> = expected.into_iter().flatten().collect();
+        assert_measured_noise::<Precision::Raw>(&measured, &expected)
+    }
+}
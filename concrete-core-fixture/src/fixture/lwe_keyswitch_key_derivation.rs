@@ -0,0 +1,171 @@
+use crate::fixture::lwe_secret_key_derivation::random_master_seed_and_label;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::PrototypesLweSecretKey;
+use crate::generation::synthesizing::{SynthesizesLweKeyswitchKey, SynthesizesLweSecretKey};
+use crate::generation::{IntegerPrecision, Maker};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, KeyDerivationLabel, LweDimension, MasterSeed,
+};
+use concrete_core::prelude::{
+    LweKeyswitchKeyDerivationEngine, LweKeyswitchKeyEntity, LweSecretKeyEntity,
+};
+
+/// A fixture for the types implementing the `LweKeyswitchKeyDerivationEngine` trait.
+pub struct LweKeyswitchKeyDerivationFixture;
+
+#[derive(Debug)]
+pub struct LweKeyswitchKeyDerivationParameters {
+    pub input_lwe_dimension: LweDimension,
+    pub output_lwe_dimension: LweDimension,
+    pub level: DecompositionLevelCount,
+    pub base_log: DecompositionBaseLog,
+    pub noise: Variance,
+}
+
+impl<Precision, Engine, InputSecretKey, OutputSecretKey, KeyswitchKey>
+    Fixture<Precision, Engine, (InputSecretKey, OutputSecretKey, KeyswitchKey)>
+    for LweKeyswitchKeyDerivationFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweKeyswitchKeyDerivationEngine<InputSecretKey, OutputSecretKey, KeyswitchKey>,
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: LweSecretKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity<
+            InputKeyDistribution = InputSecretKey::KeyDistribution,
+            OutputKeyDistribution = OutputSecretKey::KeyDistribution,
+        > + PartialEq,
+    Maker: SynthesizesLweSecretKey<Precision, InputSecretKey>
+        + SynthesizesLweSecretKey<Precision, OutputSecretKey>
+        + SynthesizesLweKeyswitchKey<Precision, KeyswitchKey>,
+{
+    type Parameters = LweKeyswitchKeyDerivationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputSecretKey::KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, OutputSecretKey::KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (MasterSeed, KeyDerivationLabel);
+    type PreExecutionContext = (
+        InputSecretKey,
+        OutputSecretKey,
+        MasterSeed,
+        KeyDerivationLabel,
+    );
+    type PostExecutionContext = (
+        InputSecretKey,
+        OutputSecretKey,
+        KeyswitchKey,
+        KeyswitchKey,
+        KeyswitchKey,
+    );
+    type Criteria = ();
+    // Whether the key derived again is the same, and whether the key derived with another label
+    // differs.
+    type Outcome = (bool, bool);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweKeyswitchKeyDerivationParameters {
+                input_lwe_dimension: LweDimension(256),
+                output_lwe_dimension: LweDimension(128),
+                level: DecompositionLevelCount(3),
+                base_log: DecompositionBaseLog(4),
+                noise: Variance(0.00000001),
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_input_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            InputSecretKey::KeyDistribution,
+        >>::new_lwe_secret_key(
+            maker, parameters.input_lwe_dimension
+        );
+        let proto_output_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            OutputSecretKey::KeyDistribution,
+        >>::new_lwe_secret_key(
+            maker, parameters.output_lwe_dimension
+        );
+        (proto_input_secret_key, proto_output_secret_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        random_master_seed_and_label()
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_input_secret_key, proto_output_secret_key) = repetition_proto;
+        let (master_seed, label) = sample_proto;
+        (
+            maker.synthesize_lwe_secret_key(proto_input_secret_key),
+            maker.synthesize_lwe_secret_key(proto_output_secret_key),
+            *master_seed,
+            *label,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_key, output_key, master_seed, label) = context;
+        let mut derive = |label| unsafe {
+            engine.derive_lwe_keyswitch_key_unchecked(
+                master_seed,
+                label,
+                &input_key,
+                &output_key,
+                parameters.level,
+                parameters.base_log,
+                parameters.noise,
+            )
+        };
+        let (key, same_key) = (derive(label), derive(label));
+        let other_key = derive(KeyDerivationLabel(label.0.wrapping_add(1)));
+        (input_key, output_key, key, same_key, other_key)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_key, output_key, key, same_key, other_key) = context;
+        let outcome = (key == same_key, key != other_key);
+        maker.destroy_lwe_secret_key(input_key);
+        maker.destroy_lwe_secret_key(output_key);
+        maker.destroy_lwe_keyswitch_key(key);
+        maker.destroy_lwe_keyswitch_key(same_key);
+        maker.destroy_lwe_keyswitch_key(other_key);
+        outcome
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs.iter().all(|(same, different)| *same && *different)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{PrototypesContainer, PrototypesLweSecretKey};
+use crate::generation::synthesizing::{SynthesizesContainer, SynthesizesLweSecretKey};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweDimension;
+
+use concrete_core::prelude::{LweSecretKeyConsumingRetrievalEngine, LweSecretKeyEntity};
+
+#[derive(Debug)]
+pub struct LweSecretKeyConsumingRetrievalParameters {
+    pub lwe_dimension: LweDimension,
+}
+
+/// A fixture for the types implementing the `LweSecretKeyConsumingRetrievalEngine` trait with LWE
+/// secret keys.
+pub struct LweSecretKeyConsumingRetrievalFixture;
+
+impl<Precision, Engine, SecretKey, Container> Fixture<Precision, Engine, (SecretKey, Container)>
+    for LweSecretKeyConsumingRetrievalFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweSecretKeyConsumingRetrievalEngine<SecretKey, Container>,
+    SecretKey: LweSecretKeyEntity,
+    Maker:
+        SynthesizesLweSecretKey<Precision, SecretKey> + SynthesizesContainer<Precision, Container>,
+{
+    type Parameters = LweSecretKeyConsumingRetrievalParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, SecretKey::KeyDistribution>>::LweSecretKeyProto,
+    );
+    type PreExecutionContext = (SecretKey,);
+    type PostExecutionContext = (Container,);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSecretKeyConsumingRetrievalParameters {
+                    lwe_dimension: LweDimension(1),
+                },
+                LweSecretKeyConsumingRetrievalParameters {
+                    lwe_dimension: LweDimension(512),
+                },
+                LweSecretKeyConsumingRetrievalParameters {
+                    lwe_dimension: LweDimension(630),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_key = Precision::Raw::uniform_between_vec(0..2, parameters.lwe_dimension.0);
+        (maker.transform_raw_vec_to_lwe_secret_key(&raw_key),)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = sample_proto;
+        (maker.synthesize_lwe_secret_key(proto_secret_key),)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key,) = context;
+        let raw_secret_key =
+            unsafe { engine.consume_retrieve_lwe_secret_key_unchecked(secret_key) };
+        (raw_secret_key,)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (proto_secret_key,) = sample_proto;
+        let (raw_secret_key,) = context;
+        let proto_container = maker.unsynthesize_container(raw_secret_key);
+        (
+            maker.transform_lwe_secret_key_to_raw_vec(proto_secret_key),
+            maker.transform_container_to_raw_vec(&proto_container),
+        )
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (Variance(0.),)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+}
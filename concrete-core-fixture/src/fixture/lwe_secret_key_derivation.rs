@@ -0,0 +1,178 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::PrototypesLweSecretKey;
+use crate::generation::synthesizing::SynthesizesLweSecretKey;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{KeyDerivationLabel, LweDimension, MasterSeed};
+use concrete_core::prelude::{LweSecretKeyDerivationEngine, LweSecretKeyEntity};
+
+/// A fixture for the types implementing the `LweSecretKeyDerivationEngine` trait.
+pub struct LweSecretKeyDerivationFixture;
+
+#[derive(Debug)]
+pub struct LweSecretKeyDerivationParameters {
+    pub lwe_dimension: LweDimension,
+}
+
+impl<Precision, Engine, SecretKey> Fixture<Precision, Engine, (SecretKey,)>
+    for LweSecretKeyDerivationFixture
+where
+    Precision: IntegerPrecision,
+    Engine: LweSecretKeyDerivationEngine<SecretKey>,
+    SecretKey: LweSecretKeyEntity,
+    Maker: SynthesizesLweSecretKey<Precision, SecretKey>,
+{
+    type Parameters = LweSecretKeyDerivationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (MasterSeed, KeyDerivationLabel);
+    type PreExecutionContext = (MasterSeed, KeyDerivationLabel);
+    type PostExecutionContext = (SecretKey, SecretKey, SecretKey);
+    type Criteria = ();
+    // The key, the same key derived again, and the key derived with another label.
+    type Outcome = (
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+    );
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSecretKeyDerivationParameters {
+                    lwe_dimension: LweDimension(1),
+                },
+                LweSecretKeyDerivationParameters {
+                    lwe_dimension: LweDimension(630),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        random_master_seed_and_label()
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        *sample_proto
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (master_seed, label) = context;
+        let other_label = KeyDerivationLabel(label.0.wrapping_add(1));
+        unsafe {
+            (
+                engine.derive_lwe_secret_key_unchecked(
+                    master_seed,
+                    label,
+                    parameters.lwe_dimension,
+                ),
+                engine.derive_lwe_secret_key_unchecked(
+                    master_seed,
+                    label,
+                    parameters.lwe_dimension,
+                ),
+                engine.derive_lwe_secret_key_unchecked(
+                    master_seed,
+                    other_label,
+                    parameters.lwe_dimension,
+                ),
+            )
+        }
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (key, same_key, other_key) = context;
+        let mut to_raw = |key| {
+            let proto_key = maker.unsynthesize_lwe_secret_key(key);
+            maker.transform_lwe_secret_key_to_raw_vec(&proto_key)
+        };
+        (to_raw(key), to_raw(same_key), to_raw(other_key))
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let keys: Vec<_> = outputs.iter().map(|(key, ..)| key.as_slice()).collect();
+        let other_keys: Vec<_> = outputs.iter().map(|(.., other)| other.as_slice()).collect();
+        outputs.iter().all(|(key, same_key, _)| key == same_key)
+            && are_uniform_binary(keys.as_slice())
+            && are_independent(keys.as_slice(), other_keys.as_slice())
+    }
+}
+
+// Samples a random master seed and a random label.
+pub(crate) fn random_master_seed_and_label() -> (MasterSeed, KeyDerivationLabel) {
+    let master_seed = ((u64::uniform() as u128) << 64) | u64::uniform() as u128;
+    (
+        MasterSeed(master_seed),
+        KeyDerivationLabel(u64::uniform() as usize),
+    )
+}
+
+// Checks that the keys are made of binary coefficients, about half of them being ones.
+pub(crate) fn are_uniform_binary<Raw: UnsignedInteger>(keys: &[&[Raw]]) -> bool {
+    let coefficients = keys.iter().flat_map(|key| key.iter());
+    let (count, ones) = coefficients.fold((0usize, 0usize), |(count, ones), coef| {
+        (count + 1, ones + (*coef == Raw::ONE) as usize)
+    });
+    let all_binary = keys.iter().all(|key| {
+        key.iter()
+            .all(|coef| *coef == Raw::ZERO || *coef == Raw::ONE)
+    });
+    all_binary && is_about_half(ones, count)
+}
+
+// Checks that two lists of binary keys are independent, i.e. that they match on about half of
+// their coefficients.
+pub(crate) fn are_independent<Raw: UnsignedInteger>(
+    first_keys: &[&[Raw]],
+    second_keys: &[&[Raw]],
+) -> bool {
+    let pairs = first_keys
+        .iter()
+        .zip(second_keys.iter())
+        .flat_map(|(first, second)| first.iter().zip(second.iter()));
+    let (count, matching) = pairs.fold((0usize, 0usize), |(count, matching), (a, b)| {
+        (count + 1, matching + (a == b) as usize)
+    });
+    is_about_half(matching, count)
+}
+
+// Checks that `n` is within six standard deviations of the expected number of successes of `count`
+// fair coin flips.
+fn is_about_half(n: usize, count: usize) -> bool {
+    let deviation = (n as f64 - count as f64 / 2.).abs();
+    deviation <= 6. * (count as f64 / 4.).sqrt()
+}
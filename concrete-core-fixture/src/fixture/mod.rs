@@ -428,3 +428,45 @@ pub use lwe_bootstrap_key_discarding_conversion::*;
 
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch::*;
+
+mod glwe_ciphertext_vector_creation;
+pub use glwe_ciphertext_vector_creation::*;
+
+mod glwe_ciphertext_vector_consuming_retrieval;
+pub use glwe_ciphertext_vector_consuming_retrieval::*;
+
+mod lwe_secret_key_from_container_creation;
+pub use lwe_secret_key_from_container_creation::*;
+
+mod lwe_secret_key_consuming_retrieval;
+pub use lwe_secret_key_consuming_retrieval::*;
+
+mod glwe_secret_key_from_container_creation;
+pub use glwe_secret_key_from_container_creation::*;
+
+mod glwe_secret_key_consuming_retrieval;
+pub use glwe_secret_key_consuming_retrieval::*;
+
+mod lwe_secret_key_derivation;
+pub use lwe_secret_key_derivation::*;
+
+mod glwe_secret_key_derivation;
+pub use glwe_secret_key_derivation::*;
+
+mod lwe_keyswitch_key_derivation;
+pub use lwe_keyswitch_key_derivation::*;
+
+mod lwe_bootstrap_key_derivation;
+pub use lwe_bootstrap_key_derivation::*;
+
+mod lwe_ciphertext_noise_measurement;
+pub use lwe_ciphertext_noise_measurement::*;
+
+mod lwe_ciphertext_vector_noise_measurement;
+pub use lwe_ciphertext_vector_noise_measurement::*;
+
+mod glwe_ciphertext_noise_measurement;
+pub use glwe_ciphertext_noise_measurement::*;
+
+mod glwe_ciphertext_vector_noise_measurement;
+pub use glwe_ciphertext_vector_noise_measurement::*;
@@ -6,9 +6,12 @@ use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
 use crate::generation::prototyping::plaintext_vector::PrototypesPlaintextVector;
 use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
 use concrete_commons::dispersion::Variance;
-use concrete_commons::parameters::{GlweCiphertextCount, GlweSize, PlaintextCount};
+use concrete_commons::parameters::{
+    GlweCiphertextCount, GlweDimension, GlweSize, PlaintextCount, PolynomialSize,
+};
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
 use concrete_core::prelude::{
+    GlweCiphertextVectorConsumingRetrievalEngine, GlweCiphertextVectorCreationEngine,
     GlweCiphertextVectorDecryptionEngine, GlweCiphertextVectorEncryptionEngine,
     GlweCiphertextVectorTrivialDecryptionEngine, GlweCiphertextVectorTrivialEncryptionEngine,
     PlaintextVectorCreationEngine,
@@ -55,6 +58,18 @@ pub trait PrototypesGlweCiphertextVector<
         secret_key: &Self::GlweSecretKeyProto,
         ciphertext: &Self::GlweCiphertextVectorProto,
     ) -> Self::PlaintextVectorProto;
+
+    fn transform_raw_vec_to_glwe_ciphertext_vector(
+        &mut self,
+        raw: &[Precision::Raw],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweCiphertextVectorProto;
+
+    fn transform_glwe_ciphertext_vector_to_raw_vec(
+        &mut self,
+        ciphertext_vector: &Self::GlweCiphertextVectorProto,
+    ) -> Vec<Precision::Raw>;
 }
 
 impl PrototypesGlweCiphertextVector<Precision32, BinaryKeyDistribution> for Maker {
@@ -133,6 +148,29 @@ impl PrototypesGlweCiphertextVector<Precision32, BinaryKeyDistribution> for Make
                 .unwrap(),
         )
     }
+
+    fn transform_raw_vec_to_glwe_ciphertext_vector(
+        &mut self,
+        raw: &[u32],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweCiphertextVectorProto {
+        ProtoBinaryGlweCiphertextVector32(
+            self.core_engine
+                .create_glwe_ciphertext_vector(raw.to_owned(), glwe_dimension, polynomial_size)
+                .unwrap(),
+        )
+    }
+
+    fn transform_glwe_ciphertext_vector_to_raw_vec(
+        &mut self,
+        ciphertext_vector: &Self::GlweCiphertextVectorProto,
+    ) -> Vec<u32> {
+        let ciphertext_vector = ciphertext_vector.0.to_owned();
+        self.core_engine
+            .consume_retrieve_glwe_ciphertext_vector(ciphertext_vector)
+            .unwrap()
+    }
 }
 
 impl PrototypesGlweCiphertextVector<Precision64, BinaryKeyDistribution> for Maker {
@@ -210,4 +248,27 @@ impl PrototypesGlweCiphertextVector<Precision64, BinaryKeyDistribution> for Make
                 .unwrap(),
         )
     }
+
+    fn transform_raw_vec_to_glwe_ciphertext_vector(
+        &mut self,
+        raw: &[u64],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweCiphertextVectorProto {
+        ProtoBinaryGlweCiphertextVector64(
+            self.core_engine
+                .create_glwe_ciphertext_vector(raw.to_owned(), glwe_dimension, polynomial_size)
+                .unwrap(),
+        )
+    }
+
+    fn transform_glwe_ciphertext_vector_to_raw_vec(
+        &mut self,
+        ciphertext_vector: &Self::GlweCiphertextVectorProto,
+    ) -> Vec<u64> {
+        let ciphertext_vector = ciphertext_vector.0.to_owned();
+        self.core_engine
+            .consume_retrieve_glwe_ciphertext_vector(ciphertext_vector)
+            .unwrap()
+    }
 }
@@ -6,7 +6,10 @@ use crate::generation::prototyping::PrototypesLweSecretKey;
 use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
-use concrete_core::prelude::{GlweSecretKeyCreationEngine, GlweToLweSecretKeyTransmutationEngine};
+use concrete_core::prelude::{
+    GlweSecretKeyConsumingRetrievalEngine, GlweSecretKeyCreationEngine,
+    GlweSecretKeyFromContainerCreationEngine, GlweToLweSecretKeyTransmutationEngine,
+};
 
 /// A trait allowing to manipulate GLWE secret key prototypes.
 pub trait PrototypesGlweSecretKey<
@@ -27,6 +30,15 @@ pub trait PrototypesGlweSecretKey<
         &mut self,
         glwe_key: &Self::GlweSecretKeyProto,
     ) -> Self::LweSecretKeyProto;
+    fn transform_raw_vec_to_glwe_secret_key(
+        &mut self,
+        raw: &[Precision::Raw],
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto;
+    fn transform_glwe_secret_key_to_raw_vec(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+    ) -> Vec<Precision::Raw>;
 }
 
 impl PrototypesGlweSecretKey<Precision32, BinaryKeyDistribution> for Maker {
@@ -54,6 +66,28 @@ impl PrototypesGlweSecretKey<Precision32, BinaryKeyDistribution> for Maker {
                 .unwrap(),
         )
     }
+
+    fn transform_raw_vec_to_glwe_secret_key(
+        &mut self,
+        raw: &[u32],
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoBinaryGlweSecretKey32(
+            self.core_engine
+                .create_glwe_secret_key_from_container(raw.to_owned(), polynomial_size)
+                .unwrap(),
+        )
+    }
+
+    fn transform_glwe_secret_key_to_raw_vec(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+    ) -> Vec<u32> {
+        let secret_key = secret_key.0.to_owned();
+        self.core_engine
+            .consume_retrieve_glwe_secret_key(secret_key)
+            .unwrap()
+    }
 }
 
 impl PrototypesGlweSecretKey<Precision64, BinaryKeyDistribution> for Maker {
@@ -81,4 +115,26 @@ impl PrototypesGlweSecretKey<Precision64, BinaryKeyDistribution> for Maker {
                 .unwrap(),
         )
     }
+
+    fn transform_raw_vec_to_glwe_secret_key(
+        &mut self,
+        raw: &[u64],
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoBinaryGlweSecretKey64(
+            self.core_engine
+                .create_glwe_secret_key_from_container(raw.to_owned(), polynomial_size)
+                .unwrap(),
+        )
+    }
+
+    fn transform_glwe_secret_key_to_raw_vec(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+    ) -> Vec<u64> {
+        let secret_key = secret_key.0.to_owned();
+        self.core_engine
+            .consume_retrieve_glwe_secret_key(secret_key)
+            .unwrap()
+    }
 }
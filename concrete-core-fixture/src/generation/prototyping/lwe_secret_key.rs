@@ -4,7 +4,10 @@ use crate::generation::prototypes::{
 use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::markers::{BinaryKeyDistribution, KeyDistributionMarker};
-use concrete_core::prelude::LweSecretKeyCreationEngine;
+use concrete_core::prelude::{
+    LweSecretKeyConsumingRetrievalEngine, LweSecretKeyCreationEngine,
+    LweSecretKeyFromContainerCreationEngine,
+};
 
 /// A trait allowing to manipulate lwe secret key prototypes.
 pub trait PrototypesLweSecretKey<
@@ -17,6 +20,14 @@ pub trait PrototypesLweSecretKey<
         KeyDistribution = KeyDistribution,
     >;
     fn new_lwe_secret_key(&mut self, lwe_dimension: LweDimension) -> Self::LweSecretKeyProto;
+    fn transform_raw_vec_to_lwe_secret_key(
+        &mut self,
+        raw: &[Precision::Raw],
+    ) -> Self::LweSecretKeyProto;
+    fn transform_lwe_secret_key_to_raw_vec(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+    ) -> Vec<Precision::Raw>;
 }
 
 impl PrototypesLweSecretKey<Precision32, BinaryKeyDistribution> for Maker {
@@ -29,6 +40,24 @@ impl PrototypesLweSecretKey<Precision32, BinaryKeyDistribution> for Maker {
                 .unwrap(),
         )
     }
+
+    fn transform_raw_vec_to_lwe_secret_key(&mut self, raw: &[u32]) -> Self::LweSecretKeyProto {
+        ProtoBinaryLweSecretKey32(
+            self.core_engine
+                .create_lwe_secret_key_from_container(raw.to_owned())
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_secret_key_to_raw_vec(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+    ) -> Vec<u32> {
+        let secret_key = secret_key.0.to_owned();
+        self.core_engine
+            .consume_retrieve_lwe_secret_key(secret_key)
+            .unwrap()
+    }
 }
 
 impl PrototypesLweSecretKey<Precision64, BinaryKeyDistribution> for Maker {
@@ -41,4 +70,22 @@ impl PrototypesLweSecretKey<Precision64, BinaryKeyDistribution> for Maker {
                 .unwrap(),
         )
     }
+
+    fn transform_raw_vec_to_lwe_secret_key(&mut self, raw: &[u64]) -> Self::LweSecretKeyProto {
+        ProtoBinaryLweSecretKey64(
+            self.core_engine
+                .create_lwe_secret_key_from_container(raw.to_owned())
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_secret_key_to_raw_vec(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+    ) -> Vec<u64> {
+        let secret_key = secret_key.0.to_owned();
+        self.core_engine
+            .consume_retrieve_lwe_secret_key(secret_key)
+            .unwrap()
+    }
 }
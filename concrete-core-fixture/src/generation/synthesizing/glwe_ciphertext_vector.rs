@@ -69,4 +69,215 @@ mod backend_core {
             self.core_engine.destroy(entity).unwrap();
         }
     }
+
+    use concrete_core::prelude::{
+        GlweCiphertextVectorConsumingRetrievalEngine, GlweCiphertextVectorCreationEngine,
+        GlweCiphertextVectorEntity, GlweCiphertextVectorView32, GlweCiphertextVectorView64,
+    };
+
+    impl<'a> SynthesizesGlweCiphertextVector<Precision32, GlweCiphertextVectorView32<'a>> for Maker {
+        fn synthesize_glwe_ciphertext_vector(
+            &mut self,
+            prototype: &Self::GlweCiphertextVectorProto,
+        ) -> GlweCiphertextVectorView32<'a> {
+            let ciphertext_vector = prototype.0.to_owned();
+            let glwe_dimension = ciphertext_vector.glwe_dimension();
+            let polynomial_size = ciphertext_vector.polynomial_size();
+            let container = self
+                .core_engine
+                .consume_retrieve_glwe_ciphertext_vector(ciphertext_vector)
+                .unwrap();
+            self.core_engine
+                .create_glwe_ciphertext_vector(
+                    container.leak() as &[u32],
+                    glwe_dimension,
+                    polynomial_size,
+                )
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_ciphertext_vector(
+            &mut self,
+            entity: GlweCiphertextVectorView32,
+        ) -> Self::GlweCiphertextVectorProto {
+            let glwe_dimension = entity.glwe_dimension();
+            let polynomial_size = entity.polynomial_size();
+            let slice = self
+                .core_engine
+                .consume_retrieve_glwe_ciphertext_vector(entity)
+                .unwrap();
+            let ciphertext_vector = self
+                .core_engine
+                .create_glwe_ciphertext_vector(
+                    unsafe {
+                        Vec::from_raw_parts(slice.as_ptr() as *mut u32, slice.len(), slice.len())
+                    },
+                    glwe_dimension,
+                    polynomial_size,
+                )
+                .unwrap();
+            ProtoBinaryGlweCiphertextVector32(ciphertext_vector)
+        }
+
+        fn destroy_glwe_ciphertext_vector(&mut self, entity: GlweCiphertextVectorView32) {
+            // Re-construct the vector so that it frees memory when it's dropped
+            let slice = self
+                .core_engine
+                .consume_retrieve_glwe_ciphertext_vector(entity)
+                .unwrap();
+            unsafe { Vec::from_raw_parts(slice.as_ptr() as *mut u32, slice.len(), slice.len()) };
+        }
+    }
+
+    impl<'a> SynthesizesGlweCiphertextVector<Precision64, GlweCiphertextVectorView64<'a>> for Maker {
+        fn synthesize_glwe_ciphertext_vector(
+            &mut self,
+            prototype: &Self::GlweCiphertextVectorProto,
+        ) -> GlweCiphertextVectorView64<'a> {
+            let ciphertext_vector = prototype.0.to_owned();
+            let glwe_dimension = ciphertext_vector.glwe_dimension();
+            let polynomial_size = ciphertext_vector.polynomial_size();
+            let container = self
+                .core_engine
+                .consume_retrieve_glwe_ciphertext_vector(ciphertext_vector)
+                .unwrap();
+            self.core_engine
+                .create_glwe_ciphertext_vector(
+                    container.leak() as &[u64],
+                    glwe_dimension,
+                    polynomial_size,
+                )
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_ciphertext_vector(
+            &mut self,
+            entity: GlweCiphertextVectorView64,
+        ) -> Self::GlweCiphertextVectorProto {
+            let glwe_dimension = entity.glwe_dimension();
+            let polynomial_size = entity.polynomial_size();
+            let slice = self
+                .core_engine
+                .consume_retrieve_glwe_ciphertext_vector(entity)
+                .unwrap();
+            let ciphertext_vector = self
+                .core_engine
+                .create_glwe_ciphertext_vector(
+                    unsafe {
+                        Vec::from_raw_parts(slice.as_ptr() as *mut u64, slice.len(), slice.len())
+                    },
+                    glwe_dimension,
+                    polynomial_size,
+                )
+                .unwrap();
+            ProtoBinaryGlweCiphertextVector64(ciphertext_vector)
+        }
+
+        fn destroy_glwe_ciphertext_vector(&mut self, entity: GlweCiphertextVectorView64) {
+            // Re-construct the vector so that it frees memory when it's dropped
+            let slice = self
+                .core_engine
+                .consume_retrieve_glwe_ciphertext_vector(entity)
+                .unwrap();
+            unsafe { Vec::from_raw_parts(slice.as_ptr() as *mut u64, slice.len(), slice.len()) };
+        }
+    }
+
+    use concrete_core::prelude::{GlweCiphertextVectorMutView32, GlweCiphertextVectorMutView64};
+
+    impl<'a> SynthesizesGlweCiphertextVector<Precision32, GlweCiphertextVectorMutView32<'a>> for Maker {
+        fn synthesize_glwe_ciphertext_vector(
+            &mut self,
+            prototype: &Self::GlweCiphertextVectorProto,
+        ) -> GlweCiphertextVectorMutView32<'a> {
+            let ciphertext_vector = prototype.0.to_owned();
+            let glwe_dimension = ciphertext_vector.glwe_dimension();
+            let polynomial_size = ciphertext_vector.polynomial_size();
+            let container = self
+                .core_engine
+                .consume_retrieve_glwe_ciphertext_vector(ciphertext_vector)
+                .unwrap();
+            self.core_engine
+                .create_glwe_ciphertext_vector(container.leak(), glwe_dimension, polynomial_size)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_ciphertext_vector(
+            &mut self,
+            entity: GlweCiphertextVectorMutView32,
+        ) -> Self::GlweCiphertextVectorProto {
+            let glwe_dimension = entity.glwe_dimension();
+            let polynomial_size = entity.polynomial_size();
+            let slice = self
+                .core_engine
+                .consume_retrieve_glwe_ciphertext_vector(entity)
+                .unwrap();
+            let ciphertext_vector = self
+                .core_engine
+                .create_glwe_ciphertext_vector(
+                    unsafe { Vec::from_raw_parts(slice.as_mut_ptr(), slice.len(), slice.len()) },
+                    glwe_dimension,
+                    polynomial_size,
+                )
+                .unwrap();
+            ProtoBinaryGlweCiphertextVector32(ciphertext_vector)
+        }
+
+        fn destroy_glwe_ciphertext_vector(&mut self, entity: GlweCiphertextVectorMutView32) {
+            // Re-construct the vector so that it frees memory when it's dropped
+            let slice = self
+                .core_engine
+                .consume_retrieve_glwe_ciphertext_vector(entity)
+                .unwrap();
+            unsafe { Vec::from_raw_parts(slice.as_mut_ptr(), slice.len(), slice.len()) };
+        }
+    }
+
+    impl<'a> SynthesizesGlweCiphertextVector<Precision64, GlweCiphertextVectorMutView64<'a>> for Maker {
+        fn synthesize_glwe_ciphertext_vector(
+            &mut self,
+            prototype: &Self::GlweCiphertextVectorProto,
+        ) -> GlweCiphertextVectorMutView64<'a> {
+            let ciphertext_vector = prototype.0.to_owned();
+            let glwe_dimension = ciphertext_vector.glwe_dimension();
+            let polynomial_size = ciphertext_vector.polynomial_size();
+            let container = self
+                .core_engine
+                .consume_retrieve_glwe_ciphertext_vector(ciphertext_vector)
+                .unwrap();
+            self.core_engine
+                .create_glwe_ciphertext_vector(container.leak(), glwe_dimension, polynomial_size)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_ciphertext_vector(
+            &mut self,
+            entity: GlweCiphertextVectorMutView64,
+        ) -> Self::GlweCiphertextVectorProto {
+            let glwe_dimension = entity.glwe_dimension();
+            let polynomial_size = entity.polynomial_size();
+            let slice = self
+                .core_engine
+                .consume_retrieve_glwe_ciphertext_vector(entity)
+                .unwrap();
+            let ciphertext_vector = self
+                .core_engine
+                .create_glwe_ciphertext_vector(
+                    unsafe { Vec::from_raw_parts(slice.as_mut_ptr(), slice.len(), slice.len()) },
+                    glwe_dimension,
+                    polynomial_size,
+                )
+                .unwrap();
+            ProtoBinaryGlweCiphertextVector64(ciphertext_vector)
+        }
+
+        fn destroy_glwe_ciphertext_vector(&mut self, entity: GlweCiphertextVectorMutView64) {
+            // Re-construct the vector so that it frees memory when it's dropped
+            let slice = self
+                .core_engine
+                .consume_retrieve_glwe_ciphertext_vector(entity)
+                .unwrap();
+            unsafe { Vec::from_raw_parts(slice.as_mut_ptr(), slice.len(), slice.len()) };
+        }
+    }
 }
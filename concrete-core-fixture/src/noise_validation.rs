@@ -0,0 +1,244 @@
+//! A module validating the noise formulas of `concrete-npe` against the fixtures.
+//!
+//! For a fixture whose criterion is the variance predicted by `concrete-npe`, the engine is
+//! executed many times for every set of parameters, and the noise of the outputs is gathered in a
+//! single sample. The variance of this sample gives a chi-squared confidence interval for the true
+//! variance of the output noise, which must contain the predicted variance for the formula to be
+//! considered valid.
+//!
+//! The noise values of a single output (e.g. the coefficients of a GLWE ciphertext) are not
+//! independent, and neither are the outputs computed with the same keys. The confidence interval
+//! is hence computed with one degree of freedom per execution rather than per noise value, which
+//! makes it wider than the nominal one.
+use crate::fixture::Fixture;
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::torus_modular_distance;
+use crate::SampleSize;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_core::prelude::AbstractEngine;
+use concrete_npe::SampleStatistics;
+use std::fmt;
+
+/// A trait for fixture outcomes made of expected and actual raw plaintexts, from which the noise
+/// of the output can be extracted.
+pub trait NoiseOutcome {
+    /// Returns the noise of every output plaintext, as torus values.
+    fn noise_samples(&self) -> Vec<f64>;
+}
+
+impl<Raw> NoiseOutcome for (Raw, Raw)
+where
+    Raw: RawUnsignedIntegers,
+{
+    fn noise_samples(&self) -> Vec<f64> {
+        vec![torus_modular_distance(self.0, self.1)]
+    }
+}
+
+impl<Raw> NoiseOutcome for (Vec<Raw>, Vec<Raw>)
+where
+    Raw: RawUnsignedIntegers,
+{
+    fn noise_samples(&self) -> Vec<f64> {
+        self.0
+            .iter()
+            .zip(self.1.iter())
+            .map(|(expected, actual)| torus_modular_distance(*expected, *actual))
+            .collect()
+    }
+}
+
+/// The outcome of the validation of a noise formula for a single set of parameters.
+#[derive(Clone, Debug)]
+pub struct NoiseValidationReport {
+    /// The parameters the engine was executed with.
+    pub parameters: String,
+    /// The number of engine executions the noise values were gathered from.
+    pub execution_count: usize,
+    /// The number of noise values the measured variance was computed on.
+    pub sample_count: usize,
+    /// The variance predicted by `concrete-npe`.
+    pub predicted: Variance,
+    /// The variance of the measured noise.
+    pub measured: Variance,
+    /// The bounds of the confidence interval of the true variance.
+    pub interval: (Variance, Variance),
+}
+
+impl NoiseValidationReport {
+    /// Builds the report of a sample of noise values gathered from `execution_count` executions,
+    /// with a confidence interval of a given level.
+    pub fn new(
+        parameters: String,
+        predicted: Variance,
+        noise_samples: &[f64],
+        execution_count: usize,
+        confidence: f64,
+    ) -> NoiseValidationReport {
+        let statistics = SampleStatistics::from_samples(noise_samples);
+        let degrees_of_freedom = (execution_count.min(statistics.sample_count) - 1) as f64;
+        let scaled_variance = degrees_of_freedom * statistics.variance.get_variance();
+        let alpha = 1. - confidence;
+        NoiseValidationReport {
+            parameters,
+            execution_count,
+            sample_count: statistics.sample_count,
+            predicted,
+            measured: statistics.variance,
+            interval: (
+                Variance(
+                    scaled_variance / chi_squared_quantile(1. - alpha / 2., degrees_of_freedom),
+                ),
+                Variance(scaled_variance / chi_squared_quantile(alpha / 2., degrees_of_freedom)),
+            ),
+        }
+    }
+
+    /// Returns whether the predicted variance lies in the confidence interval of the true
+    /// variance.
+    pub fn is_valid(&self) -> bool {
+        let predicted = self.predicted.get_variance();
+        self.interval.0.get_variance() <= predicted && predicted <= self.interval.1.get_variance()
+    }
+}
+
+impl fmt::Display for NoiseValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "| {:>9.4} | {:>9.4} | [{:>9.4}, {:>9.4}] | {:>10} | {:>8} | {:>6} | {}",
+            self.predicted.get_log_standard_dev(),
+            self.measured.get_log_standard_dev(),
+            self.interval.0.get_log_standard_dev(),
+            self.interval.1.get_log_standard_dev(),
+            self.execution_count,
+            self.sample_count,
+            if self.is_valid() { "ok" } else { "FAIL" },
+            self.parameters
+        )
+    }
+}
+
+/// A table of noise validation reports, displayed with one row per set of parameters.
+///
+/// The variances are displayed as base 2 logarithms of standard deviations.
+pub struct NoiseValidationTable<'a> {
+    /// The name of the validated fixture.
+    pub name: &'a str,
+    /// The reports of the validated fixture.
+    pub reports: &'a [NoiseValidationReport],
+}
+
+impl<'a> fmt::Display for NoiseValidationTable<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.name)?;
+        writeln!(
+            f,
+            "| {:>9} | {:>9} | {:^22} | {:>10} | {:>8} | {:>6} | parameters",
+            "predicted", "measured", "confidence interval", "executions", "samples", "status"
+        )?;
+        for report in self.reports.iter() {
+            writeln!(f, "{}", report)?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates the noise formula used as criterion by a fixture, for every set of parameters of the
+/// fixture.
+///
+/// For every set of parameters, a single set of repetition prototypes is generated, and
+/// `sample_size` executions of the engine are sampled. All the noise values of the outputs are
+/// used to compute a confidence interval of level `confidence` for the variance of the noise.
+pub fn validate_noise_formula<Fix, Precision, Engine, RelatedEntities>(
+    maker: &mut Maker,
+    engine: &mut Engine,
+    sample_size: SampleSize,
+    confidence: f64,
+) -> Vec<NoiseValidationReport>
+where
+    Fix: Fixture<Precision, Engine, RelatedEntities, Criteria = (Variance,)>,
+    Fix::Outcome: NoiseOutcome,
+    Precision: IntegerPrecision,
+    Engine: AbstractEngine,
+{
+    Fix::generate_parameters_iterator()
+        .map(|parameters| {
+            let repetition_proto = Fix::generate_random_repetition_prototypes(&parameters, maker);
+            let outputs = Fix::sample(maker, engine, &parameters, &repetition_proto, sample_size);
+            let (predicted,) = Fix::compute_criteria(&parameters, maker, &repetition_proto);
+            let noise_samples: Vec<f64> = outputs
+                .iter()
+                .flat_map(|output| output.noise_samples())
+                .collect();
+            NoiseValidationReport::new(
+                format!("{:?}", parameters),
+                predicted,
+                noise_samples.as_slice(),
+                outputs.len(),
+                confidence,
+            )
+        })
+        .collect()
+}
+
+// Computes the quantile of order `p` of the chi-squared distribution with `k` degrees of
+// freedom, using the Wilson-Hilferty approximation, which is accurate for large `k`.
+fn chi_squared_quantile(p: f64, k: f64) -> f64 {
+    let spread = 2. / (9. * k);
+    k * (1. - spread + normal_quantile(p) * spread.sqrt()).powi(3)
+}
+
+// Computes the quantile of order `p` of the standard normal distribution, using the rational
+// approximation of Acklam, which has a relative error below 1.15e-9.
+fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.024_25;
+    assert!(
+        0. < p && p < 1.,
+        "The order of a quantile must be in ]0, 1[."
+    );
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.)
+    };
+    if p < P_LOW {
+        tail((-2. * p.ln()).sqrt())
+    } else if p <= 1. - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.)
+    } else {
+        -tail((-2. * (1. - p).ln()).sqrt())
+    }
+}
@@ -0,0 +1,40 @@
+//! A module containing the conversion of measured noises to torus values.
+use crate::raw::generation::RawUnsignedIntegers;
+
+/// A trait for the types used by the noise measurement engines to represent a measured noise.
+///
+/// The implementors are converted to their torus values in $[-0.5, 0.5)$, so that the noises
+/// measured with different representations can be compared with the ones computed from raw
+/// integers of type `Raw`.
+pub trait MeasuredNoise<Raw: RawUnsignedIntegers> {
+    /// Returns the torus values of the measured noise.
+    fn torus_values(&self) -> Vec<f64>;
+}
+
+impl MeasuredNoise<u32> for i32 {
+    fn torus_values(&self) -> Vec<f64> {
+        vec![*self as f64 / 2_f64.powi(32)]
+    }
+}
+
+impl MeasuredNoise<u64> for i64 {
+    fn torus_values(&self) -> Vec<f64> {
+        vec![*self as f64 / 2_f64.powi(64)]
+    }
+}
+
+impl<Raw: RawUnsignedIntegers> MeasuredNoise<Raw> for f64 {
+    fn torus_values(&self) -> Vec<f64> {
+        vec![*self]
+    }
+}
+
+impl<Raw, Noise> MeasuredNoise<Raw> for Vec<Noise>
+where
+    Raw: RawUnsignedIntegers,
+    Noise: MeasuredNoise<Raw>,
+{
+    fn torus_values(&self) -> Vec<f64> {
+        self.iter().flat_map(MeasuredNoise::torus_values).collect()
+    }
+}
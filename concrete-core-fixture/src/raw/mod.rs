@@ -5,4 +5,5 @@
 //! `u64` types.
 
 pub mod generation;
+pub mod measured_noise;
 pub mod statistical_test;
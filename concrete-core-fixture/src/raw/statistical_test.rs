@@ -90,7 +90,7 @@ where
     true
 }
 
-fn torus_modular_distance<T: RawUnsignedIntegers>(first: T, other: T) -> f64 {
+pub(crate) fn torus_modular_distance<T: RawUnsignedIntegers>(first: T, other: T) -> f64 {
     let d0 = first.wrapping_sub(other);
     let d1 = other.wrapping_sub(first);
     if d0 < d1 {
@@ -101,3 +101,19 @@ fn torus_modular_distance<T: RawUnsignedIntegers>(first: T, other: T) -> f64 {
         -d / 2_f64.powi(T::BITS as i32)
     }
 }
+
+/// A function checking that measured noises match the expected ones.
+///
+/// Both the `measured` and `expected` noises are torus values. They are considered to match when
+/// they are at most one unit of a `Raw` integer apart, which accounts for the rounding of the
+/// conversion to floating point.
+pub fn assert_measured_noise<Raw>(measured: &[f64], expected: &[f64]) -> bool
+where
+    Raw: RawUnsignedIntegers,
+{
+    measured.len() == expected.len()
+        && measured
+            .iter()
+            .zip(expected.iter())
+            .all(|(m, e)| (m - e).abs() <= 2_f64.powi(-(Raw::BITS as i32)))
+}
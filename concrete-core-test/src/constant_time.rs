@@ -0,0 +1,115 @@
+//! Timing-leakage audits of the secret-dependent routines of the core backend.
+//!
+//! Every audit compares the timings of a routine on a fixed secret (the zero key or the zero
+//! value) against the timings on uniformly sampled secrets. See the [`dudect`](crate::dudect)
+//! module for details about the statistical test. The audits are only meaningful in release mode,
+//! and should be run one at a time on an otherwise idle machine. For this reason, they are ignored
+//! by default, and executed by the `test_constant_time` task.
+use crate::dudect::{audit, InputClass, MEASUREMENTS};
+use concrete_core::backends::core::private::crypto::encoding::Plaintext;
+use concrete_core::backends::core::private::crypto::ggsw::StandardGgswCiphertext;
+use concrete_core::backends::core::private::crypto::lwe::LweCiphertext;
+use concrete_core::backends::core::private::crypto::secret::generators::{
+    EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use concrete_core::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+use concrete_core::backends::core::private::math::decomposition::SignedDecomposer;
+use concrete_core::backends::core::private::math::random::RandomGenerator;
+use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+use concrete_core::prelude::*;
+
+#[test]
+#[ignore]
+fn test_lwe_encryption_is_constant_time() {
+    let lwe_dimension = LweDimension(630);
+    let noise = Variance(2f64.powi(-30));
+    let fixed_key = LweSecretKey::binary_from_container(vec![0u64; lwe_dimension.0]);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    let mut ciphertext = LweCiphertext::allocate(0u64, lwe_dimension.to_lwe_size());
+    let report = audit(
+        MEASUREMENTS,
+        |class| match class {
+            InputClass::Fixed => fixed_key.clone(),
+            InputClass::Random => {
+                LweSecretKey::generate_binary(lwe_dimension, &mut secret_generator)
+            }
+        },
+        |key| {
+            key.encrypt_lwe(
+                &mut ciphertext,
+                &Plaintext(1u64 << 60),
+                noise,
+                &mut encryption_generator,
+            );
+            ciphertext.get_body().0
+        },
+    );
+    println!("LWE encryption: {}", report);
+    assert!(!report.is_leaking(), "LWE encryption: {}", report);
+}
+
+#[test]
+#[ignore]
+fn test_ggsw_constant_encryption_is_constant_time() {
+    let glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(256);
+    let noise = Variance(2f64.powi(-30));
+    let fixed_key = GlweSecretKey::binary_from_container(
+        vec![0u64; glwe_dimension.0 * polynomial_size.0],
+        polynomial_size,
+    );
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    let mut ciphertext = StandardGgswCiphertext::allocate(
+        0u64,
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        DecompositionLevelCount(3),
+        DecompositionBaseLog(7),
+    );
+    let report = audit(
+        MEASUREMENTS,
+        |class| match class {
+            InputClass::Fixed => fixed_key.clone(),
+            InputClass::Random => GlweSecretKey::generate_binary(
+                glwe_dimension,
+                polynomial_size,
+                &mut secret_generator,
+            ),
+        },
+        |key| {
+            key.encrypt_constant_ggsw(
+                &mut ciphertext,
+                &Plaintext(1u64),
+                noise,
+                &mut encryption_generator,
+            );
+            *ciphertext.as_tensor().first()
+        },
+    );
+    println!("GGSW constant encryption: {}", report);
+    assert!(!report.is_leaking(), "GGSW constant encryption: {}", report);
+}
+
+#[test]
+#[ignore]
+fn test_signed_decomposition_is_constant_time() {
+    let decomposer =
+        SignedDecomposer::<u64>::new(DecompositionBaseLog(4), DecompositionLevelCount(8));
+    let mut generator = RandomGenerator::new(None);
+    let report = audit(
+        MEASUREMENTS,
+        |class| match class {
+            InputClass::Fixed => 0u64,
+            InputClass::Random => generator.random_uniform::<u64>(),
+        },
+        |input| {
+            decomposer
+                .decompose(*input)
+                .fold(0u64, |acc, term| acc.wrapping_add(term.value()))
+        },
+    );
+    println!("Signed decomposition: {}", report);
+    assert!(!report.is_leaking(), "Signed decomposition: {}", report);
+}
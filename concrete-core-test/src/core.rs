@@ -44,6 +44,16 @@ type Slice64 = &'static [u64];
 type MutSlice32 = &'static mut [u32];
 type MutSlice64 = &'static mut [u64];
 
+// Helper aliases for noise measurement fixtures which require knowing how the noise is represented
+type SignedNoise32 = i32;
+type SignedNoise64 = i64;
+type SignedNoiseVec32 = Vec<i32>;
+type SignedNoiseVec64 = Vec<i64>;
+type TorusNoise32 = f64;
+type TorusNoise64 = f64;
+type TorusNoiseVec32 = Vec<f64>;
+type TorusNoiseVec64 = Vec<f64>;
+
 test! {
     (CleartextCreationFixture, (Cleartext)),
     (CleartextRetrievalFixture, (Cleartext)),
@@ -73,6 +83,20 @@ test! {
     (GlweCiphertextConsumingRetrievalFixture, (GlweCiphertext, Vec)),
     (GlweCiphertextConsumingRetrievalFixture, (GlweCiphertextView, Slice)),
     (GlweCiphertextConsumingRetrievalFixture, (GlweCiphertextMutView, MutSlice)),
+    (GlweCiphertextVectorCreationFixture, (GlweCiphertextVector, Vec)),
+    (GlweCiphertextVectorCreationFixture, (GlweCiphertextVectorView, Slice)),
+    (GlweCiphertextVectorCreationFixture, (GlweCiphertextVectorMutView, MutSlice)),
+    (GlweCiphertextVectorConsumingRetrievalFixture, (GlweCiphertextVector, Vec)),
+    (GlweCiphertextVectorConsumingRetrievalFixture, (GlweCiphertextVectorView, Slice)),
+    (GlweCiphertextVectorConsumingRetrievalFixture, (GlweCiphertextVectorMutView, MutSlice)),
+    (GlweSecretKeyFromContainerCreationFixture, (GlweSecretKey, Vec)),
+    (GlweSecretKeyConsumingRetrievalFixture, (GlweSecretKey, Vec)),
+    (LweSecretKeyFromContainerCreationFixture, (LweSecretKey, Vec)),
+    (LweSecretKeyConsumingRetrievalFixture, (LweSecretKey, Vec)),
+    (LweSecretKeyDerivationFixture, (LweSecretKey)),
+    (GlweSecretKeyDerivationFixture, (GlweSecretKey, LweSecretKey)),
+    (LweKeyswitchKeyDerivationFixture, (LweSecretKey, LweSecretKey, LweKeyswitchKey)),
+    (LweBootstrapKeyDerivationFixture, (LweSecretKey, GlweSecretKey, LweBootstrapKey)),
     (LweCiphertextEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
     (LweCiphertextZeroEncryptionFixture, (LweSecretKey, LweCiphertext)),
     (LweCiphertextTrivialEncryptionFixture, (Plaintext, LweCiphertext)),
@@ -134,5 +158,19 @@ test! {
     (PlaintextVectorCreationFixture, (PlaintextVector)),
     (PlaintextVectorRetrievalFixture, (PlaintextVector)),
     (GlweCiphertextGgswCiphertextExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext)),
-    (GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext))
+    (GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext)),
+    (LweCiphertextNoiseMeasurementFixture, (LweSecretKey, LweCiphertext, Plaintext, SignedNoise)),
+    (LweCiphertextNoiseMeasurementFixture, (LweSecretKey, LweCiphertext, Plaintext, TorusNoise)),
+    (LweCiphertextVectorNoiseMeasurementFixture, (LweSecretKey, LweCiphertextVector, PlaintextVector,
+        SignedNoiseVec)),
+    (LweCiphertextVectorNoiseMeasurementFixture, (LweSecretKey, LweCiphertextVector, PlaintextVector,
+        TorusNoiseVec)),
+    (GlweCiphertextNoiseMeasurementFixture, (GlweSecretKey, GlweCiphertext, PlaintextVector,
+        SignedNoiseVec)),
+    (GlweCiphertextNoiseMeasurementFixture, (GlweSecretKey, GlweCiphertext, PlaintextVector,
+        TorusNoiseVec)),
+    (GlweCiphertextVectorNoiseMeasurementFixture, (GlweSecretKey, GlweCiphertextVector,
+        PlaintextVector, SignedNoiseVec)),
+    (GlweCiphertextVectorNoiseMeasurementFixture, (GlweSecretKey, GlweCiphertextVector,
+        PlaintextVector, TorusNoiseVec))
 }
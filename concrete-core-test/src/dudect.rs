@@ -0,0 +1,206 @@
+//! A dudect-style harness detecting secret-dependent timing differences.
+//!
+//! The approach follows the one of _Dude, is my code constant time?_ (Reparaz, Balasch and
+//! Verbauwhede, 2017): the routine under audit is timed on inputs belonging to two classes, a
+//! _fixed_ class where the secret always takes the same value, and a _random_ class where the
+//! secret is sampled anew for every measurement. The classes are interleaved at random, and a
+//! Welch t-test is performed on the two timing distributions. If the routine runs in constant
+//! time, the two distributions are the same, and the t statistic stays small whatever the number
+//! of measurements.
+//!
+//! To get rid of the measurements polluted by interrupts or context switches, the test is also
+//! performed on the measurements cropped at a set of upper percentiles, and the largest t
+//! statistic is reported.
+use concrete_core::backends::core::private::math::random::RandomGenerator;
+use std::fmt;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// The number of measurements performed by default for a single audit.
+pub const MEASUREMENTS: usize = 50_000;
+
+/// The t statistic above which a routine is considered to leak timing information.
+///
+/// This is the threshold used by the reference implementation of dudect to declare a routine
+/// _definitely not constant time_.
+pub const LEAKAGE_THRESHOLD: f64 = 10.;
+
+/// The number of times an audit is performed before a routine is reported as leaking.
+///
+/// A single audit may exceed the threshold because of a burst of noise on the machine, while a
+/// routine which actually leaks exceeds it on every attempt.
+pub const ATTEMPTS: usize = 3;
+
+// The number of measurements performed before the audit starts, to warm the caches up.
+const WARMUP_MEASUREMENTS: usize = 1_000;
+
+// The number of cropped t-tests performed in addition to the un-cropped one.
+const CROPPED_TESTS: usize = 10;
+
+/// The class an input of the audited routine belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputClass {
+    /// The secret input takes the same value for every measurement.
+    Fixed,
+    /// The secret input is sampled anew for every measurement.
+    Random,
+}
+
+/// The outcome of a timing audit.
+#[derive(Clone, Copy, Debug)]
+pub struct LeakageReport {
+    /// The number of measurements the statistics were computed on.
+    pub measurements: usize,
+    /// The largest absolute value of the t statistic among all the tests.
+    pub max_t: f64,
+    /// The percentile the measurements were cropped at for the test achieving `max_t`, if any.
+    pub cropping_percentile: Option<f64>,
+}
+
+impl LeakageReport {
+    /// Returns whether the audit found a timing difference between the two input classes.
+    pub fn is_leaking(&self) -> bool {
+        self.max_t > LEAKAGE_THRESHOLD
+    }
+}
+
+impl fmt::Display for LeakageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "max |t| = {:.2} over {} measurements",
+            self.max_t, self.measurements
+        )?;
+        if let Some(percentile) = self.cropping_percentile {
+            write!(f, " (cropped at percentile {:.4})", percentile)?;
+        }
+        if self.is_leaking() {
+            write!(f, ": timing leakage detected")
+        } else {
+            write!(f, ": no timing leakage detected")
+        }
+    }
+}
+
+/// Audits a routine for timing differences depending on its secret input.
+///
+/// Before each measurement, `prepare` is called with the class of the next input, and returns the
+/// input in question. Only the call to `run` on this input is timed. The output of `run` is passed
+/// to [`black_box`] so that the compiler can not elide the computation.
+///
+/// The audit is performed up to [`ATTEMPTS`] times, and the first report which does not exhibit a
+/// leakage is returned. If every attempt exhibits a leakage, the last report is returned.
+pub fn audit<Input, Output, Prepare, Run>(
+    measurements: usize,
+    mut prepare: Prepare,
+    mut run: Run,
+) -> LeakageReport
+where
+    Prepare: FnMut(InputClass) -> Input,
+    Run: FnMut(&Input) -> Output,
+{
+    let mut report = audit_once(measurements, &mut prepare, &mut run);
+    for _ in 1..ATTEMPTS {
+        if !report.is_leaking() {
+            break;
+        }
+        report = audit_once(measurements, &mut prepare, &mut run);
+    }
+    report
+}
+
+// Performs a single audit of the routine.
+fn audit_once<Input, Output, Prepare, Run>(
+    measurements: usize,
+    prepare: &mut Prepare,
+    run: &mut Run,
+) -> LeakageReport
+where
+    Prepare: FnMut(InputClass) -> Input,
+    Run: FnMut(&Input) -> Output,
+{
+    let mut generator = RandomGenerator::new(None);
+    let mut timings = Vec::with_capacity(measurements);
+    for index in 0..WARMUP_MEASUREMENTS + measurements {
+        let class = if generator.random_uniform::<u8>() & 1 == 0 {
+            InputClass::Fixed
+        } else {
+            InputClass::Random
+        };
+        let input = prepare(class);
+        let start = Instant::now();
+        let output = run(&input);
+        let elapsed = start.elapsed();
+        black_box(output);
+        if index >= WARMUP_MEASUREMENTS {
+            timings.push((class, elapsed.as_nanos() as f64));
+        }
+    }
+    analyze(&timings)
+}
+
+// Computes the t statistics of the un-cropped and cropped measurements, and keeps the largest.
+fn analyze(timings: &[(InputClass, f64)]) -> LeakageReport {
+    let mut sorted: Vec<f64> = timings.iter().map(|(_, time)| *time).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut report = LeakageReport {
+        measurements: timings.len(),
+        max_t: welch_t(timings, f64::INFINITY).abs(),
+        cropping_percentile: None,
+    };
+    for i in 0..CROPPED_TESTS {
+        // Same percentiles as the reference implementation, which get closer to 1 exponentially.
+        let percentile = 1. - 0.5f64.powf(10. * (i + 1) as f64 / CROPPED_TESTS as f64);
+        let threshold = sorted[((sorted.len() - 1) as f64 * percentile) as usize];
+        let t = welch_t(timings, threshold).abs();
+        if t > report.max_t {
+            report.max_t = t;
+            report.cropping_percentile = Some(percentile);
+        }
+    }
+    report
+}
+
+// Computes the Welch t statistic between the timings of the two classes, ignoring the timings
+// above `threshold`.
+fn welch_t(timings: &[(InputClass, f64)], threshold: f64) -> f64 {
+    let mut fixed = OnlineMoments::default();
+    let mut random = OnlineMoments::default();
+    for (class, time) in timings.iter().filter(|(_, time)| *time <= threshold) {
+        match class {
+            InputClass::Fixed => fixed.push(*time),
+            InputClass::Random => random.push(*time),
+        }
+    }
+    if fixed.count < 2 || random.count < 2 {
+        return 0.;
+    }
+    let deviation =
+        (fixed.variance() / fixed.count as f64 + random.variance() / random.count as f64).sqrt();
+    if deviation == 0. {
+        return 0.;
+    }
+    (fixed.mean - random.mean) / deviation
+}
+
+// The mean and variance of a stream of values, computed with Welford's algorithm.
+#[derive(Default)]
+struct OnlineMoments {
+    count: usize,
+    mean: f64,
+    sum_of_squares: f64,
+}
+
+impl OnlineMoments {
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.sum_of_squares += delta * (value - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        self.sum_of_squares / (self.count - 1) as f64
+    }
+}
@@ -14,3 +14,12 @@ pub const SAMPLE_SIZE: SampleSize = SampleSize(100);
 
 #[cfg(all(test, feature = "backend_core"))]
 pub mod core;
+
+#[cfg(feature = "constant_time_audit")]
+pub mod dudect;
+
+#[cfg(all(test, feature = "constant_time_audit"))]
+mod constant_time;
+
+#[cfg(all(test, feature = "noise_validation"))]
+mod noise_validation;
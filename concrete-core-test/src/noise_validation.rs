@@ -0,0 +1,61 @@
+//! Validation of the `concrete-npe` noise formulas against the core backend.
+//!
+//! Every test samples many executions of an operator whose fixture uses a `concrete-npe` formula
+//! as criterion, and checks that the predicted variance lies in a chi-squared confidence interval
+//! of the measured one. A table of the predicted and measured variances is printed, and can be
+//! displayed by running the tests with `--nocapture`.
+use concrete_core::prelude::*;
+use concrete_core_fixture::fixture::*;
+use concrete_core_fixture::generation::{Maker, Precision32, Precision64};
+use concrete_core_fixture::noise_validation::{validate_noise_formula, NoiseValidationTable};
+use concrete_core_fixture::SampleSize;
+use paste::paste;
+
+/// The number of executions sampled for every set of parameters.
+pub const NOISE_SAMPLE_SIZE: SampleSize = SampleSize(1_000);
+
+/// The level of the confidence interval of the measured variance.
+pub const CONFIDENCE: f64 = 0.999;
+
+macro_rules! validate {
+    ($fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< validate_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let mut maker = Maker::default();
+                let mut engine = CoreEngine::new(()).unwrap();
+                let reports = validate_noise_formula::<
+                    $fixture,
+                    $precision,
+                    CoreEngine,
+                    ($($types,)+),
+                >(&mut maker, &mut engine, NOISE_SAMPLE_SIZE, CONFIDENCE);
+                let name = stringify!([< $fixture:snake _ $precision:snake >]);
+                println!("{}", NoiseValidationTable { name, reports: &reports });
+                assert!(reports.iter().all(|report| report.is_valid()));
+            }
+        }
+    };
+    ($(($fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                validate!{$fixture, Precision32, ($([< $types 32 >]),+)}
+                validate!{$fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+validate! {
+    (LweCiphertextDiscardingAdditionFixture, (LweCiphertext, LweCiphertext)),
+    (LweCiphertextDiscardingSubtractionFixture, (LweCiphertext, LweCiphertext)),
+    (LweCiphertextVectorDiscardingAdditionFixture, (LweCiphertextVector, LweCiphertextVector)),
+    (LweCiphertextCleartextDiscardingMultiplicationFixture, (LweCiphertext, Cleartext, LweCiphertext)),
+    (LweCiphertextVectorDiscardingAffineTransformationFixture, (LweCiphertextVector, CleartextVector, Plaintext, LweCiphertext)),
+    (LweCiphertextDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertext, LweCiphertext)),
+    (LweCiphertextDiscardingExtractionFixture, (GlweCiphertext, LweCiphertext)),
+    (LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture, (LweCiphertextVector,
+        PackingKeyswitchKey, GlweCiphertext)),
+    (GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FourierGgswCiphertext, GlweCiphertext)),
+    (LweCiphertextDiscardingBootstrapFixture1, (FourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext))
+}
@@ -0,0 +1,408 @@
+//! Checks that the noise tracking engine attaches to its outputs the variances given by the
+//! formulas of `concrete-npe`, and that it refuses to run operations exceeding its noise budget.
+#![cfg(feature = "backend_noise_tracking")]
+use concrete_core::prelude::*;
+use concrete_npe::{
+    estimate_addition_noise, estimate_external_product_noise,
+    estimate_integer_plaintext_multiplication_noise, estimate_keyswitch_noise_lwe_to_lwe,
+    estimate_modulus_switching_noise_with_binary_key, estimate_pbs_noise,
+    estimate_weighted_sum_noise,
+};
+
+const NOISE: Variance = Variance(0.000_000_000_000_001);
+const BUDGET: Variance = Variance(0.000_000_01);
+
+fn new_engines(noise_budget: Variance) -> (CoreEngine, NoiseTrackingEngine) {
+    (
+        CoreEngine::new(()).unwrap(),
+        NoiseTrackingEngine::new(NoiseTrackingParameters { noise_budget }).unwrap(),
+    )
+}
+
+fn encrypt(
+    core_engine: &mut CoreEngine,
+    engine: &mut NoiseTrackingEngine,
+    key: &LweSecretKey64,
+    noise: Variance,
+) -> NoisyLweCiphertext64 {
+    let plaintext = core_engine.create_plaintext(&(1_u64 << 59)).unwrap();
+    engine
+        .encrypt_lwe_ciphertext(key, &plaintext, noise)
+        .unwrap()
+}
+
+#[test]
+fn test_lwe_ciphertext_addition_variance() {
+    let (mut core_engine, mut engine) = new_engines(BUDGET);
+    let key: LweSecretKey64 = core_engine.create_lwe_secret_key(LweDimension(16)).unwrap();
+    let input_1 = encrypt(&mut core_engine, &mut engine, &key, NOISE);
+    let input_2 = encrypt(&mut core_engine, &mut engine, &key, Variance(NOISE.0 * 3.));
+    let expected = estimate_addition_noise::<u64, _, _>(input_1.variance(), input_2.variance());
+
+    let mut output = encrypt(&mut core_engine, &mut engine, &key, NOISE);
+    engine
+        .discard_add_lwe_ciphertext(&mut output, &input_1, &input_2)
+        .unwrap();
+    assert_eq!(output.variance(), expected);
+
+    let mut output = input_1.clone();
+    engine
+        .fuse_add_lwe_ciphertext(&mut output, &input_2)
+        .unwrap();
+    assert_eq!(output.variance(), expected);
+}
+
+#[test]
+fn test_lwe_ciphertext_vector_addition_variance() {
+    let (mut core_engine, mut engine) = new_engines(BUDGET);
+    let key: LweSecretKey64 = core_engine.create_lwe_secret_key(LweDimension(16)).unwrap();
+    let plaintext_vector = core_engine
+        .create_plaintext_vector(&[1_u64 << 59; 4])
+        .unwrap();
+    let input_1: NoisyLweCiphertextVector64 = engine
+        .encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, NOISE)
+        .unwrap();
+    let input_2: NoisyLweCiphertextVector64 = engine
+        .encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, Variance(NOISE.0 * 3.))
+        .unwrap();
+    let expected = estimate_addition_noise::<u64, _, _>(NOISE, Variance(NOISE.0 * 3.));
+
+    let mut output = input_1.clone();
+    engine
+        .discard_add_lwe_ciphertext_vector(&mut output, &input_1, &input_2)
+        .unwrap();
+    assert_eq!(output.variances(), &[expected; 4]);
+
+    let mut output = input_1.clone();
+    engine
+        .fuse_add_lwe_ciphertext_vector(&mut output, &input_2)
+        .unwrap();
+    assert_eq!(output.variances(), &[expected; 4]);
+}
+
+#[test]
+fn test_lwe_ciphertext_cleartext_multiplication_variance() {
+    let (mut core_engine, mut engine) = new_engines(Variance(1.));
+    let key: LweSecretKey64 = core_engine.create_lwe_secret_key(LweDimension(16)).unwrap();
+    let input = encrypt(&mut core_engine, &mut engine, &key, NOISE);
+    // The square of this cleartext does not fit in 32 bits.
+    let cleartext: Cleartext64 = core_engine.create_cleartext(&(1_u64 << 20)).unwrap();
+    let expected = estimate_integer_plaintext_multiplication_noise(NOISE, 1_u64 << 20);
+    assert_eq!(expected, Variance(NOISE.0 * 2_f64.powi(40)));
+
+    let mut output = input.clone();
+    engine
+        .discard_mul_lwe_ciphertext_cleartext(&mut output, &input, &cleartext)
+        .unwrap();
+    assert_eq!(output.variance(), expected);
+
+    let mut output = input;
+    engine
+        .fuse_mul_lwe_ciphertext_cleartext(&mut output, &cleartext)
+        .unwrap();
+    assert_eq!(output.variance(), expected);
+}
+
+#[test]
+fn test_lwe_ciphertext_vector_affine_transformation_variance() {
+    let (mut core_engine, mut engine) = new_engines(BUDGET);
+    let key: LweSecretKey64 = core_engine.create_lwe_secret_key(LweDimension(16)).unwrap();
+    let plaintext_vector = core_engine
+        .create_plaintext_vector(&[1_u64 << 59; 3])
+        .unwrap();
+    let inputs: NoisyLweCiphertextVector64 = engine
+        .encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, NOISE)
+        .unwrap();
+    let weights: CleartextVector64 = core_engine.create_cleartext_vector(&[1, 2, 3]).unwrap();
+    let bias = core_engine.create_plaintext(&0_u64).unwrap();
+    let mut output = encrypt(&mut core_engine, &mut engine, &key, NOISE);
+
+    engine
+        .discard_affine_transform_lwe_ciphertext_vector(&mut output, &inputs, &weights, &bias)
+        .unwrap();
+    assert_eq!(
+        output.variance(),
+        estimate_weighted_sum_noise(inputs.variances(), &[1_u64, 2, 3])
+    );
+}
+
+#[test]
+fn test_lwe_ciphertext_keyswitch_variance() {
+    let (mut core_engine, mut engine) = new_engines(BUDGET);
+    let input_key: LweSecretKey64 = core_engine.create_lwe_secret_key(LweDimension(32)).unwrap();
+    let output_key: LweSecretKey64 = core_engine.create_lwe_secret_key(LweDimension(16)).unwrap();
+    let ksk: NoisyLweKeyswitchKey64 = engine
+        .create_lwe_keyswitch_key(
+            &input_key,
+            &output_key,
+            DecompositionLevelCount(3),
+            DecompositionBaseLog(8),
+            NOISE,
+        )
+        .unwrap();
+    let input = encrypt(&mut core_engine, &mut engine, &input_key, NOISE);
+    let mut output = encrypt(&mut core_engine, &mut engine, &output_key, NOISE);
+
+    engine
+        .discard_keyswitch_lwe_ciphertext(&mut output, &input, &ksk)
+        .unwrap();
+    assert_eq!(
+        output.variance(),
+        estimate_keyswitch_noise_lwe_to_lwe::<u64, _, _, BinaryKeyKind>(
+            LweDimension(32),
+            NOISE,
+            NOISE,
+            DecompositionBaseLog(8),
+            DecompositionLevelCount(3),
+        )
+    );
+}
+
+#[test]
+fn test_glwe_ciphertext_external_product_variance() {
+    let (mut core_engine, mut engine) = new_engines(BUDGET);
+    let polynomial_size = PolynomialSize(256);
+    let key: GlweSecretKey64 = core_engine
+        .create_glwe_secret_key(GlweDimension(1), polynomial_size)
+        .unwrap();
+    let plaintext = core_engine.create_plaintext(&1_u64).unwrap();
+    let ggsw: NoisyFourierGgswCiphertext64 = engine
+        .encrypt_scalar_ggsw_ciphertext(
+            &key,
+            &plaintext,
+            NOISE,
+            DecompositionLevelCount(3),
+            DecompositionBaseLog(7),
+        )
+        .unwrap();
+    let plaintext_vector = core_engine
+        .create_plaintext_vector(&vec![1_u64 << 59; polynomial_size.0])
+        .unwrap();
+    let glwe: NoisyGlweCiphertext64 = engine
+        .encrypt_glwe_ciphertext(&key, &plaintext_vector, NOISE)
+        .unwrap();
+    let expected = estimate_external_product_noise::<u64, _, _, BinaryKeyKind>(
+        polynomial_size,
+        GlweDimension(1),
+        NOISE,
+        NOISE,
+        DecompositionBaseLog(7),
+        DecompositionLevelCount(3),
+        ggsw.message_squared(),
+    );
+
+    let output = engine
+        .compute_external_product_glwe_ciphertext_ggsw_ciphertext(&glwe, &ggsw)
+        .unwrap();
+    assert_eq!(output.variance(), expected);
+
+    let mut output = glwe.clone();
+    engine
+        .discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(&glwe, &ggsw, &mut output)
+        .unwrap();
+    assert_eq!(output.variance(), expected);
+}
+
+#[test]
+fn test_glwe_ciphertext_vector_encryption_variance() {
+    let (mut core_engine, mut engine) = new_engines(BUDGET);
+    let key: GlweSecretKey64 = core_engine
+        .create_glwe_secret_key(GlweDimension(2), PolynomialSize(4))
+        .unwrap();
+    let plaintext_vector = core_engine
+        .create_plaintext_vector(&[1_u64 << 59; 12])
+        .unwrap();
+    let ciphertexts: NoisyGlweCiphertextVector64 = engine
+        .encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, NOISE)
+        .unwrap();
+    assert_eq!(ciphertexts.variances(), &[NOISE; 3]);
+    let decrypted = engine
+        .decrypt_glwe_ciphertext_vector(&key, &ciphertexts)
+        .unwrap();
+    assert_eq!(decrypted.plaintext_count(), PlaintextCount(12));
+
+    let zeros: NoisyGlweCiphertextVector64 = engine
+        .zero_encrypt_glwe_ciphertext_vector(&key, NOISE, GlweCiphertextCount(5))
+        .unwrap();
+    assert_eq!(zeros.variances(), &[NOISE; 5]);
+}
+
+struct BootstrapSetup {
+    core_engine: CoreEngine,
+    engine: NoiseTrackingEngine,
+    input_key: LweSecretKey64,
+    output_key: LweSecretKey64,
+    acc: NoisyGlweCiphertext64,
+    bsk: NoisyFourierLweBootstrapKey64,
+}
+
+// The variance of an input encrypted with `NOISE`, once its modulus is switched to 2N.
+fn modulus_switching_variance() -> Variance {
+    estimate_modulus_switching_noise_with_binary_key::<u64, _>(LweDimension(8), 9, NOISE)
+}
+
+fn bootstrap_setup(noise_budget: Variance, acc_noise: Variance) -> BootstrapSetup {
+    let (mut core_engine, mut engine) = new_engines(Variance(1.));
+    let polynomial_size = PolynomialSize(256);
+    let input_key: LweSecretKey64 = core_engine.create_lwe_secret_key(LweDimension(8)).unwrap();
+    let output_key: LweSecretKey64 = core_engine
+        .create_lwe_secret_key(LweDimension(polynomial_size.0))
+        .unwrap();
+    let glwe_key: GlweSecretKey64 = core_engine
+        .create_glwe_secret_key(GlweDimension(1), polynomial_size)
+        .unwrap();
+    let bsk: NoisyFourierLweBootstrapKey64 = engine
+        .create_lwe_bootstrap_key(
+            &input_key,
+            &glwe_key,
+            DecompositionBaseLog(7),
+            DecompositionLevelCount(3),
+            NOISE,
+        )
+        .unwrap();
+    let lut = core_engine
+        .create_plaintext_vector(&vec![1_u64 << 59; polynomial_size.0])
+        .unwrap();
+    let acc: NoisyGlweCiphertext64 = engine
+        .encrypt_glwe_ciphertext(&glwe_key, &lut, acc_noise)
+        .unwrap();
+    BootstrapSetup {
+        core_engine,
+        engine: NoiseTrackingEngine::new(NoiseTrackingParameters { noise_budget }).unwrap(),
+        input_key,
+        output_key,
+        acc,
+        bsk,
+    }
+}
+
+#[test]
+fn test_lwe_ciphertext_bootstrap_variance() {
+    let BootstrapSetup {
+        mut core_engine,
+        mut engine,
+        input_key,
+        output_key,
+        acc,
+        bsk,
+    } = bootstrap_setup(Variance(modulus_switching_variance().0 * 2.), NOISE);
+    let input = encrypt(&mut core_engine, &mut engine, &input_key, NOISE);
+    let mut output = encrypt(&mut core_engine, &mut engine, &output_key, NOISE);
+
+    engine
+        .discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)
+        .unwrap();
+    assert_eq!(
+        output.variance(),
+        estimate_addition_noise::<u64, _, _>(
+            NOISE,
+            estimate_pbs_noise::<u64, _, BinaryKeyKind>(
+                LweDimension(8),
+                PolynomialSize(256),
+                GlweDimension(1),
+                DecompositionBaseLog(7),
+                DecompositionLevelCount(3),
+                NOISE,
+            )
+        )
+    );
+}
+
+#[test]
+fn test_lwe_ciphertext_addition_exceeding_budget() {
+    let (mut core_engine, mut engine) = new_engines(Variance(NOISE.0 * 1.5));
+    let key: LweSecretKey64 = core_engine.create_lwe_secret_key(LweDimension(16)).unwrap();
+    let input = encrypt(&mut core_engine, &mut engine, &key, NOISE);
+    let mut output = input.clone();
+
+    let result = engine.fuse_add_lwe_ciphertext(&mut output, &input);
+    assert!(matches!(
+        result,
+        Err(LweCiphertextFusingAdditionError::Engine(
+            NoiseTrackingError::NoiseBudgetExceeded { .. }
+        ))
+    ));
+    // The output is left untouched.
+    assert_eq!(output, input);
+}
+
+#[test]
+fn test_lwe_ciphertext_keyswitch_exceeding_budget() {
+    let (mut core_engine, mut engine) = new_engines(Variance(NOISE.0 * 2.));
+    let input_key: LweSecretKey64 = core_engine.create_lwe_secret_key(LweDimension(32)).unwrap();
+    let output_key: LweSecretKey64 = core_engine.create_lwe_secret_key(LweDimension(16)).unwrap();
+    let ksk: NoisyLweKeyswitchKey64 = engine
+        .create_lwe_keyswitch_key(
+            &input_key,
+            &output_key,
+            DecompositionLevelCount(3),
+            DecompositionBaseLog(8),
+            NOISE,
+        )
+        .unwrap();
+    let input = encrypt(&mut core_engine, &mut engine, &input_key, NOISE);
+    let mut output = encrypt(&mut core_engine, &mut engine, &output_key, NOISE);
+    let before = output.clone();
+
+    let result = engine.discard_keyswitch_lwe_ciphertext(&mut output, &input, &ksk);
+    assert!(matches!(
+        result,
+        Err(LweCiphertextDiscardingKeyswitchError::Engine(
+            NoiseTrackingError::NoiseBudgetExceeded { .. }
+        ))
+    ));
+    assert_eq!(output, before);
+}
+
+#[test]
+fn test_lwe_ciphertext_bootstrap_exceeding_budget() {
+    // The modulus switching of the input fits in the budget, but the output does not.
+    let BootstrapSetup {
+        mut core_engine,
+        mut engine,
+        input_key,
+        output_key,
+        acc,
+        bsk,
+    } = bootstrap_setup(
+        Variance(modulus_switching_variance().0 * 2.),
+        Variance(modulus_switching_variance().0 * 4.),
+    );
+    let input = encrypt(&mut core_engine, &mut engine, &input_key, NOISE);
+    let mut output = encrypt(&mut core_engine, &mut engine, &output_key, NOISE);
+    let before = output.clone();
+
+    let result = engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk);
+    assert!(matches!(
+        result,
+        Err(LweCiphertextDiscardingBootstrapError::Engine(
+            NoiseTrackingError::NoiseBudgetExceeded { .. }
+        ))
+    ));
+    assert_eq!(output, before);
+}
+
+#[test]
+fn test_lwe_ciphertext_bootstrap_modulus_switching_exceeding_budget() {
+    // The output fits in the budget, but the input does not once its modulus is switched to 2N.
+    let BootstrapSetup {
+        mut core_engine,
+        mut engine,
+        input_key,
+        output_key,
+        acc,
+        bsk,
+    } = bootstrap_setup(Variance(modulus_switching_variance().0 / 2.), NOISE);
+    let input = encrypt(&mut core_engine, &mut engine, &input_key, NOISE);
+    let mut output = encrypt(&mut core_engine, &mut engine, &output_key, NOISE);
+    let before = output.clone();
+
+    let result = engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk);
+    assert!(matches!(
+        result,
+        Err(LweCiphertextDiscardingBootstrapError::Engine(
+            NoiseTrackingError::NoiseBudgetExceeded { variance, .. }
+        )) if variance == modulus_switching_variance()
+    ));
+    assert_eq!(output, before);
+}
@@ -0,0 +1,108 @@
+//! Checks that the core engines reject invalid parameters with an error instead of panicking deep
+//! inside the FFT or the decomposition.
+#![cfg(feature = "backend_core")]
+use concrete_core::prelude::*;
+
+// A polynomial size which is not a power of two, and hence not supported by the FFT.
+const UNSUPPORTED_POLYNOMIAL_SIZE: PolynomialSize = PolynomialSize(1000);
+
+#[test]
+fn test_glwe_ciphertext_conversion_unsupported_polynomial_size() {
+    let mut engine = CoreEngine::new(()).unwrap();
+    let key: GlweSecretKey64 = engine
+        .create_glwe_secret_key(GlweDimension(1), UNSUPPORTED_POLYNOMIAL_SIZE)
+        .unwrap();
+    let ciphertext: GlweCiphertext64 = engine
+        .zero_encrypt_glwe_ciphertext(&key, Variance(0.))
+        .unwrap();
+    let result: Result<FourierGlweCiphertext64, _> = engine.convert_glwe_ciphertext(&ciphertext);
+    assert!(matches!(
+        result,
+        Err(GlweCiphertextConversionError::Engine(
+            CoreError::UnsupportedPolynomialSize
+        ))
+    ));
+}
+
+#[test]
+fn test_ggsw_ciphertext_conversion_unsupported_polynomial_size() {
+    let mut engine = CoreEngine::new(()).unwrap();
+    let key: GlweSecretKey32 = engine
+        .create_glwe_secret_key(GlweDimension(1), UNSUPPORTED_POLYNOMIAL_SIZE)
+        .unwrap();
+    let plaintext = engine.create_plaintext(&(1_u32 << 20)).unwrap();
+    let ciphertext: GgswCiphertext32 = engine
+        .encrypt_scalar_ggsw_ciphertext(
+            &key,
+            &plaintext,
+            Variance(0.),
+            DecompositionLevelCount(2),
+            DecompositionBaseLog(4),
+        )
+        .unwrap();
+    let result: Result<FourierGgswCiphertext32, _> = engine.convert_ggsw_ciphertext(&ciphertext);
+    assert!(matches!(
+        result,
+        Err(GgswCiphertextConversionError::Engine(
+            CoreError::UnsupportedPolynomialSize
+        ))
+    ));
+}
+
+#[test]
+fn test_lwe_bootstrap_key_conversion_unsupported_polynomial_size() {
+    let mut engine = CoreEngine::new(()).unwrap();
+    let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(4)).unwrap();
+    let glwe_key: GlweSecretKey64 = engine
+        .create_glwe_secret_key(GlweDimension(1), UNSUPPORTED_POLYNOMIAL_SIZE)
+        .unwrap();
+    let bsk: LweBootstrapKey64 = engine
+        .create_lwe_bootstrap_key(
+            &lwe_key,
+            &glwe_key,
+            DecompositionBaseLog(4),
+            DecompositionLevelCount(2),
+            Variance(0.),
+        )
+        .unwrap();
+    let result: Result<FourierLweBootstrapKey64, _> = engine.convert_lwe_bootstrap_key(&bsk);
+    assert!(matches!(
+        result,
+        Err(LweBootstrapKeyConversionError::Engine(
+            CoreError::UnsupportedPolynomialSize
+        ))
+    ));
+}
+
+#[test]
+fn test_lwe_keyswitch_key_creation_invalid_decomposition() {
+    let mut engine = CoreEngine::new(()).unwrap();
+    let input_key: LweSecretKey32 = engine.create_lwe_secret_key(LweDimension(8)).unwrap();
+    let output_key: LweSecretKey32 = engine.create_lwe_secret_key(LweDimension(4)).unwrap();
+    let mut create = |level, base_log| -> Result<LweKeyswitchKey32, _> {
+        engine.create_lwe_keyswitch_key(
+            &input_key,
+            &output_key,
+            DecompositionLevelCount(level),
+            DecompositionBaseLog(base_log),
+            Variance(0.),
+        )
+    };
+    assert!(matches!(
+        create(2, 0),
+        Err(LweKeyswitchKeyCreationError::NullDecompositionBaseLog)
+    ));
+    assert!(matches!(
+        create(0, 4),
+        Err(LweKeyswitchKeyCreationError::NullDecompositionLevelCount)
+    ));
+    assert!(matches!(
+        create(5, 8),
+        Err(LweKeyswitchKeyCreationError::DecompositionTooLarge)
+    ));
+    assert!(matches!(
+        create(usize::MAX, usize::MAX),
+        Err(LweKeyswitchKeyCreationError::DecompositionTooLarge)
+    ));
+    assert!(create(4, 8).is_ok());
+}
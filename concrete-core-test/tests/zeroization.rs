@@ -0,0 +1,201 @@
+//! Checks that the secret material of the core backend is wiped before its memory is released.
+//!
+//! This test binary installs a global allocator which inspects the content of the blocks of a
+//! watched size when they are deallocated. Each scenario uses a key whose storage has a size
+//! that is unlikely to be used by any other allocation.
+#![cfg(feature = "backend_core")]
+use concrete_core::prelude::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// An allocator recording whether the blocks of the watched size were wiped when freed.
+struct WipeCheckingAllocator;
+
+static WATCHED_SIZE: AtomicUsize = AtomicUsize::new(0);
+static WIPED_DEALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static DIRTY_DEALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for WipeCheckingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if layout.size() != 0 && layout.size() == WATCHED_SIZE.load(Ordering::SeqCst) {
+            let block = std::slice::from_raw_parts(ptr, layout.size());
+            if block.iter().all(|byte| *byte == 0) {
+                WIPED_DEALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+            } else {
+                DIRTY_DEALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: WipeCheckingAllocator = WipeCheckingAllocator;
+
+// The watched size is shared by the whole binary, so the scenarios must not run concurrently.
+static SCENARIO_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// A guard releasing the scenario lock when dropped, even if the scenario panicked.
+struct ScenarioGuard;
+
+impl ScenarioGuard {
+    fn acquire() -> ScenarioGuard {
+        while SCENARIO_RUNNING
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            std::thread::yield_now();
+        }
+        ScenarioGuard
+    }
+}
+
+impl Drop for ScenarioGuard {
+    fn drop(&mut self) {
+        WATCHED_SIZE.store(0, Ordering::SeqCst);
+        SCENARIO_RUNNING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Runs `scenario` while watching the deallocations of `watched_size` bytes, and returns the
+/// number of wiped and dirty blocks freed in the process.
+fn watch_deallocations<F: FnOnce()>(watched_size: usize, scenario: F) -> (usize, usize) {
+    let _guard = ScenarioGuard::acquire();
+    WIPED_DEALLOCATIONS.store(0, Ordering::SeqCst);
+    DIRTY_DEALLOCATIONS.store(0, Ordering::SeqCst);
+    WATCHED_SIZE.store(watched_size, Ordering::SeqCst);
+    scenario();
+    (
+        WIPED_DEALLOCATIONS.load(Ordering::SeqCst),
+        DIRTY_DEALLOCATIONS.load(Ordering::SeqCst),
+    )
+}
+
+#[test]
+fn test_allocator_detects_dirty_blocks() {
+    let (wiped, dirty) = watch_deallocations(1009 * 4, || {
+        let secret = vec![1u32; 1009];
+        drop(secret);
+    });
+    assert_eq!((wiped, dirty), (0, 1));
+}
+
+#[test]
+fn test_lwe_secret_key_32_wiped_on_destroy() {
+    let mut engine = CoreEngine::new(()).unwrap();
+    let (wiped, dirty) = watch_deallocations(1013 * 4, || {
+        let key: LweSecretKey32 = engine.create_lwe_secret_key(LweDimension(1013)).unwrap();
+        engine.destroy(key).unwrap();
+    });
+    assert_eq!((wiped, dirty), (1, 0));
+}
+
+#[test]
+fn test_lwe_secret_key_64_wiped_on_drop() {
+    let mut engine = CoreEngine::new(()).unwrap();
+    let (wiped, dirty) = watch_deallocations(1019 * 8, || {
+        let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(1019)).unwrap();
+        drop(key);
+    });
+    assert_eq!((wiped, dirty), (1, 0));
+}
+
+#[test]
+fn test_glwe_secret_key_32_wiped_on_drop() {
+    let mut engine = CoreEngine::new(()).unwrap();
+    let (wiped, dirty) = watch_deallocations(3 * 331 * 4, || {
+        let key: GlweSecretKey32 = engine
+            .create_glwe_secret_key(GlweDimension(3), PolynomialSize(331))
+            .unwrap();
+        drop(key);
+    });
+    assert_eq!((wiped, dirty), (1, 0));
+}
+
+#[test]
+fn test_glwe_secret_key_64_wiped_on_destroy() {
+    let mut engine = CoreEngine::new(()).unwrap();
+    let (wiped, dirty) = watch_deallocations(3 * 337 * 8, || {
+        let key: GlweSecretKey64 = engine
+            .create_glwe_secret_key(GlweDimension(3), PolynomialSize(337))
+            .unwrap();
+        engine.destroy(key).unwrap();
+    });
+    assert_eq!((wiped, dirty), (1, 0));
+}
+
+#[test]
+fn test_transmuted_glwe_secret_key_wiped_on_drop() {
+    let mut engine = CoreEngine::new(()).unwrap();
+    let (wiped, dirty) = watch_deallocations(2 * 347 * 8, || {
+        let glwe_key: GlweSecretKey64 = engine
+            .create_glwe_secret_key(GlweDimension(2), PolynomialSize(347))
+            .unwrap();
+        let lwe_key: LweSecretKey64 = engine
+            .transmute_glwe_secret_key_to_lwe_secret_key(glwe_key)
+            .unwrap();
+        drop(lwe_key);
+    });
+    assert_eq!((wiped, dirty), (1, 0));
+}
+
+#[test]
+fn test_fourier_buffers_wiped_on_engine_drop() {
+    let mut engine = CoreEngine::new(()).unwrap();
+    // The lut and rounded buffers of the engine hold a GLWE ciphertext of this size.
+    let glwe_dimension = GlweDimension(6);
+    let polynomial_size = PolynomialSize(256);
+    let lwe_key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(4)).unwrap();
+    let glwe_key: GlweSecretKey64 = engine
+        .create_glwe_secret_key(glwe_dimension, polynomial_size)
+        .unwrap();
+    let bsk: FourierLweBootstrapKey64 = engine
+        .create_lwe_bootstrap_key(
+            &lwe_key,
+            &glwe_key,
+            DecompositionBaseLog(7),
+            DecompositionLevelCount(3),
+            Variance(0.),
+        )
+        .unwrap();
+    let plaintext = engine.create_plaintext(&(1_u64 << 60)).unwrap();
+    let input = engine
+        .encrypt_lwe_ciphertext(&lwe_key, &plaintext, Variance(0.))
+        .unwrap();
+    let lut = engine
+        .create_plaintext_vector(&vec![1_u64 << 60; polynomial_size.0])
+        .unwrap();
+    let acc = engine
+        .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &lut)
+        .unwrap();
+    let mut output = engine
+        .trivially_encrypt_lwe_ciphertext(
+            LweDimension(glwe_dimension.0 * polynomial_size.0).to_lwe_size(),
+            &plaintext,
+        )
+        .unwrap();
+    engine
+        .discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)
+        .unwrap();
+
+    let (wiped, dirty) = watch_deallocations(7 * 256 * 8, || drop(engine));
+    assert_eq!((wiped, dirty), (2, 0));
+}
+
+#[test]
+fn test_secret_random_generator_state_wiped_on_drop() {
+    use concrete_core::backends::core::private::crypto::secret::generators::SecretRandomGenerator;
+    use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+    // The generator is boxed, so that its inline state is released through the allocator.
+    let mut generator = Box::new(SecretRandomGenerator::new(None));
+    let key = LweSecretKey::<_, Vec<u64>>::generate_binary(LweDimension(16), &mut generator);
+    drop(key);
+    let (wiped, dirty) = watch_deallocations(std::mem::size_of::<SecretRandomGenerator>(), || {
+        drop(generator)
+    });
+    assert_eq!((wiped, dirty), (1, 0));
+}
@@ -4,14 +4,17 @@ use crate::backends::core::implementation::entities::{
     FourierGgswCiphertext64, FourierGlweCiphertext32, FourierGlweCiphertext64,
     FourierLweBootstrapKey32, FourierLweBootstrapKey64, GgswCiphertext32, GgswCiphertext64,
     GlweCiphertext32, GlweCiphertext64, GlweCiphertextMutView32, GlweCiphertextMutView64,
-    GlweCiphertextVector32, GlweCiphertextVector64, GlweCiphertextView32, GlweCiphertextView64,
-    GlweSecretKey32, GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32,
-    LweCiphertext64, LweCiphertextMutView32, LweCiphertextMutView64, LweCiphertextVector32,
-    LweCiphertextVector64, LweCiphertextView32, LweCiphertextView64, LweKeyswitchKey32,
-    LweKeyswitchKey64, LweSecretKey32, LweSecretKey64, PackingKeyswitchKey32,
-    PackingKeyswitchKey64, Plaintext32, Plaintext64, PlaintextVector32, PlaintextVector64,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweCiphertextVectorMutView32,
+    GlweCiphertextVectorMutView64, GlweCiphertextVectorView32, GlweCiphertextVectorView64,
+    GlweCiphertextView32, GlweCiphertextView64, GlweSecretKey32, GlweSecretKey64,
+    LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32, LweCiphertext64, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextVector32, LweCiphertextVector64, LweCiphertextView32,
+    LweCiphertextView64, LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey32, LweSecretKey64,
+    PackingKeyswitchKey32, PackingKeyswitchKey64, Plaintext32, Plaintext64, PlaintextVector32,
+    PlaintextVector64,
 };
-use crate::backends::core::private::math::tensor::AsMutTensor;
+use crate::backends::core::private::math::tensor::{AsMutSlice, AsMutTensor};
+use crate::backends::core::private::utils::zeroize;
 use crate::specification::engines::{DestructionEngine, DestructionError};
 
 impl DestructionEngine<Cleartext32> for CoreEngine {
@@ -326,6 +329,54 @@ impl DestructionEngine<GlweCiphertextMutView64<'_>> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: &mut GlweCiphertextMutView64<'_>) {}
 }
 
+impl DestructionEngine<GlweCiphertextVectorView32<'_>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: GlweCiphertextVectorView32<'_>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut GlweCiphertextVectorView32<'_>) {}
+}
+
+impl DestructionEngine<GlweCiphertextVectorMutView32<'_>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: GlweCiphertextVectorMutView32<'_>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut GlweCiphertextVectorMutView32<'_>) {}
+}
+
+impl DestructionEngine<GlweCiphertextVectorView64<'_>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: GlweCiphertextVectorView64<'_>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut GlweCiphertextVectorView64<'_>) {}
+}
+
+impl DestructionEngine<GlweCiphertextVectorMutView64<'_>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        mut entity: GlweCiphertextVectorMutView64<'_>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: &mut GlweCiphertextVectorMutView64<'_>) {}
+}
+
 impl DestructionEngine<GgswCiphertext32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -456,7 +507,7 @@ impl DestructionEngine<LweSecretKey32> for CoreEngine {
     }
 
     unsafe fn destroy_unchecked(&mut self, entity: &mut LweSecretKey32) {
-        entity.0.as_mut_tensor().fill_with_element(0u32);
+        zeroize(entity.0.as_mut_tensor().as_mut_slice());
     }
 }
 
@@ -470,7 +521,7 @@ impl DestructionEngine<LweSecretKey64> for CoreEngine {
     }
 
     unsafe fn destroy_unchecked(&mut self, entity: &mut LweSecretKey64) {
-        entity.0.as_mut_tensor().fill_with_element(0u64);
+        zeroize(entity.0.as_mut_tensor().as_mut_slice());
     }
 }
 
@@ -484,7 +535,7 @@ impl DestructionEngine<GlweSecretKey32> for CoreEngine {
     }
 
     unsafe fn destroy_unchecked(&mut self, entity: &mut GlweSecretKey32) {
-        entity.0.as_mut_tensor().fill_with_element(0u32);
+        zeroize(entity.0.as_mut_tensor().as_mut_slice());
     }
 }
 
@@ -498,7 +549,7 @@ impl DestructionEngine<GlweSecretKey64> for CoreEngine {
     }
 
     unsafe fn destroy_unchecked(&mut self, entity: &mut GlweSecretKey64) {
-        entity.0.as_mut_tensor().fill_with_element(0u64);
+        zeroize(entity.0.as_mut_tensor().as_mut_slice());
     }
 }
 
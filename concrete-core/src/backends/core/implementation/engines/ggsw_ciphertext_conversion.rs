@@ -3,7 +3,8 @@ use crate::backends::core::implementation::entities::{
     FourierGgswCiphertext32, FourierGgswCiphertext64, GgswCiphertext32, GgswCiphertext64,
 };
 use crate::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
-use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::fft::{Complex64, ALLOWED_POLY_SIZE};
+use crate::prelude::CoreError;
 use crate::specification::engines::{
     GgswCiphertextConversionEngine, GgswCiphertextConversionError,
 };
@@ -61,6 +62,11 @@ impl GgswCiphertextConversionEngine<GgswCiphertext32, FourierGgswCiphertext32> f
         &mut self,
         input: &GgswCiphertext32,
     ) -> Result<FourierGgswCiphertext32, GgswCiphertextConversionError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&input.polynomial_size().0) {
+            return Err(GgswCiphertextConversionError::Engine(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
     }
 
@@ -136,6 +142,11 @@ impl GgswCiphertextConversionEngine<GgswCiphertext64, FourierGgswCiphertext64> f
         &mut self,
         input: &GgswCiphertext64,
     ) -> Result<FourierGgswCiphertext64, GgswCiphertextConversionError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&input.polynomial_size().0) {
+            return Err(GgswCiphertextConversionError::Engine(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
     }
 
@@ -2,6 +2,8 @@ use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
     FourierGgswCiphertext32, FourierGgswCiphertext64, GgswCiphertext32, GgswCiphertext64,
 };
+use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
+use crate::prelude::CoreError;
 use crate::specification::engines::{
     GgswCiphertextDiscardingConversionEngine, GgswCiphertextDiscardingConversionError,
 };
@@ -70,6 +72,11 @@ impl GgswCiphertextDiscardingConversionEngine<GgswCiphertext32, FourierGgswCiphe
         output: &mut FourierGgswCiphertext32,
         input: &GgswCiphertext32,
     ) -> Result<(), GgswCiphertextDiscardingConversionError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&input.polynomial_size().0) {
+            return Err(GgswCiphertextDiscardingConversionError::Engine(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         GgswCiphertextDiscardingConversionError::perform_generic_checks(output, input)?;
         unsafe { self.discard_convert_ggsw_ciphertext_unchecked(output, input) };
         Ok(())
@@ -151,6 +158,11 @@ impl GgswCiphertextDiscardingConversionEngine<GgswCiphertext64, FourierGgswCiphe
         output: &mut FourierGgswCiphertext64,
         input: &GgswCiphertext64,
     ) -> Result<(), GgswCiphertextDiscardingConversionError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&input.polynomial_size().0) {
+            return Err(GgswCiphertextDiscardingConversionError::Engine(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         GgswCiphertextDiscardingConversionError::perform_generic_checks(output, input)?;
         unsafe { self.discard_convert_ggsw_ciphertext_unchecked(output, input) };
         Ok(())
@@ -61,13 +61,17 @@ impl GgswCiphertextScalarEncryptionEngine<GlweSecretKey32, Plaintext32, GgswCiph
         decomposition_level_count: DecompositionLevelCount,
         decomposition_base_log: DecompositionBaseLog,
     ) -> Result<GgswCiphertext32, GgswCiphertextScalarEncryptionError<Self::EngineError>> {
+        let decomposition = GgswCiphertextScalarEncryptionError::perform_generic_checks::<u32>(
+            decomposition_level_count,
+            decomposition_base_log,
+        )?;
         Ok(unsafe {
             self.encrypt_scalar_ggsw_ciphertext_unchecked(
                 key,
                 input,
                 noise,
-                decomposition_level_count,
-                decomposition_base_log,
+                decomposition.level_count(),
+                decomposition.base_log(),
             )
         })
     }
@@ -147,13 +151,17 @@ impl GgswCiphertextScalarEncryptionEngine<GlweSecretKey64, Plaintext64, GgswCiph
         decomposition_level_count: DecompositionLevelCount,
         decomposition_base_log: DecompositionBaseLog,
     ) -> Result<GgswCiphertext64, GgswCiphertextScalarEncryptionError<Self::EngineError>> {
+        let decomposition = GgswCiphertextScalarEncryptionError::perform_generic_checks::<u64>(
+            decomposition_level_count,
+            decomposition_base_log,
+        )?;
         Ok(unsafe {
             self.encrypt_scalar_ggsw_ciphertext_unchecked(
                 key,
                 input,
                 noise,
-                decomposition_level_count,
-                decomposition_base_log,
+                decomposition.level_count(),
+                decomposition.base_log(),
             )
         })
     }
@@ -64,12 +64,15 @@ impl GgswCiphertextScalarTrivialEncryptionEngine<Plaintext32, GgswCiphertext32>
         input: &Plaintext32,
     ) -> Result<GgswCiphertext32, GgswCiphertextScalarTrivialEncryptionError<Self::EngineError>>
     {
+        let decomposition = GgswCiphertextScalarTrivialEncryptionError::perform_generic_checks::<
+            u32,
+        >(decomposition_level_count, decomposition_base_log)?;
         unsafe {
             Ok(self.trivially_encrypt_scalar_ggsw_ciphertext_unchecked(
                 polynomial_size,
                 glwe_size,
-                decomposition_level_count,
-                decomposition_base_log,
+                decomposition.level_count(),
+                decomposition.base_log(),
                 input,
             ))
         }
@@ -144,12 +147,15 @@ impl GgswCiphertextScalarTrivialEncryptionEngine<Plaintext64, GgswCiphertext64>
         input: &Plaintext64,
     ) -> Result<GgswCiphertext64, GgswCiphertextScalarTrivialEncryptionError<Self::EngineError>>
     {
+        let decomposition = GgswCiphertextScalarTrivialEncryptionError::perform_generic_checks::<
+            u64,
+        >(decomposition_level_count, decomposition_base_log)?;
         unsafe {
             Ok(self.trivially_encrypt_scalar_ggsw_ciphertext_unchecked(
                 polynomial_size,
                 glwe_size,
-                decomposition_level_count,
-                decomposition_base_log,
+                decomposition.level_count(),
+                decomposition.base_log(),
                 input,
             ))
         }
@@ -136,6 +136,12 @@ impl GlweCiphertextConversionEngine<GlweCiphertext64, FourierGlweCiphertext64> f
         &mut self,
         input: &GlweCiphertext64,
     ) -> Result<FourierGlweCiphertext64, GlweCiphertextConversionError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&input.polynomial_size().0) {
+            return Err(GlweCiphertextConversionError::from(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
+
         Ok(unsafe { self.convert_glwe_ciphertext_unchecked(input) })
     }
 
@@ -0,0 +1,301 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
+    PlaintextVector64,
+};
+use crate::specification::engines::{
+    GlweCiphertextDecryptionEngine, GlweCiphertextNoiseMeasurementEngine,
+    GlweCiphertextNoiseMeasurementError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. The noise is returned as signed integers, centered around zero.
+impl
+    GlweCiphertextNoiseMeasurementEngine<
+        GlweSecretKey32,
+        GlweCiphertext32,
+        PlaintextVector32,
+        Vec<i32>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, Variance};
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measured_noise: Vec<i32> =
+    ///     engine.measure_glwe_ciphertext_noise(&key, &ciphertext, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measured_noise.len(), polynomial_size.0);
+    /// // The noise is within ten standard deviations of zero.
+    /// assert!(measured_noise
+    ///     .iter()
+    ///     .all(|n| (*n as f64 / 2_f64.powi(32)).abs() < 10. * noise.get_standard_dev()));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_glwe_ciphertext_noise(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlweCiphertext32,
+        expected: &PlaintextVector32,
+    ) -> Result<Vec<i32>, GlweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        GlweCiphertextNoiseMeasurementError::perform_generic_checks(key, input, expected)?;
+        Ok(unsafe { self.measure_glwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_glwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlweCiphertext32,
+        expected: &PlaintextVector32,
+    ) -> Vec<i32> {
+        let decrypted: PlaintextVector32 = self.decrypt_glwe_ciphertext_unchecked(key, input);
+        decrypted
+            .0
+            .plaintext_iter()
+            .zip(expected.0.plaintext_iter())
+            .map(|(d, e)| d.0.wrapping_sub(e.0) as i32)
+            .collect()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. The noise is returned as signed integers, centered around zero.
+impl
+    GlweCiphertextNoiseMeasurementEngine<
+        GlweSecretKey64,
+        GlweCiphertext64,
+        PlaintextVector64,
+        Vec<i64>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, Variance};
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measured_noise: Vec<i64> =
+    ///     engine.measure_glwe_ciphertext_noise(&key, &ciphertext, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measured_noise.len(), polynomial_size.0);
+    /// // The noise is within ten standard deviations of zero.
+    /// assert!(measured_noise
+    ///     .iter()
+    ///     .all(|n| (*n as f64 / 2_f64.powi(64)).abs() < 10. * noise.get_standard_dev()));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_glwe_ciphertext_noise(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlweCiphertext64,
+        expected: &PlaintextVector64,
+    ) -> Result<Vec<i64>, GlweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        GlweCiphertextNoiseMeasurementError::perform_generic_checks(key, input, expected)?;
+        Ok(unsafe { self.measure_glwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_glwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlweCiphertext64,
+        expected: &PlaintextVector64,
+    ) -> Vec<i64> {
+        let decrypted: PlaintextVector64 = self.decrypt_glwe_ciphertext_unchecked(key, input);
+        decrypted
+            .0
+            .plaintext_iter()
+            .zip(expected.0.plaintext_iter())
+            .map(|(d, e)| d.0.wrapping_sub(e.0) as i64)
+            .collect()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. The noise is returned as torus values in $[-0.5, 0.5)$.
+impl
+    GlweCiphertextNoiseMeasurementEngine<
+        GlweSecretKey32,
+        GlweCiphertext32,
+        PlaintextVector32,
+        Vec<f64>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, Variance};
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measured_noise: Vec<f64> =
+    ///     engine.measure_glwe_ciphertext_noise(&key, &ciphertext, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measured_noise.len(), polynomial_size.0);
+    /// // The noise is within ten standard deviations of zero.
+    /// assert!(measured_noise
+    ///     .iter()
+    ///     .all(|n| n.abs() < 10. * noise.get_standard_dev()));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_glwe_ciphertext_noise(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlweCiphertext32,
+        expected: &PlaintextVector32,
+    ) -> Result<Vec<f64>, GlweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        GlweCiphertextNoiseMeasurementError::perform_generic_checks(key, input, expected)?;
+        Ok(unsafe { self.measure_glwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_glwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlweCiphertext32,
+        expected: &PlaintextVector32,
+    ) -> Vec<f64> {
+        let decrypted: PlaintextVector32 = self.decrypt_glwe_ciphertext_unchecked(key, input);
+        decrypted
+            .0
+            .plaintext_iter()
+            .zip(expected.0.plaintext_iter())
+            .map(|(d, e)| d.0.wrapping_sub(e.0) as i32 as f64 / 2_f64.powi(32))
+            .collect()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. The noise is returned as torus values in $[-0.5, 0.5)$.
+impl
+    GlweCiphertextNoiseMeasurementEngine<
+        GlweSecretKey64,
+        GlweCiphertext64,
+        PlaintextVector64,
+        Vec<f64>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, Variance};
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measured_noise: Vec<f64> =
+    ///     engine.measure_glwe_ciphertext_noise(&key, &ciphertext, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measured_noise.len(), polynomial_size.0);
+    /// // The noise is within ten standard deviations of zero.
+    /// assert!(measured_noise
+    ///     .iter()
+    ///     .all(|n| n.abs() < 10. * noise.get_standard_dev()));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_glwe_ciphertext_noise(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlweCiphertext64,
+        expected: &PlaintextVector64,
+    ) -> Result<Vec<f64>, GlweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        GlweCiphertextNoiseMeasurementError::perform_generic_checks(key, input, expected)?;
+        Ok(unsafe { self.measure_glwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_glwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlweCiphertext64,
+        expected: &PlaintextVector64,
+    ) -> Vec<f64> {
+        let decrypted: PlaintextVector64 = self.decrypt_glwe_ciphertext_unchecked(key, input);
+        decrypted
+            .0
+            .plaintext_iter()
+            .zip(expected.0.plaintext_iter())
+            .map(|(d, e)| d.0.wrapping_sub(e.0) as i64 as f64 / 2_f64.powi(64))
+            .collect()
+    }
+}
@@ -0,0 +1,442 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweCiphertextVectorMutView32,
+    GlweCiphertextVectorMutView64, GlweCiphertextVectorView32, GlweCiphertextVectorView64,
+};
+use crate::backends::core::private::crypto::glwe::GlweList as ImplGlweList;
+use crate::specification::engines::{
+    GlweCiphertextVectorCreationEngine, GlweCiphertextVectorCreationError,
+};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns a
+/// [`GlweCiphertextVector32`].
+impl GlweCiphertextVectorCreationEngine<Vec<u32>, GlweCiphertextVector32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let glwe_count = 3;
+    ///
+    /// // You have to make sure you size the container properly
+    /// let owned_container =
+    ///     vec![0_u32; glwe_dimension.to_glwe_size().0 * polynomial_size.0 * glwe_count];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let ciphertext_vector: GlweCiphertextVector32 =
+    ///     engine.create_glwe_ciphertext_vector(owned_container, glwe_dimension, polynomial_size)?;
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     ciphertext_vector.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(glwe_count)
+    /// );
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext_vector(
+        &mut self,
+        container: Vec<u32>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweCiphertextVector32, GlweCiphertextVectorCreationError<Self::EngineError>> {
+        GlweCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.create_glwe_ciphertext_vector_unchecked(container, glwe_dimension, polynomial_size)
+        })
+    }
+
+    unsafe fn create_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertextVector32 {
+        GlweCiphertextVector32(ImplGlweList::from_container(
+            container,
+            glwe_dimension,
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns a
+/// [`GlweCiphertextVector64`].
+impl GlweCiphertextVectorCreationEngine<Vec<u64>, GlweCiphertextVector64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let glwe_count = 3;
+    ///
+    /// // You have to make sure you size the container properly
+    /// let owned_container =
+    ///     vec![0_u64; glwe_dimension.to_glwe_size().0 * polynomial_size.0 * glwe_count];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let ciphertext_vector: GlweCiphertextVector64 =
+    ///     engine.create_glwe_ciphertext_vector(owned_container, glwe_dimension, polynomial_size)?;
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     ciphertext_vector.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(glwe_count)
+    /// );
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext_vector(
+        &mut self,
+        container: Vec<u64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweCiphertextVector64, GlweCiphertextVectorCreationError<Self::EngineError>> {
+        GlweCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.create_glwe_ciphertext_vector_unchecked(container, glwe_dimension, polynomial_size)
+        })
+    }
+
+    unsafe fn create_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertextVector64 {
+        GlweCiphertextVector64(ImplGlweList::from_container(
+            container,
+            glwe_dimension,
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns an
+/// immutable [`GlweCiphertextVectorView32`] that does not own its memory.
+impl<'data> GlweCiphertextVectorCreationEngine<&'data [u32], GlweCiphertextVectorView32<'data>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let glwe_count = 3;
+    ///
+    /// // You have to make sure you size the container properly
+    /// let mut owned_container =
+    ///     vec![0_u32; glwe_dimension.to_glwe_size().0 * polynomial_size.0 * glwe_count];
+    ///
+    /// let slice = &owned_container[..];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let ciphertext_vector_view: GlweCiphertextVectorView32 =
+    ///     engine.create_glwe_ciphertext_vector(slice, glwe_dimension, polynomial_size)?;
+    /// assert_eq!(ciphertext_vector_view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector_view.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     ciphertext_vector_view.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(glwe_count)
+    /// );
+    ///
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext_vector(
+        &mut self,
+        container: &'data [u32],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<
+        GlweCiphertextVectorView32<'data>,
+        GlweCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        GlweCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.create_glwe_ciphertext_vector_unchecked(container, glwe_dimension, polynomial_size)
+        })
+    }
+
+    unsafe fn create_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'data [u32],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertextVectorView32<'data> {
+        GlweCiphertextVectorView32(ImplGlweList::from_container(
+            container,
+            glwe_dimension,
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns a
+/// mutable [`GlweCiphertextVectorMutView32`] that does not own its memory.
+impl<'data>
+    GlweCiphertextVectorCreationEngine<&'data mut [u32], GlweCiphertextVectorMutView32<'data>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let glwe_count = 3;
+    ///
+    /// // You have to make sure you size the container properly
+    /// let mut owned_container =
+    ///     vec![0_u32; glwe_dimension.to_glwe_size().0 * polynomial_size.0 * glwe_count];
+    ///
+    /// let slice = &mut owned_container[..];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let ciphertext_vector_view: GlweCiphertextVectorMutView32 =
+    ///     engine.create_glwe_ciphertext_vector(slice, glwe_dimension, polynomial_size)?;
+    /// assert_eq!(ciphertext_vector_view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector_view.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     ciphertext_vector_view.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(glwe_count)
+    /// );
+    ///
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext_vector(
+        &mut self,
+        container: &'data mut [u32],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<
+        GlweCiphertextVectorMutView32<'data>,
+        GlweCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        GlweCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.create_glwe_ciphertext_vector_unchecked(container, glwe_dimension, polynomial_size)
+        })
+    }
+
+    unsafe fn create_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'data mut [u32],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertextVectorMutView32<'data> {
+        GlweCiphertextVectorMutView32(ImplGlweList::from_container(
+            container,
+            glwe_dimension,
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns an
+/// immutable [`GlweCiphertextVectorView64`] that does not own its memory.
+impl<'data> GlweCiphertextVectorCreationEngine<&'data [u64], GlweCiphertextVectorView64<'data>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let glwe_count = 3;
+    ///
+    /// // You have to make sure you size the container properly
+    /// let mut owned_container =
+    ///     vec![0_u64; glwe_dimension.to_glwe_size().0 * polynomial_size.0 * glwe_count];
+    ///
+    /// let slice = &owned_container[..];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let ciphertext_vector_view: GlweCiphertextVectorView64 =
+    ///     engine.create_glwe_ciphertext_vector(slice, glwe_dimension, polynomial_size)?;
+    /// assert_eq!(ciphertext_vector_view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector_view.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     ciphertext_vector_view.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(glwe_count)
+    /// );
+    ///
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext_vector(
+        &mut self,
+        container: &'data [u64],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<
+        GlweCiphertextVectorView64<'data>,
+        GlweCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        GlweCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.create_glwe_ciphertext_vector_unchecked(container, glwe_dimension, polynomial_size)
+        })
+    }
+
+    unsafe fn create_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'data [u64],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertextVectorView64<'data> {
+        GlweCiphertextVectorView64(ImplGlweList::from_container(
+            container,
+            glwe_dimension,
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns a
+/// mutable [`GlweCiphertextVectorMutView64`] that does not own its memory.
+impl<'data>
+    GlweCiphertextVectorCreationEngine<&'data mut [u64], GlweCiphertextVectorMutView64<'data>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let glwe_count = 3;
+    ///
+    /// // You have to make sure you size the container properly
+    /// let mut owned_container =
+    ///     vec![0_u64; glwe_dimension.to_glwe_size().0 * polynomial_size.0 * glwe_count];
+    ///
+    /// let slice = &mut owned_container[..];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let ciphertext_vector_view: GlweCiphertextVectorMutView64 =
+    ///     engine.create_glwe_ciphertext_vector(slice, glwe_dimension, polynomial_size)?;
+    /// assert_eq!(ciphertext_vector_view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector_view.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     ciphertext_vector_view.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(glwe_count)
+    /// );
+    ///
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext_vector(
+        &mut self,
+        container: &'data mut [u64],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<
+        GlweCiphertextVectorMutView64<'data>,
+        GlweCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        GlweCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.create_glwe_ciphertext_vector_unchecked(container, glwe_dimension, polynomial_size)
+        })
+    }
+
+    unsafe fn create_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'data mut [u64],
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertextVectorMutView64<'data> {
+        GlweCiphertextVectorMutView64(ImplGlweList::from_container(
+            container,
+            glwe_dimension,
+            polynomial_size,
+        ))
+    }
+}
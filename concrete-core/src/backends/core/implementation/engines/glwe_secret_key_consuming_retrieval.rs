@@ -0,0 +1,86 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{GlweSecretKey32, GlweSecretKey64};
+use crate::backends::core::private::math::tensor::AsMutTensor;
+use crate::specification::engines::{
+    GlweSecretKeyConsumingRetrievalEngine, GlweSecretKeyConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyConsumingRetrievalEngine`] for [`CoreEngine`] that returns the
+/// underlying vec of a [`GlweSecretKey32`] consuming it in the process
+impl GlweSecretKeyConsumingRetrievalEngine<GlweSecretKey32, Vec<u32>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let polynomial_size = PolynomialSize(4);
+    /// let raw_key = vec![0_u32, 1, 1, 0, 1, 0, 0, 1];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let glwe_secret_key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key_from_container(raw_key.clone(), polynomial_size)?;
+    /// let retrieved_key = engine.consume_retrieve_glwe_secret_key(glwe_secret_key)?;
+    /// assert_eq!(raw_key, retrieved_key);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_glwe_secret_key(
+        &mut self,
+        secret_key: GlweSecretKey32,
+    ) -> Result<Vec<u32>, GlweSecretKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_glwe_secret_key_unchecked(secret_key) })
+    }
+
+    unsafe fn consume_retrieve_glwe_secret_key_unchecked(
+        &mut self,
+        mut secret_key: GlweSecretKey32,
+    ) -> Vec<u32> {
+        // The container is moved out, and the emptied key is dropped.
+        std::mem::take(secret_key.0.as_mut_tensor().as_mut_container())
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyConsumingRetrievalEngine`] for [`CoreEngine`] that returns the
+/// underlying vec of a [`GlweSecretKey64`] consuming it in the process
+impl GlweSecretKeyConsumingRetrievalEngine<GlweSecretKey64, Vec<u64>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let polynomial_size = PolynomialSize(4);
+    /// let raw_key = vec![0_u64, 1, 1, 0, 1, 0, 0, 1];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key_from_container(raw_key.clone(), polynomial_size)?;
+    /// let retrieved_key = engine.consume_retrieve_glwe_secret_key(glwe_secret_key)?;
+    /// assert_eq!(raw_key, retrieved_key);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_glwe_secret_key(
+        &mut self,
+        secret_key: GlweSecretKey64,
+    ) -> Result<Vec<u64>, GlweSecretKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_glwe_secret_key_unchecked(secret_key) })
+    }
+
+    unsafe fn consume_retrieve_glwe_secret_key_unchecked(
+        &mut self,
+        mut secret_key: GlweSecretKey64,
+    ) -> Vec<u64> {
+        // The container is moved out, and the emptied key is dropped.
+        std::mem::take(secret_key.0.as_mut_tensor().as_mut_container())
+    }
+}
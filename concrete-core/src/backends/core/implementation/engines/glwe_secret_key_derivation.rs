@@ -0,0 +1,161 @@
+use concrete_commons::parameters::{GlweDimension, KeyDerivationLabel, MasterSeed, PolynomialSize};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{GlweSecretKey32, GlweSecretKey64};
+use crate::backends::core::private::crypto::secret::generators::{
+    DerivedKeyKind, KeyDerivationGenerator,
+};
+use crate::backends::core::private::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
+use crate::specification::engines::{GlweSecretKeyDerivationEngine, GlweSecretKeyDerivationError};
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyDerivationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl GlweSecretKeyDerivationEngine<GlweSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, KeyDerivationLabel, MasterSeed, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let master_seed = MasterSeed(0x0123_4567_89ab_cdef);
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let glwe_secret_key: GlweSecretKey32 = engine.derive_glwe_secret_key(
+    ///     master_seed,
+    ///     KeyDerivationLabel(1),
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    /// )?;
+    /// let same_glwe_secret_key: GlweSecretKey32 = engine.derive_glwe_secret_key(
+    ///     master_seed,
+    ///     KeyDerivationLabel(1),
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    /// )?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(glwe_secret_key, same_glwe_secret_key);
+    /// engine.destroy(glwe_secret_key)?;
+    /// engine.destroy(same_glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn derive_glwe_secret_key(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKey32, GlweSecretKeyDerivationError<Self::EngineError>> {
+        GlweSecretKeyDerivationError::perform_generic_checks(glwe_dimension, polynomial_size)?;
+        Ok(unsafe {
+            self.derive_glwe_secret_key_unchecked(
+                master_seed,
+                label,
+                glwe_dimension,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn derive_glwe_secret_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKey32 {
+        let mut secret_generator = KeyDerivationGenerator::new(master_seed.0)
+            .derive_secret_generator(DerivedKeyKind::GlweSecretKey, label.0);
+        GlweSecretKey32(ImplGlweSecretKey::generate_binary(
+            glwe_dimension,
+            polynomial_size,
+            &mut secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyDerivationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl GlweSecretKeyDerivationEngine<GlweSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, KeyDerivationLabel, MasterSeed, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let master_seed = MasterSeed(0x0123_4567_89ab_cdef);
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let glwe_secret_key: GlweSecretKey64 = engine.derive_glwe_secret_key(
+    ///     master_seed,
+    ///     KeyDerivationLabel(1),
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    /// )?;
+    /// let same_glwe_secret_key: GlweSecretKey64 = engine.derive_glwe_secret_key(
+    ///     master_seed,
+    ///     KeyDerivationLabel(1),
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    /// )?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(glwe_secret_key, same_glwe_secret_key);
+    /// engine.destroy(glwe_secret_key)?;
+    /// engine.destroy(same_glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn derive_glwe_secret_key(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKey64, GlweSecretKeyDerivationError<Self::EngineError>> {
+        GlweSecretKeyDerivationError::perform_generic_checks(glwe_dimension, polynomial_size)?;
+        Ok(unsafe {
+            self.derive_glwe_secret_key_unchecked(
+                master_seed,
+                label,
+                glwe_dimension,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn derive_glwe_secret_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKey64 {
+        let mut secret_generator = KeyDerivationGenerator::new(master_seed.0)
+            .derive_secret_generator(DerivedKeyKind::GlweSecretKey, label.0);
+        GlweSecretKey64(ImplGlweSecretKey::generate_binary(
+            glwe_dimension,
+            polynomial_size,
+            &mut secret_generator,
+        ))
+    }
+}
@@ -0,0 +1,121 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{GlweSecretKey32, GlweSecretKey64};
+use crate::backends::core::private::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
+use crate::specification::engines::{
+    GlweSecretKeyFromContainerCreationEngine, GlweSecretKeyFromContainerCreationError,
+};
+use concrete_commons::parameters::PolynomialSize;
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyFromContainerCreationEngine`] for [`CoreEngine`] which
+/// returns a [`GlweSecretKey32`].
+impl GlweSecretKeyFromContainerCreationEngine<Vec<u32>, GlweSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here the key is created outside of the engine, its values must be binary
+    /// let raw_key = vec![0_u32, 1, 1, 0, 1, 0, 0, 1];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let glwe_secret_key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key_from_container(raw_key, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key_from_container(
+        &mut self,
+        container: Vec<u32>,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKey32, GlweSecretKeyFromContainerCreationError<Self::EngineError>> {
+        GlweSecretKeyFromContainerCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            polynomial_size,
+        )?;
+        GlweSecretKeyFromContainerCreationError::<Self::EngineError>::perform_binary_key_checks(
+            container.as_slice(),
+        )?;
+        Ok(unsafe {
+            self.create_glwe_secret_key_from_container_unchecked(container, polynomial_size)
+        })
+    }
+
+    unsafe fn create_glwe_secret_key_from_container_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKey32 {
+        GlweSecretKey32(ImplGlweSecretKey::binary_from_container(
+            container,
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyFromContainerCreationEngine`] for [`CoreEngine`] which
+/// returns a [`GlweSecretKey64`].
+impl GlweSecretKeyFromContainerCreationEngine<Vec<u64>, GlweSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here the key is created outside of the engine, its values must be binary
+    /// let raw_key = vec![0_u64, 1, 1, 0, 1, 0, 0, 1];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key_from_container(raw_key, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key_from_container(
+        &mut self,
+        container: Vec<u64>,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKey64, GlweSecretKeyFromContainerCreationError<Self::EngineError>> {
+        GlweSecretKeyFromContainerCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            polynomial_size,
+        )?;
+        GlweSecretKeyFromContainerCreationError::<Self::EngineError>::perform_binary_key_checks(
+            container.as_slice(),
+        )?;
+        Ok(unsafe {
+            self.create_glwe_secret_key_from_container_unchecked(container, polynomial_size)
+        })
+    }
+
+    unsafe fn create_glwe_secret_key_from_container_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKey64 {
+        GlweSecretKey64(ImplGlweSecretKey::binary_from_container(
+            container,
+            polynomial_size,
+        ))
+    }
+}
@@ -2,6 +2,8 @@ use crate::backends::core::engines::CoreEngine;
 use crate::backends::core::entities::{
     GlweSecretKey32, GlweSecretKey64, LweSecretKey32, LweSecretKey64,
 };
+use crate::backends::core::private::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::backends::core::private::math::tensor::AsMutTensor;
 use crate::specification::engines::{
     GlweToLweSecretKeyTransmutationEngine, GlweToLweSecretKeyTransmutationEngineError,
 };
@@ -40,9 +42,11 @@ impl GlweToLweSecretKeyTransmutationEngine<GlweSecretKey32, LweSecretKey32> for
 
     unsafe fn transmute_glwe_secret_key_to_lwe_secret_key_unchecked(
         &mut self,
-        glwe_secret_key: GlweSecretKey32,
+        mut glwe_secret_key: GlweSecretKey32,
     ) -> LweSecretKey32 {
-        LweSecretKey32(glwe_secret_key.0.into_lwe_secret_key())
+        // The container is moved out, and the emptied GLWE key is dropped.
+        let container = std::mem::take(glwe_secret_key.0.as_mut_tensor().as_mut_container());
+        LweSecretKey32(ImplLweSecretKey::binary_from_container(container))
     }
 }
 
@@ -80,8 +84,10 @@ impl GlweToLweSecretKeyTransmutationEngine<GlweSecretKey64, LweSecretKey64> for
 
     unsafe fn transmute_glwe_secret_key_to_lwe_secret_key_unchecked(
         &mut self,
-        glwe_secret_key: GlweSecretKey64,
+        mut glwe_secret_key: GlweSecretKey64,
     ) -> LweSecretKey64 {
-        LweSecretKey64(glwe_secret_key.0.into_lwe_secret_key())
+        // The container is moved out, and the emptied GLWE key is dropped.
+        let container = std::mem::take(glwe_secret_key.0.as_mut_tensor().as_mut_container());
+        LweSecretKey64(ImplLweSecretKey::binary_from_container(container))
     }
 }
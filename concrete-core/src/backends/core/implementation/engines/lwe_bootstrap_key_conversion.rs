@@ -3,7 +3,8 @@ use crate::backends::core::implementation::entities::{
     FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweBootstrapKey32, LweBootstrapKey64,
 };
 use crate::backends::core::private::crypto::bootstrap::FourierBootstrapKey as ImplFourierBootstrapKey;
-use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::fft::{Complex64, ALLOWED_POLY_SIZE};
+use crate::prelude::CoreError;
 use crate::specification::engines::{
     LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
 };
@@ -54,6 +55,11 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, FourierLweBootstrapKey32
         &mut self,
         input: &LweBootstrapKey32,
     ) -> Result<FourierLweBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&input.polynomial_size().0) {
+            return Err(LweBootstrapKeyConversionError::Engine(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
     }
 
@@ -124,6 +130,11 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, FourierLweBootstrapKey64
         &mut self,
         input: &LweBootstrapKey64,
     ) -> Result<FourierLweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&input.polynomial_size().0) {
+            return Err(LweBootstrapKeyConversionError::Engine(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
     }
 
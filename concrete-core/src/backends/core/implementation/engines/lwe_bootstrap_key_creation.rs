@@ -10,8 +10,8 @@ use crate::backends::core::private::crypto::bootstrap::{
     FourierBootstrapKey as ImplFourierBootstrapKey,
     StandardBootstrapKey as ImplStandardBootstrapKey,
 };
-use crate::backends::core::private::math::fft::Complex64;
-use crate::prelude::LweBootstrapKeyEntity;
+use crate::backends::core::private::math::fft::{Complex64, ALLOWED_POLY_SIZE};
+use crate::prelude::{CoreError, GlweSecretKeyEntity, LweBootstrapKeyEntity};
 use crate::specification::engines::{LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError};
 
 /// # Description:
@@ -63,17 +63,16 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey32, GlweSecretKey32, LweBootstrap
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
     ) -> Result<LweBootstrapKey32, LweBootstrapKeyCreationError<Self::EngineError>> {
-        LweBootstrapKeyCreationError::perform_generic_checks(
+        let decomposition = LweBootstrapKeyCreationError::perform_generic_checks::<u32>(
             decomposition_base_log,
             decomposition_level_count,
-            32,
         )?;
         Ok(unsafe {
             self.create_lwe_bootstrap_key_unchecked(
                 input_key,
                 output_key,
-                decomposition_base_log,
-                decomposition_level_count,
+                decomposition.base_log(),
+                decomposition.level_count(),
                 noise,
             )
         })
@@ -154,17 +153,16 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, LweBootstrap
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
     ) -> Result<LweBootstrapKey64, LweBootstrapKeyCreationError<Self::EngineError>> {
-        LweBootstrapKeyCreationError::perform_generic_checks(
+        let decomposition = LweBootstrapKeyCreationError::perform_generic_checks::<u64>(
             decomposition_base_log,
             decomposition_level_count,
-            64,
         )?;
         Ok(unsafe {
             self.create_lwe_bootstrap_key_unchecked(
                 input_key,
                 output_key,
-                decomposition_base_log,
-                decomposition_level_count,
+                decomposition.base_log(),
+                decomposition.level_count(),
                 noise,
             )
         })
@@ -245,17 +243,21 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey32, GlweSecretKey32, FourierLweBo
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
     ) -> Result<FourierLweBootstrapKey32, LweBootstrapKeyCreationError<Self::EngineError>> {
-        LweBootstrapKeyCreationError::perform_generic_checks(
+        let decomposition = LweBootstrapKeyCreationError::perform_generic_checks::<u32>(
             decomposition_base_log,
             decomposition_level_count,
-            32,
         )?;
+        if !ALLOWED_POLY_SIZE.contains(&output_key.polynomial_size().0) {
+            return Err(LweBootstrapKeyCreationError::Engine(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         Ok(unsafe {
             self.create_lwe_bootstrap_key_unchecked(
                 input_key,
                 output_key,
-                decomposition_base_log,
-                decomposition_level_count,
+                decomposition.base_log(),
+                decomposition.level_count(),
                 noise,
             )
         })
@@ -351,17 +353,21 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, FourierLweBo
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
     ) -> Result<FourierLweBootstrapKey64, LweBootstrapKeyCreationError<Self::EngineError>> {
-        LweBootstrapKeyCreationError::perform_generic_checks(
+        let decomposition = LweBootstrapKeyCreationError::perform_generic_checks::<u64>(
             decomposition_base_log,
             decomposition_level_count,
-            64,
         )?;
+        if !ALLOWED_POLY_SIZE.contains(&output_key.polynomial_size().0) {
+            return Err(LweBootstrapKeyCreationError::Engine(
+                CoreError::UnsupportedPolynomialSize,
+            ));
+        }
         Ok(unsafe {
             self.create_lwe_bootstrap_key_unchecked(
                 input_key,
                 output_key,
-                decomposition_base_log,
-                decomposition_level_count,
+                decomposition.base_log(),
+                decomposition.level_count(),
                 noise,
             )
         })
@@ -0,0 +1,253 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, KeyDerivationLabel, MasterSeed,
+};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweSecretKey32, GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweSecretKey32,
+    LweSecretKey64,
+};
+use crate::backends::core::private::crypto::bootstrap::StandardBootstrapKey;
+use crate::backends::core::private::crypto::secret::generators::{
+    DerivedKeyKind, KeyDerivationGenerator,
+};
+use crate::specification::engines::{
+    LweBootstrapKeyDerivationEngine, LweBootstrapKeyDerivationError,
+};
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDerivationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It outputs a bootstrap key in the standard domain.
+impl LweBootstrapKeyDerivationEngine<LweSecretKey32, GlweSecretKey32, LweBootstrapKey32>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, KeyDerivationLabel,
+    ///     LweDimension, MasterSeed, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let master_seed = MasterSeed(0x0123_4567_89ab_cdef);
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 =
+    ///     engine.derive_lwe_secret_key(master_seed, KeyDerivationLabel(0), lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     engine.derive_glwe_secret_key(master_seed, KeyDerivationLabel(1), glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey32 = engine.derive_lwe_bootstrap_key(
+    ///     master_seed,
+    ///     KeyDerivationLabel(2),
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     dec_bl,
+    ///     dec_lc,
+    ///     noise,
+    /// )?;
+    /// let same_bsk: LweBootstrapKey32 = engine.derive_lwe_bootstrap_key(
+    ///     master_seed,
+    ///     KeyDerivationLabel(2),
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     dec_bl,
+    ///     dec_lc,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk, same_bsk);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(same_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn derive_lwe_bootstrap_key(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        input_key: &LweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweBootstrapKey32, LweBootstrapKeyDerivationError<Self::EngineError>> {
+        let decomposition = LweBootstrapKeyDerivationError::perform_generic_checks::<u32>(
+            decomposition_base_log,
+            decomposition_level_count,
+        )?;
+        Ok(unsafe {
+            self.derive_lwe_bootstrap_key_unchecked(
+                master_seed,
+                label,
+                input_key,
+                output_key,
+                decomposition.base_log(),
+                decomposition.level_count(),
+                noise,
+            )
+        })
+    }
+
+    unsafe fn derive_lwe_bootstrap_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        input_key: &LweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweBootstrapKey32 {
+        let mut encryption_generator = KeyDerivationGenerator::new(master_seed.0)
+            .derive_encryption_generator(DerivedKeyKind::LweBootstrapKey, label.0);
+        let mut key = StandardBootstrapKey::allocate(
+            0,
+            output_key.0.key_size().to_glwe_size(),
+            output_key.0.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.0.key_size(),
+        );
+        key.fill_with_new_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut encryption_generator,
+        );
+        LweBootstrapKey32(key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDerivationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It outputs a bootstrap key in the standard domain.
+impl LweBootstrapKeyDerivationEngine<LweSecretKey64, GlweSecretKey64, LweBootstrapKey64>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, KeyDerivationLabel,
+    ///     LweDimension, MasterSeed, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let master_seed = MasterSeed(0x0123_4567_89ab_cdef);
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 =
+    ///     engine.derive_lwe_secret_key(master_seed, KeyDerivationLabel(0), lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     engine.derive_glwe_secret_key(master_seed, KeyDerivationLabel(1), glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey64 = engine.derive_lwe_bootstrap_key(
+    ///     master_seed,
+    ///     KeyDerivationLabel(2),
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     dec_bl,
+    ///     dec_lc,
+    ///     noise,
+    /// )?;
+    /// let same_bsk: LweBootstrapKey64 = engine.derive_lwe_bootstrap_key(
+    ///     master_seed,
+    ///     KeyDerivationLabel(2),
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     dec_bl,
+    ///     dec_lc,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk, same_bsk);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(same_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn derive_lwe_bootstrap_key(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweBootstrapKey64, LweBootstrapKeyDerivationError<Self::EngineError>> {
+        let decomposition = LweBootstrapKeyDerivationError::perform_generic_checks::<u64>(
+            decomposition_base_log,
+            decomposition_level_count,
+        )?;
+        Ok(unsafe {
+            self.derive_lwe_bootstrap_key_unchecked(
+                master_seed,
+                label,
+                input_key,
+                output_key,
+                decomposition.base_log(),
+                decomposition.level_count(),
+                noise,
+            )
+        })
+    }
+
+    unsafe fn derive_lwe_bootstrap_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweBootstrapKey64 {
+        let mut encryption_generator = KeyDerivationGenerator::new(master_seed.0)
+            .derive_encryption_generator(DerivedKeyKind::LweBootstrapKey, label.0);
+        let mut key = StandardBootstrapKey::allocate(
+            0,
+            output_key.0.key_size().to_glwe_size(),
+            output_key.0.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.0.key_size(),
+        );
+        key.fill_with_new_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut encryption_generator,
+        );
+        LweBootstrapKey64(key)
+    }
+}
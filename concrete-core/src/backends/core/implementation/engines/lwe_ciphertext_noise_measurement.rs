@@ -0,0 +1,242 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDecryptionEngine, LweCiphertextNoiseMeasurementEngine,
+    LweCiphertextNoiseMeasurementError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. The noise is returned as a signed integer, centered around zero.
+impl LweCiphertextNoiseMeasurementEngine<LweSecretKey32, LweCiphertext32, Plaintext32, i32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, Variance};
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let measured_noise: i32 = engine.measure_lwe_ciphertext_noise(&key, &ciphertext, &plaintext)?;
+    /// #
+    /// // The noise is within ten standard deviations of zero.
+    /// let torus_noise = measured_noise as f64 / 2_f64.powi(32);
+    /// assert!(torus_noise.abs() < 10. * noise.get_standard_dev());
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_noise(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertext32,
+        expected: &Plaintext32,
+    ) -> Result<i32, LweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        LweCiphertextNoiseMeasurementError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.measure_lwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertext32,
+        expected: &Plaintext32,
+    ) -> i32 {
+        let decrypted: Plaintext32 = self.decrypt_lwe_ciphertext_unchecked(key, input);
+        decrypted.0 .0.wrapping_sub(expected.0 .0) as i32
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. The noise is returned as a signed integer, centered around zero.
+impl LweCiphertextNoiseMeasurementEngine<LweSecretKey64, LweCiphertext64, Plaintext64, i64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, Variance};
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let measured_noise: i64 = engine.measure_lwe_ciphertext_noise(&key, &ciphertext, &plaintext)?;
+    /// #
+    /// // The noise is within ten standard deviations of zero.
+    /// let torus_noise = measured_noise as f64 / 2_f64.powi(64);
+    /// assert!(torus_noise.abs() < 10. * noise.get_standard_dev());
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_noise(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertext64,
+        expected: &Plaintext64,
+    ) -> Result<i64, LweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        LweCiphertextNoiseMeasurementError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.measure_lwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertext64,
+        expected: &Plaintext64,
+    ) -> i64 {
+        let decrypted: Plaintext64 = self.decrypt_lwe_ciphertext_unchecked(key, input);
+        decrypted.0 .0.wrapping_sub(expected.0 .0) as i64
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. The noise is returned as a torus value in $[-0.5, 0.5)$.
+impl LweCiphertextNoiseMeasurementEngine<LweSecretKey32, LweCiphertext32, Plaintext32, f64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, Variance};
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let measured_noise: f64 = engine.measure_lwe_ciphertext_noise(&key, &ciphertext, &plaintext)?;
+    /// #
+    /// // The noise is within ten standard deviations of zero.
+    /// assert!(measured_noise.abs() < 10. * noise.get_standard_dev());
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_noise(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertext32,
+        expected: &Plaintext32,
+    ) -> Result<f64, LweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        LweCiphertextNoiseMeasurementError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.measure_lwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertext32,
+        expected: &Plaintext32,
+    ) -> f64 {
+        let decrypted: Plaintext32 = self.decrypt_lwe_ciphertext_unchecked(key, input);
+        decrypted.0 .0.wrapping_sub(expected.0 .0) as i32 as f64 / 2_f64.powi(32)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. The noise is returned as a torus value in $[-0.5, 0.5)$.
+impl LweCiphertextNoiseMeasurementEngine<LweSecretKey64, LweCiphertext64, Plaintext64, f64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, Variance};
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let measured_noise: f64 = engine.measure_lwe_ciphertext_noise(&key, &ciphertext, &plaintext)?;
+    /// #
+    /// // The noise is within ten standard deviations of zero.
+    /// assert!(measured_noise.abs() < 10. * noise.get_standard_dev());
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_noise(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertext64,
+        expected: &Plaintext64,
+    ) -> Result<f64, LweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        LweCiphertextNoiseMeasurementError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.measure_lwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertext64,
+        expected: &Plaintext64,
+    ) -> f64 {
+        let decrypted: Plaintext64 = self.decrypt_lwe_ciphertext_unchecked(key, input);
+        decrypted.0 .0.wrapping_sub(expected.0 .0) as i64 as f64 / 2_f64.powi(64)
+    }
+}
@@ -0,0 +1,297 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDecryptionEngine, LweCiphertextVectorNoiseMeasurementEngine,
+    LweCiphertextVectorNoiseMeasurementError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. The noise is returned as signed integers, centered around zero.
+impl
+    LweCiphertextVectorNoiseMeasurementEngine<
+        LweSecretKey32,
+        LweCiphertextVector32,
+        PlaintextVector32,
+        Vec<i32>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, Variance};
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measured_noise: Vec<i32> =
+    ///     engine.measure_lwe_ciphertext_vector_noise(&key, &ciphertext_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measured_noise.len(), 3);
+    /// // The noise is within ten standard deviations of zero.
+    /// assert!(measured_noise
+    ///     .iter()
+    ///     .all(|n| (*n as f64 / 2_f64.powi(32)).abs() < 10. * noise.get_standard_dev()));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+        expected: &PlaintextVector32,
+    ) -> Result<Vec<i32>, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>> {
+        LweCiphertextVectorNoiseMeasurementError::perform_generic_checks(key, input, expected)?;
+        Ok(unsafe { self.measure_lwe_ciphertext_vector_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+        expected: &PlaintextVector32,
+    ) -> Vec<i32> {
+        let decrypted: PlaintextVector32 = self.decrypt_lwe_ciphertext_vector_unchecked(key, input);
+        decrypted
+            .0
+            .plaintext_iter()
+            .zip(expected.0.plaintext_iter())
+            .map(|(d, e)| d.0.wrapping_sub(e.0) as i32)
+            .collect()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. The noise is returned as signed integers, centered around zero.
+impl
+    LweCiphertextVectorNoiseMeasurementEngine<
+        LweSecretKey64,
+        LweCiphertextVector64,
+        PlaintextVector64,
+        Vec<i64>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, Variance};
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measured_noise: Vec<i64> =
+    ///     engine.measure_lwe_ciphertext_vector_noise(&key, &ciphertext_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measured_noise.len(), 3);
+    /// // The noise is within ten standard deviations of zero.
+    /// assert!(measured_noise
+    ///     .iter()
+    ///     .all(|n| (*n as f64 / 2_f64.powi(64)).abs() < 10. * noise.get_standard_dev()));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+        expected: &PlaintextVector64,
+    ) -> Result<Vec<i64>, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>> {
+        LweCiphertextVectorNoiseMeasurementError::perform_generic_checks(key, input, expected)?;
+        Ok(unsafe { self.measure_lwe_ciphertext_vector_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+        expected: &PlaintextVector64,
+    ) -> Vec<i64> {
+        let decrypted: PlaintextVector64 = self.decrypt_lwe_ciphertext_vector_unchecked(key, input);
+        decrypted
+            .0
+            .plaintext_iter()
+            .zip(expected.0.plaintext_iter())
+            .map(|(d, e)| d.0.wrapping_sub(e.0) as i64)
+            .collect()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. The noise is returned as torus values in $[-0.5, 0.5)$.
+impl
+    LweCiphertextVectorNoiseMeasurementEngine<
+        LweSecretKey32,
+        LweCiphertextVector32,
+        PlaintextVector32,
+        Vec<f64>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, Variance};
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measured_noise: Vec<f64> =
+    ///     engine.measure_lwe_ciphertext_vector_noise(&key, &ciphertext_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measured_noise.len(), 3);
+    /// // The noise is within ten standard deviations of zero.
+    /// assert!(measured_noise
+    ///     .iter()
+    ///     .all(|n| n.abs() < 10. * noise.get_standard_dev()));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+        expected: &PlaintextVector32,
+    ) -> Result<Vec<f64>, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>> {
+        LweCiphertextVectorNoiseMeasurementError::perform_generic_checks(key, input, expected)?;
+        Ok(unsafe { self.measure_lwe_ciphertext_vector_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+        expected: &PlaintextVector32,
+    ) -> Vec<f64> {
+        let decrypted: PlaintextVector32 = self.decrypt_lwe_ciphertext_vector_unchecked(key, input);
+        decrypted
+            .0
+            .plaintext_iter()
+            .zip(expected.0.plaintext_iter())
+            .map(|(d, e)| d.0.wrapping_sub(e.0) as i32 as f64 / 2_f64.powi(32))
+            .collect()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. The noise is returned as torus values in $[-0.5, 0.5)$.
+impl
+    LweCiphertextVectorNoiseMeasurementEngine<
+        LweSecretKey64,
+        LweCiphertextVector64,
+        PlaintextVector64,
+        Vec<f64>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::{DispersionParameter, Variance};
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measured_noise: Vec<f64> =
+    ///     engine.measure_lwe_ciphertext_vector_noise(&key, &ciphertext_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measured_noise.len(), 3);
+    /// // The noise is within ten standard deviations of zero.
+    /// assert!(measured_noise
+    ///     .iter()
+    ///     .all(|n| n.abs() < 10. * noise.get_standard_dev()));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+        expected: &PlaintextVector64,
+    ) -> Result<Vec<f64>, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>> {
+        LweCiphertextVectorNoiseMeasurementError::perform_generic_checks(key, input, expected)?;
+        Ok(unsafe { self.measure_lwe_ciphertext_vector_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+        expected: &PlaintextVector64,
+    ) -> Vec<f64> {
+        let decrypted: PlaintextVector64 = self.decrypt_lwe_ciphertext_vector_unchecked(key, input);
+        decrypted
+            .0
+            .plaintext_iter()
+            .zip(expected.0.plaintext_iter())
+            .map(|(d, e)| d.0.wrapping_sub(e.0) as i64 as f64 / 2_f64.powi(64))
+            .collect()
+    }
+}
@@ -70,17 +70,16 @@ impl LweKeyswitchKeyCreationEngine<LweSecretKey32, LweSecretKey32, LweKeyswitchK
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
     ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyCreationError<Self::EngineError>> {
-        LweKeyswitchKeyCreationError::perform_generic_checks(
+        let decomposition = LweKeyswitchKeyCreationError::perform_generic_checks::<u32>(
             decomposition_level_count,
             decomposition_base_log,
-            32,
         )?;
         Ok(unsafe {
             self.create_lwe_keyswitch_key_unchecked(
                 input_key,
                 output_key,
-                decomposition_level_count,
-                decomposition_base_log,
+                decomposition.level_count(),
+                decomposition.base_log(),
                 noise,
             )
         })
@@ -172,17 +171,16 @@ impl LweKeyswitchKeyCreationEngine<LweSecretKey64, LweSecretKey64, LweKeyswitchK
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
     ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyCreationError<Self::EngineError>> {
-        LweKeyswitchKeyCreationError::perform_generic_checks(
+        let decomposition = LweKeyswitchKeyCreationError::perform_generic_checks::<u64>(
             decomposition_level_count,
             decomposition_base_log,
-            64,
         )?;
         Ok(unsafe {
             self.create_lwe_keyswitch_key_unchecked(
                 input_key,
                 output_key,
-                decomposition_level_count,
-                decomposition_base_log,
+                decomposition.level_count(),
+                decomposition.base_log(),
                 noise,
             )
         })
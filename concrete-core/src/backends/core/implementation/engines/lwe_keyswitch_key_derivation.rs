@@ -0,0 +1,253 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, KeyDerivationLabel, MasterSeed,
+};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey32, LweSecretKey64,
+};
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::backends::core::private::crypto::secret::generators::{
+    DerivedKeyKind, KeyDerivationGenerator,
+};
+use crate::specification::engines::{
+    LweKeyswitchKeyDerivationEngine, LweKeyswitchKeyDerivationError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyDerivationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweKeyswitchKeyDerivationEngine<LweSecretKey32, LweSecretKey32, LweKeyswitchKey32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, KeyDerivationLabel, LweDimension,
+    ///     MasterSeed,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let master_seed = MasterSeed(0x0123_4567_89ab_cdef);
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let input_key: LweSecretKey32 =
+    ///     engine.derive_lwe_secret_key(master_seed, KeyDerivationLabel(0), input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 =
+    ///     engine.derive_lwe_secret_key(master_seed, KeyDerivationLabel(1), output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key: LweKeyswitchKey32 = engine.derive_lwe_keyswitch_key(
+    ///     master_seed,
+    ///     KeyDerivationLabel(2),
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let same_keyswitch_key: LweKeyswitchKey32 = engine.derive_lwe_keyswitch_key(
+    ///     master_seed,
+    ///     KeyDerivationLabel(2),
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(keyswitch_key, same_keyswitch_key);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(same_keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn derive_lwe_keyswitch_key(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        input_key: &LweSecretKey32,
+        output_key: &LweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyDerivationError<Self::EngineError>> {
+        let decomposition = LweKeyswitchKeyDerivationError::perform_generic_checks::<u32>(
+            decomposition_level_count,
+            decomposition_base_log,
+        )?;
+        Ok(unsafe {
+            self.derive_lwe_keyswitch_key_unchecked(
+                master_seed,
+                label,
+                input_key,
+                output_key,
+                decomposition.level_count(),
+                decomposition.base_log(),
+                noise,
+            )
+        })
+    }
+
+    unsafe fn derive_lwe_keyswitch_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        input_key: &LweSecretKey32,
+        output_key: &LweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey32 {
+        let mut encryption_generator = KeyDerivationGenerator::new(master_seed.0)
+            .derive_encryption_generator(DerivedKeyKind::LweKeyswitchKey, label.0);
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut encryption_generator,
+        );
+        LweKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyDerivationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweKeyswitchKeyDerivationEngine<LweSecretKey64, LweSecretKey64, LweKeyswitchKey64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, KeyDerivationLabel, LweDimension,
+    ///     MasterSeed,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let master_seed = MasterSeed(0x0123_4567_89ab_cdef);
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let input_key: LweSecretKey64 =
+    ///     engine.derive_lwe_secret_key(master_seed, KeyDerivationLabel(0), input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 =
+    ///     engine.derive_lwe_secret_key(master_seed, KeyDerivationLabel(1), output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key: LweKeyswitchKey64 = engine.derive_lwe_keyswitch_key(
+    ///     master_seed,
+    ///     KeyDerivationLabel(2),
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let same_keyswitch_key: LweKeyswitchKey64 = engine.derive_lwe_keyswitch_key(
+    ///     master_seed,
+    ///     KeyDerivationLabel(2),
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(keyswitch_key, same_keyswitch_key);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(same_keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn derive_lwe_keyswitch_key(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        input_key: &LweSecretKey64,
+        output_key: &LweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyDerivationError<Self::EngineError>> {
+        let decomposition = LweKeyswitchKeyDerivationError::perform_generic_checks::<u64>(
+            decomposition_level_count,
+            decomposition_base_log,
+        )?;
+        Ok(unsafe {
+            self.derive_lwe_keyswitch_key_unchecked(
+                master_seed,
+                label,
+                input_key,
+                output_key,
+                decomposition.level_count(),
+                decomposition.base_log(),
+                noise,
+            )
+        })
+    }
+
+    unsafe fn derive_lwe_keyswitch_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        input_key: &LweSecretKey64,
+        output_key: &LweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey64 {
+        let mut encryption_generator = KeyDerivationGenerator::new(master_seed.0)
+            .derive_encryption_generator(DerivedKeyKind::LweKeyswitchKey, label.0);
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut encryption_generator,
+        );
+        LweKeyswitchKey64(ksk)
+    }
+}
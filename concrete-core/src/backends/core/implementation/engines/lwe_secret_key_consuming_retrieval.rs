@@ -0,0 +1,82 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::core::private::math::tensor::AsMutTensor;
+use crate::specification::engines::{
+    LweSecretKeyConsumingRetrievalEngine, LweSecretKeyConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`LweSecretKeyConsumingRetrievalEngine`] for [`CoreEngine`] that returns the
+/// underlying vec of an [`LweSecretKey32`] consuming it in the process
+impl LweSecretKeyConsumingRetrievalEngine<LweSecretKey32, Vec<u32>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let raw_key = vec![0_u32, 1, 1, 0, 1, 0];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let lwe_secret_key: LweSecretKey32 =
+    ///     engine.create_lwe_secret_key_from_container(raw_key.clone())?;
+    /// let retrieved_key = engine.consume_retrieve_lwe_secret_key(lwe_secret_key)?;
+    /// assert_eq!(raw_key, retrieved_key);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_secret_key(
+        &mut self,
+        secret_key: LweSecretKey32,
+    ) -> Result<Vec<u32>, LweSecretKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_secret_key_unchecked(secret_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_secret_key_unchecked(
+        &mut self,
+        mut secret_key: LweSecretKey32,
+    ) -> Vec<u32> {
+        // The container is moved out, and the emptied key is dropped.
+        std::mem::take(secret_key.0.as_mut_tensor().as_mut_container())
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyConsumingRetrievalEngine`] for [`CoreEngine`] that returns the
+/// underlying vec of an [`LweSecretKey64`] consuming it in the process
+impl LweSecretKeyConsumingRetrievalEngine<LweSecretKey64, Vec<u64>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let raw_key = vec![0_u64, 1, 1, 0, 1, 0];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let lwe_secret_key: LweSecretKey64 =
+    ///     engine.create_lwe_secret_key_from_container(raw_key.clone())?;
+    /// let retrieved_key = engine.consume_retrieve_lwe_secret_key(lwe_secret_key)?;
+    /// assert_eq!(raw_key, retrieved_key);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_secret_key(
+        &mut self,
+        secret_key: LweSecretKey64,
+    ) -> Result<Vec<u64>, LweSecretKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_secret_key_unchecked(secret_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_secret_key_unchecked(
+        &mut self,
+        mut secret_key: LweSecretKey64,
+    ) -> Vec<u64> {
+        // The container is moved out, and the emptied key is dropped.
+        std::mem::take(secret_key.0.as_mut_tensor().as_mut_container())
+    }
+}
@@ -0,0 +1,117 @@
+use concrete_commons::parameters::{KeyDerivationLabel, LweDimension, MasterSeed};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::core::private::crypto::secret::generators::{
+    DerivedKeyKind, KeyDerivationGenerator,
+};
+use crate::backends::core::private::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::specification::engines::{LweSecretKeyDerivationEngine, LweSecretKeyDerivationError};
+
+/// # Description:
+/// Implementation of [`LweSecretKeyDerivationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweSecretKeyDerivationEngine<LweSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{KeyDerivationLabel, LweDimension, MasterSeed};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let master_seed = MasterSeed(0x0123_4567_89ab_cdef);
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let lwe_secret_key: LweSecretKey32 =
+    ///     engine.derive_lwe_secret_key(master_seed, KeyDerivationLabel(0), lwe_dimension)?;
+    /// let same_lwe_secret_key: LweSecretKey32 =
+    ///     engine.derive_lwe_secret_key(master_seed, KeyDerivationLabel(0), lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(lwe_secret_key, same_lwe_secret_key);
+    /// engine.destroy(lwe_secret_key)?;
+    /// engine.destroy(same_lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn derive_lwe_secret_key(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweSecretKey32, LweSecretKeyDerivationError<Self::EngineError>> {
+        LweSecretKeyDerivationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.derive_lwe_secret_key_unchecked(master_seed, label, lwe_dimension) })
+    }
+
+    unsafe fn derive_lwe_secret_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        lwe_dimension: LweDimension,
+    ) -> LweSecretKey32 {
+        let mut secret_generator = KeyDerivationGenerator::new(master_seed.0)
+            .derive_secret_generator(DerivedKeyKind::LweSecretKey, label.0);
+        LweSecretKey32(ImplLweSecretKey::generate_binary(
+            lwe_dimension,
+            &mut secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyDerivationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweSecretKeyDerivationEngine<LweSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{KeyDerivationLabel, LweDimension, MasterSeed};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let master_seed = MasterSeed(0x0123_4567_89ab_cdef);
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let lwe_secret_key: LweSecretKey64 =
+    ///     engine.derive_lwe_secret_key(master_seed, KeyDerivationLabel(0), lwe_dimension)?;
+    /// let same_lwe_secret_key: LweSecretKey64 =
+    ///     engine.derive_lwe_secret_key(master_seed, KeyDerivationLabel(0), lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(lwe_secret_key, same_lwe_secret_key);
+    /// engine.destroy(lwe_secret_key)?;
+    /// engine.destroy(same_lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn derive_lwe_secret_key(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweSecretKey64, LweSecretKeyDerivationError<Self::EngineError>> {
+        LweSecretKeyDerivationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.derive_lwe_secret_key_unchecked(master_seed, label, lwe_dimension) })
+    }
+
+    unsafe fn derive_lwe_secret_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        lwe_dimension: LweDimension,
+    ) -> LweSecretKey64 {
+        let mut secret_generator = KeyDerivationGenerator::new(master_seed.0)
+            .derive_secret_generator(DerivedKeyKind::LweSecretKey, label.0);
+        LweSecretKey64(ImplLweSecretKey::generate_binary(
+            lwe_dimension,
+            &mut secret_generator,
+        ))
+    }
+}
@@ -0,0 +1,112 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::core::private::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::specification::engines::{
+    LweSecretKeyFromContainerCreationEngine, LweSecretKeyFromContainerCreationError,
+};
+
+/// # Description:
+/// Implementation of [`LweSecretKeyFromContainerCreationEngine`] for [`CoreEngine`] which returns
+/// an [`LweSecretKey32`].
+impl LweSecretKeyFromContainerCreationEngine<Vec<u32>, LweSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here the key is created outside of the engine, its values must be binary
+    /// let raw_key = vec![0_u32, 1, 1, 0, 1, 0];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let lwe_secret_key: LweSecretKey32 =
+    ///     engine.create_lwe_secret_key_from_container(raw_key)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    ///
+    /// // Values which do not belong to the binary distribution are rejected
+    /// let invalid_key = vec![0_u32, 2, 1, 0, 1, 0];
+    /// let result: Result<LweSecretKey32, _> =
+    ///     engine.create_lwe_secret_key_from_container(invalid_key);
+    /// assert!(result.is_err());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key_from_container(
+        &mut self,
+        container: Vec<u32>,
+    ) -> Result<LweSecretKey32, LweSecretKeyFromContainerCreationError<Self::EngineError>> {
+        LweSecretKeyFromContainerCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+        )?;
+        LweSecretKeyFromContainerCreationError::<Self::EngineError>::perform_binary_key_checks(
+            container.as_slice(),
+        )?;
+        Ok(unsafe { self.create_lwe_secret_key_from_container_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_secret_key_from_container_unchecked(
+        &mut self,
+        container: Vec<u32>,
+    ) -> LweSecretKey32 {
+        LweSecretKey32(ImplLweSecretKey::binary_from_container(container))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyFromContainerCreationEngine`] for [`CoreEngine`] which returns
+/// an [`LweSecretKey64`].
+impl LweSecretKeyFromContainerCreationEngine<Vec<u64>, LweSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here the key is created outside of the engine, its values must be binary
+    /// let raw_key = vec![0_u64, 1, 1, 0, 1, 0];
+    ///
+    /// let mut engine = CoreEngine::new(())?;
+    /// let lwe_secret_key: LweSecretKey64 =
+    ///     engine.create_lwe_secret_key_from_container(raw_key)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    ///
+    /// // Values which do not belong to the binary distribution are rejected
+    /// let invalid_key = vec![0_u64, 2, 1, 0, 1, 0];
+    /// let result: Result<LweSecretKey64, _> =
+    ///     engine.create_lwe_secret_key_from_container(invalid_key);
+    /// assert!(result.is_err());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key_from_container(
+        &mut self,
+        container: Vec<u64>,
+    ) -> Result<LweSecretKey64, LweSecretKeyFromContainerCreationError<Self::EngineError>> {
+        LweSecretKeyFromContainerCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+        )?;
+        LweSecretKeyFromContainerCreationError::<Self::EngineError>::perform_binary_key_checks(
+            container.as_slice(),
+        )?;
+        Ok(unsafe { self.create_lwe_secret_key_from_container_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_secret_key_from_container_unchecked(
+        &mut self,
+        container: Vec<u64>,
+    ) -> LweSecretKey64 {
+        LweSecretKey64(ImplLweSecretKey::binary_from_container(container))
+    }
+}
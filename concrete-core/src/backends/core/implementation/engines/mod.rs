@@ -127,20 +127,28 @@ mod glwe_ciphertext_discarding_encryption;
 mod glwe_ciphertext_encryption;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_noise_measurement;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
+mod glwe_ciphertext_vector_consuming_retrieval;
+mod glwe_ciphertext_vector_creation;
 mod glwe_ciphertext_vector_decryption;
 mod glwe_ciphertext_vector_discarding_decryption;
 mod glwe_ciphertext_vector_discarding_encryption;
 mod glwe_ciphertext_vector_encryption;
+mod glwe_ciphertext_vector_noise_measurement;
 mod glwe_ciphertext_vector_trivial_decryption;
 mod glwe_ciphertext_vector_trivial_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
+mod glwe_secret_key_consuming_retrieval;
 mod glwe_secret_key_creation;
+mod glwe_secret_key_derivation;
+mod glwe_secret_key_from_container_creation;
 mod glwe_secret_key_to_lwe_secret_key_transmutation;
 mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_creation;
+mod lwe_bootstrap_key_derivation;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
 mod lwe_ciphertext_consuming_retrieval;
@@ -158,6 +166,7 @@ mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_opposite;
 mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_noise_measurement;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
@@ -174,12 +183,17 @@ mod lwe_ciphertext_vector_encryption;
 mod lwe_ciphertext_vector_fusing_addition;
 mod lwe_ciphertext_vector_fusing_subtraction;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
+mod lwe_ciphertext_vector_noise_measurement;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
 mod lwe_keyswitch_key_creation;
+mod lwe_keyswitch_key_derivation;
+mod lwe_secret_key_consuming_retrieval;
 mod lwe_secret_key_creation;
+mod lwe_secret_key_derivation;
+mod lwe_secret_key_from_container_creation;
 mod packing_keyswitch_key_creation;
 mod plaintext_creation;
 mod plaintext_discarding_retrieval;
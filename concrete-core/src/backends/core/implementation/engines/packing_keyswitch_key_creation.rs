@@ -72,17 +72,16 @@ impl PackingKeyswitchKeyCreationEngine<LweSecretKey32, GlweSecretKey32, PackingK
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
     ) -> Result<PackingKeyswitchKey32, PackingKeyswitchKeyCreationError<Self::EngineError>> {
-        PackingKeyswitchKeyCreationError::perform_generic_checks(
+        let decomposition = PackingKeyswitchKeyCreationError::perform_generic_checks::<u32>(
             decomposition_level_count,
             decomposition_base_log,
-            32,
         )?;
         Ok(unsafe {
             self.create_packing_keyswitch_key_unchecked(
                 input_key,
                 output_key,
-                decomposition_level_count,
-                decomposition_base_log,
+                decomposition.level_count(),
+                decomposition.base_log(),
                 noise,
             )
         })
@@ -175,17 +174,16 @@ impl PackingKeyswitchKeyCreationEngine<LweSecretKey64, GlweSecretKey64, PackingK
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
     ) -> Result<PackingKeyswitchKey64, PackingKeyswitchKeyCreationError<Self::EngineError>> {
-        PackingKeyswitchKeyCreationError::perform_generic_checks(
+        let decomposition = PackingKeyswitchKeyCreationError::perform_generic_checks::<u64>(
             decomposition_level_count,
             decomposition_base_log,
-            64,
         )?;
         Ok(unsafe {
             self.create_packing_keyswitch_key_unchecked(
                 input_key,
                 output_key,
-                decomposition_level_count,
-                decomposition_base_log,
+                decomposition.level_count(),
+                decomposition.base_log(),
                 noise,
             )
         })
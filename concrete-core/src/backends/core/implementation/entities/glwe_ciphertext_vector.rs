@@ -50,3 +50,126 @@ impl GlweCiphertextVectorEntity for GlweCiphertextVector64 {
         GlweCiphertextCount(self.0.ciphertext_count().0)
     }
 }
+
+// GlweCiphertextVectorViews are just GlweCiphertextVector entities that do not own their memory,
+// they use a slice as a container as opposed to Vec for the standard GlweCiphertextVector
+
+/// A structure representing a vector of GLWE ciphertext views, with 32 bits of precision.
+///
+/// By _view_ here, we mean that the entity does not own the data, but immutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Immutable variant.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize))]
+#[derive(Debug, PartialEq)]
+pub struct GlweCiphertextVectorView32<'a>(pub(crate) ImplGlweList<&'a [u32]>);
+impl AbstractEntity for GlweCiphertextVectorView32<'_> {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for GlweCiphertextVectorView32<'_> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertext views, with 32 bits of precision.
+///
+/// By _view_ here, we mean that the entity does not own the data, but mutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Mutable variant.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize))]
+#[derive(Debug, PartialEq)]
+pub struct GlweCiphertextVectorMutView32<'a>(pub(crate) ImplGlweList<&'a mut [u32]>);
+impl AbstractEntity for GlweCiphertextVectorMutView32<'_> {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for GlweCiphertextVectorMutView32<'_> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertext views, with 64 bits of precision.
+///
+/// By _view_ here, we mean that the entity does not own the data, but immutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Immutable variant.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize))]
+#[derive(Debug, PartialEq)]
+pub struct GlweCiphertextVectorView64<'a>(pub(crate) ImplGlweList<&'a [u64]>);
+impl AbstractEntity for GlweCiphertextVectorView64<'_> {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for GlweCiphertextVectorView64<'_> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertext views, with 64 bits of precision.
+///
+/// By _view_ here, we mean that the entity does not own the data, but mutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Mutable variant.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize))]
+#[derive(Debug, PartialEq)]
+pub struct GlweCiphertextVectorMutView64<'a>(pub(crate) ImplGlweList<&'a mut [u64]>);
+impl AbstractEntity for GlweCiphertextVectorMutView64<'_> {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for GlweCiphertextVectorMutView64<'_> {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
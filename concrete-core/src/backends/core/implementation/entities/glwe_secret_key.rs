@@ -1,4 +1,6 @@
 use crate::backends::core::private::crypto::secret::GlweSecretKey as ImpGlweSecretKey;
+use crate::backends::core::private::math::tensor::{AsMutSlice, AsMutTensor};
+use crate::backends::core::private::utils::zeroize;
 use crate::specification::entities::markers::{BinaryKeyDistribution, GlweSecretKeyKind};
 use crate::specification::entities::{AbstractEntity, GlweSecretKeyEntity};
 use concrete_commons::key_kinds::BinaryKeyKind;
@@ -25,6 +27,13 @@ impl GlweSecretKeyEntity for GlweSecretKey32 {
     }
 }
 
+// The key is wiped before its memory is released.
+impl Drop for GlweSecretKey32 {
+    fn drop(&mut self) {
+        zeroize(self.0.as_mut_tensor().as_mut_slice());
+    }
+}
+
 /// A structure representing a GLWE secret key with 64 bits of precision.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
@@ -43,3 +52,10 @@ impl GlweSecretKeyEntity for GlweSecretKey64 {
         self.0.polynomial_size()
     }
 }
+
+// The key is wiped before its memory is released.
+impl Drop for GlweSecretKey64 {
+    fn drop(&mut self) {
+        zeroize(self.0.as_mut_tensor().as_mut_slice());
+    }
+}
@@ -1,4 +1,6 @@
 use crate::backends::core::private::crypto::secret::LweSecretKey as ImpLweSecretKey;
+use crate::backends::core::private::math::tensor::{AsMutSlice, AsMutTensor};
+use crate::backends::core::private::utils::zeroize;
 use crate::specification::entities::markers::{BinaryKeyDistribution, LweSecretKeyKind};
 use crate::specification::entities::{AbstractEntity, LweSecretKeyEntity};
 use concrete_commons::key_kinds::BinaryKeyKind;
@@ -21,6 +23,13 @@ impl LweSecretKeyEntity for LweSecretKey32 {
     }
 }
 
+// The key is wiped before its memory is released.
+impl Drop for LweSecretKey32 {
+    fn drop(&mut self) {
+        zeroize(self.0.as_mut_tensor().as_mut_slice());
+    }
+}
+
 /// A structure representing an LWE secret key with 64 bits of precision.
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
@@ -35,3 +44,10 @@ impl LweSecretKeyEntity for LweSecretKey64 {
         self.0.key_size()
     }
 }
+
+// The key is wiped before its memory is released.
+impl Drop for LweSecretKey64 {
+    fn drop(&mut self) {
+        zeroize(self.0.as_mut_tensor().as_mut_slice());
+    }
+}
@@ -2,6 +2,7 @@ use crate::backends::core::private::crypto::glwe::GlweCiphertext;
 use crate::backends::core::private::math::fft::{Complex64, Fft, FourierPolynomial};
 use crate::backends::core::private::math::tensor::Tensor;
 use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::backends::core::private::utils::zeroize_bytes;
 use concrete_commons::parameters::{GlweSize, PolynomialSize};
 use concrete_fftw::array::AlignedVec;
 
@@ -53,3 +54,17 @@ where
         }
     }
 }
+
+// The lut and the rounded input of a bootstrap are wiped before their memory is released.
+impl<Scalar> Drop for FourierBuffers<Scalar> {
+    fn drop(&mut self) {
+        if std::mem::needs_drop::<Scalar>() {
+            return;
+        }
+        // Safety: the elements do not need to be dropped, and are never read again.
+        unsafe {
+            zeroize_bytes(self.lut_buffer.tensor.as_mut_container().as_mut_slice());
+            zeroize_bytes(self.rounded_buffer.tensor.as_mut_container().as_mut_slice());
+        }
+    }
+}
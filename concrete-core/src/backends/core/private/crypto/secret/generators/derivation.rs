@@ -0,0 +1,198 @@
+use crate::backends::core::private::crypto::secret::generators::{
+    EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use crate::backends::core::private::math::random::RandomGenerator;
+
+// The number of seeds derived for every key: one for the secret generator, and two for the mask
+// and noise generators of the encryption generator.
+const SEEDS_PER_KEY: usize = 3;
+
+/// The kind of a derived key.
+///
+/// The kind is part of the input of the derivation, which makes keys of different kinds
+/// independent, even when they share the same label.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DerivedKeyKind {
+    LweSecretKey = 0,
+    GlweSecretKey = 1,
+    LweKeyswitchKey = 2,
+    LweBootstrapKey = 3,
+}
+
+/// A generator deriving independent random generators from a master seed, a key kind and a label.
+///
+/// The seeds of the derived generators are the outputs of a pseudo-random function keyed by the
+/// master seed, evaluated on the kind and the label of the key. This function follows the
+/// Goldreich-Goldwasser-Micali construction on top of the csprng: starting from the master seed,
+/// every bit of the input selects the first or the second half of the 32 bytes generated from the
+/// current seed, which becomes the next seed. As a consequence:
+///
+/// + Two derivations using the same master seed, kind and label output the same generators.
+/// + Two derivations using the same master seed, and a different kind or a different label
+///   output independent generators.
+/// + The cost of a derivation does not depend on the value of the label.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_core::backends::core::private::crypto::secret::generators::{
+///     DerivedKeyKind, KeyDerivationGenerator,
+/// };
+/// use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+/// let derivation_generator = KeyDerivationGenerator::new(42);
+/// let mut first_generator =
+///     derivation_generator.derive_secret_generator(DerivedKeyKind::LweSecretKey, 3);
+/// let mut second_generator =
+///     derivation_generator.derive_secret_generator(DerivedKeyKind::LweSecretKey, 3);
+/// let first_key: LweSecretKey<_, Vec<u64>> =
+///     LweSecretKey::generate_binary(LweDimension(256), &mut first_generator);
+/// let second_key: LweSecretKey<_, Vec<u64>> =
+///     LweSecretKey::generate_binary(LweDimension(256), &mut second_generator);
+/// assert_eq!(first_key, second_key);
+/// ```
+pub struct KeyDerivationGenerator {
+    master_seed: u128,
+}
+
+impl KeyDerivationGenerator {
+    /// Creates a new derivation generator from a master seed.
+    pub fn new(master_seed: u128) -> KeyDerivationGenerator {
+        KeyDerivationGenerator { master_seed }
+    }
+
+    /// Derives the secret random generator associated with the key of kind `kind` and label
+    /// `label`.
+    pub fn derive_secret_generator(
+        &self,
+        kind: DerivedKeyKind,
+        label: usize,
+    ) -> SecretRandomGenerator {
+        let [secret_seed, _, _] = self.derive_seeds(kind, label);
+        SecretRandomGenerator::new(Some(secret_seed))
+    }
+
+    /// Derives the encryption random generator associated with the key of kind `kind` and label
+    /// `label`.
+    ///
+    /// Both the mask and the noise of the derived generator are determined by the master seed.
+    pub fn derive_encryption_generator(
+        &self,
+        kind: DerivedKeyKind,
+        label: usize,
+    ) -> EncryptionRandomGenerator {
+        let [_, mask_seed, noise_seed] = self.derive_seeds(kind, label);
+        EncryptionRandomGenerator::new_deterministic(mask_seed, noise_seed)
+    }
+
+    fn derive_seeds(&self, kind: DerivedKeyKind, label: usize) -> [u128; SEEDS_PER_KEY] {
+        let mut input = [0u8; 9];
+        input[0] = kind as u8;
+        input[1..].copy_from_slice(&(label as u64).to_le_bytes());
+        let mut seed = self.master_seed;
+        for byte in input.iter() {
+            for bit in 0..8 {
+                let mut generator = RandomGenerator::new(Some(seed));
+                let (left, right): (u128, u128) =
+                    (generator.random_uniform(), generator.random_uniform());
+                seed = if (byte >> bit) & 1 == 0 { left } else { right };
+            }
+        }
+        let mut generator = RandomGenerator::new(Some(seed));
+        [
+            generator.random_uniform(),
+            generator.random_uniform(),
+            generator.random_uniform(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::backends::core::private::crypto::secret::generators::{
+        DerivedKeyKind, KeyDerivationGenerator,
+    };
+    use crate::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+    use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor, Tensor};
+    use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+
+    #[test]
+    fn test_derivation_is_deterministic() {
+        let derivation_generator = KeyDerivationGenerator::new(0x0123_4567_89ab_cdef);
+        for label in 0..10 {
+            let mut first =
+                derivation_generator.derive_secret_generator(DerivedKeyKind::LweSecretKey, label);
+            let mut second =
+                derivation_generator.derive_secret_generator(DerivedKeyKind::LweSecretKey, label);
+            assert_eq!(
+                first.random_uniform_tensor::<u64>(16),
+                second.random_uniform_tensor::<u64>(16)
+            );
+        }
+    }
+
+    #[test]
+    fn test_derivation_labels_are_independent() {
+        let derivation_generator = KeyDerivationGenerator::new(0x0123_4567_89ab_cdef);
+        let mut first =
+            derivation_generator.derive_secret_generator(DerivedKeyKind::LweSecretKey, 0);
+        let mut second =
+            derivation_generator.derive_secret_generator(DerivedKeyKind::LweSecretKey, 1);
+        assert_ne!(
+            first.random_uniform_tensor::<u64>(16),
+            second.random_uniform_tensor::<u64>(16)
+        );
+    }
+
+    #[test]
+    fn test_derivation_kinds_are_independent() {
+        let derivation_generator = KeyDerivationGenerator::new(0x0123_4567_89ab_cdef);
+        let mut lwe_generator =
+            derivation_generator.derive_secret_generator(DerivedKeyKind::LweSecretKey, 0);
+        let mut glwe_generator =
+            derivation_generator.derive_secret_generator(DerivedKeyKind::GlweSecretKey, 0);
+        let lwe_key: LweSecretKey<_, Vec<u64>> =
+            LweSecretKey::generate_binary(LweDimension(1024), &mut lwe_generator);
+        let glwe_key: GlweSecretKey<_, Vec<u64>> = GlweSecretKey::generate_binary(
+            GlweDimension(1),
+            PolynomialSize(1024),
+            &mut glwe_generator,
+        );
+        let lwe_key = lwe_key.as_tensor().as_slice();
+        let glwe_key = glwe_key.as_tensor().as_slice();
+        // The keys are binary: independent keys match on about half of their coefficients.
+        let matching = lwe_key
+            .iter()
+            .zip(glwe_key.iter())
+            .filter(|(a, b)| a == b)
+            .count();
+        assert!(
+            matching > 384 && matching < 640,
+            "{} matching coefficients",
+            matching
+        );
+
+        let mut ksk_generator =
+            derivation_generator.derive_encryption_generator(DerivedKeyKind::LweKeyswitchKey, 0);
+        let mut bsk_generator =
+            derivation_generator.derive_encryption_generator(DerivedKeyKind::LweBootstrapKey, 0);
+        let mut ksk_mask = Tensor::allocate(0u64, 16);
+        let mut bsk_mask = Tensor::allocate(0u64, 16);
+        ksk_generator.fill_tensor_with_random_mask(&mut ksk_mask);
+        bsk_generator.fill_tensor_with_random_mask(&mut bsk_mask);
+        assert_ne!(ksk_mask, bsk_mask);
+    }
+
+    #[test]
+    fn test_derivation_accepts_any_label() {
+        let derivation_generator = KeyDerivationGenerator::new(0x0123_4567_89ab_cdef);
+        let mut first =
+            derivation_generator.derive_secret_generator(DerivedKeyKind::LweSecretKey, usize::MAX);
+        let mut second =
+            derivation_generator.derive_secret_generator(DerivedKeyKind::LweSecretKey, usize::MAX);
+        assert_eq!(
+            first.random_uniform_tensor::<u64>(16),
+            second.random_uniform_tensor::<u64>(16)
+        );
+    }
+}
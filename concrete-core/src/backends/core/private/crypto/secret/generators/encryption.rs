@@ -28,6 +28,18 @@ impl EncryptionRandomGenerator {
         }
     }
 
+    /// Creates a new encryption generator, seeding both the mask and the noise generators.
+    ///
+    /// Contrary to [`EncryptionRandomGenerator::new`], the noise sampled by this generator is
+    /// fully determined by `noise_seed`. This is only meant to be used with seeds derived from a
+    /// secret master seed.
+    pub fn new_deterministic(mask_seed: u128, noise_seed: u128) -> EncryptionRandomGenerator {
+        EncryptionRandomGenerator {
+            mask: RandomGenerator::new(Some(mask_seed)),
+            noise: RandomGenerator::new(Some(noise_seed)),
+        }
+    }
+
     // Allows to seed the noise generator. For testing purpose only.
     #[allow(dead_code)]
     pub(crate) fn seed_noise_generator(&mut self, seed: u128) {
@@ -1,3 +1,6 @@
+mod derivation;
+pub use derivation::{DerivedKeyKind, KeyDerivationGenerator};
+
 mod encryption;
 pub use encryption::EncryptionRandomGenerator;
 
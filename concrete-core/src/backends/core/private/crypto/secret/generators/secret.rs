@@ -1,15 +1,19 @@
 use crate::backends::core::private::math::random::{Gaussian, RandomGenerable, RandomGenerator};
 use crate::backends::core::private::math::tensor::Tensor;
 use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::backends::core::private::utils::zeroize_bytes;
 use concrete_commons::dispersion::DispersionParameter;
+use std::mem::ManuallyDrop;
 
 /// A random number generator which can be used to generate secret keys.
-pub struct SecretRandomGenerator(RandomGenerator);
+///
+/// The state of the generator is wiped when it is dropped.
+pub struct SecretRandomGenerator(ManuallyDrop<RandomGenerator>);
 
 impl SecretRandomGenerator {
     /// Creates a new generator, optionally seeding it with the given value.
     pub fn new(seed: Option<u128>) -> SecretRandomGenerator {
-        SecretRandomGenerator(RandomGenerator::new(seed))
+        SecretRandomGenerator(ManuallyDrop::new(RandomGenerator::new(seed)))
     }
 
     /// Returns the number of remaining bytes, if the generator is bounded.
@@ -56,3 +60,14 @@ impl SecretRandomGenerator {
             .random_gaussian_tensor(length, 0.0, Scalar::GAUSSIAN_KEY_LOG_STD.get_standard_dev())
     }
 }
+
+impl Drop for SecretRandomGenerator {
+    fn drop(&mut self) {
+        // The inner generator is dropped first, then its inline state is overwritten. The wiped
+        // storage is never read again.
+        unsafe {
+            ManuallyDrop::drop(&mut self.0);
+            zeroize_bytes(std::slice::from_mut(&mut self.0));
+        }
+    }
+}
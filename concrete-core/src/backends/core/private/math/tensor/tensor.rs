@@ -1431,11 +1431,10 @@ impl<Container> Tensor<Container> {
 
 impl<Element> FromIterator<Element> for Tensor<Vec<Element>> {
     fn from_iter<I: IntoIterator<Item = Element>>(iter: I) -> Self {
-        let mut v = Vec::new();
-        for i in iter {
-            v.push(i);
-        }
-        Tensor(v)
+        // Collecting the vector directly uses the size hint of the iterator, so that tensors built
+        // from exact-size iterators (e.g. secret keys) are allocated once, and no reallocated copy
+        // of their content is freed without being wiped.
+        Tensor(iter.into_iter().collect())
     }
 }
 
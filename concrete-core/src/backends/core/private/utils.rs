@@ -49,6 +49,39 @@ macro_rules! zip_args {
 }
 pub(crate) use zip_args;
 
+/// Overwrites the elements of a slice with their default value, using volatile writes.
+///
+/// Contrary to a plain `fill`, volatile writes can not be elided by the compiler when the slice is
+/// freed right after. This is used to wipe secret material before its memory is released.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::backends::core::private::utils::zeroize;
+/// let mut secret = vec![1u32, 0, 1, 1];
+/// zeroize(secret.as_mut_slice());
+/// assert_eq!(secret, vec![0u32; 4]);
+/// ```
+pub fn zeroize<Element: Copy + Default>(slice: &mut [Element]) {
+    for element in slice.iter_mut() {
+        // Safety: `element` is a valid, aligned, mutable reference.
+        unsafe { std::ptr::write_volatile(element, Element::default()) };
+    }
+    std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Overwrites the memory of a slice with zero bytes, using volatile writes.
+///
+/// # Safety
+///
+/// The all-zero bit pattern may not be a valid value for `Element`. The elements of the slice must
+/// not be read or dropped after the call.
+pub(crate) unsafe fn zeroize_bytes<Element>(slice: &mut [Element]) {
+    let bytes =
+        std::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut u8, std::mem::size_of_val(slice));
+    zeroize(bytes);
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::many_single_char_names)]
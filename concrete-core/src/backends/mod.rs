@@ -1,9 +1,14 @@
 //! A module containing various backends implementing the `concrete` FHE scheme.
 //!
 //! This module contains all the backends implementing the concrete specification. As of now we
-//! support the following backend:
+//! support the following backends:
 //!
 //! + `core` : A single threaded CPU backend geared towards x86_64 architectures.
+//! + `noise_tracking` : A backend wrapping the `core` one, which estimates the noise of every
+//! ciphertext it produces.
 
 #[cfg(feature = "backend_core")]
 pub mod core;
+
+#[cfg(feature = "backend_noise_tracking")]
+pub mod noise_tracking;
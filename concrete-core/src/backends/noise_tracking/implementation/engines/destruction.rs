@@ -0,0 +1,205 @@
+use crate::backends::noise_tracking::implementation::engines::NoiseTrackingEngine;
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyFourierGgswCiphertext32, NoisyFourierGgswCiphertext64, NoisyFourierLweBootstrapKey32,
+    NoisyFourierLweBootstrapKey64, NoisyGlweCiphertext32, NoisyGlweCiphertext64,
+    NoisyGlweCiphertextVector32, NoisyGlweCiphertextVector64, NoisyLweCiphertext32,
+    NoisyLweCiphertext64, NoisyLweCiphertextVector32, NoisyLweCiphertextVector64,
+    NoisyLweKeyswitchKey32, NoisyLweKeyswitchKey64,
+};
+use crate::specification::engines::{DestructionEngine, DestructionError};
+
+impl DestructionEngine<NoisyLweCiphertext32> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyLweCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyLweCiphertext32) {
+        self.core_engine.destroy_unchecked(&mut entity.ciphertext);
+    }
+}
+
+impl DestructionEngine<NoisyLweCiphertext64> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyLweCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyLweCiphertext64) {
+        self.core_engine.destroy_unchecked(&mut entity.ciphertext);
+    }
+}
+
+impl DestructionEngine<NoisyLweCiphertextVector32> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyLweCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyLweCiphertextVector32) {
+        self.core_engine.destroy_unchecked(&mut entity.ciphertexts);
+    }
+}
+
+impl DestructionEngine<NoisyLweCiphertextVector64> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyLweCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyLweCiphertextVector64) {
+        self.core_engine.destroy_unchecked(&mut entity.ciphertexts);
+    }
+}
+
+impl DestructionEngine<NoisyGlweCiphertext32> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyGlweCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyGlweCiphertext32) {
+        self.core_engine.destroy_unchecked(&mut entity.ciphertext);
+    }
+}
+
+impl DestructionEngine<NoisyGlweCiphertext64> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyGlweCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyGlweCiphertext64) {
+        self.core_engine.destroy_unchecked(&mut entity.ciphertext);
+    }
+}
+
+impl DestructionEngine<NoisyFourierGgswCiphertext32> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyFourierGgswCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyFourierGgswCiphertext32) {
+        self.core_engine.destroy_unchecked(&mut entity.ciphertext);
+    }
+}
+
+impl DestructionEngine<NoisyFourierGgswCiphertext64> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyFourierGgswCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyFourierGgswCiphertext64) {
+        self.core_engine.destroy_unchecked(&mut entity.ciphertext);
+    }
+}
+
+impl DestructionEngine<NoisyFourierLweBootstrapKey32> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyFourierLweBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyFourierLweBootstrapKey32) {
+        self.core_engine.destroy_unchecked(&mut entity.key);
+    }
+}
+
+impl DestructionEngine<NoisyFourierLweBootstrapKey64> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyFourierLweBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyFourierLweBootstrapKey64) {
+        self.core_engine.destroy_unchecked(&mut entity.key);
+    }
+}
+
+impl DestructionEngine<NoisyLweKeyswitchKey32> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyLweKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyLweKeyswitchKey32) {
+        self.core_engine.destroy_unchecked(&mut entity.key);
+    }
+}
+
+impl DestructionEngine<NoisyLweKeyswitchKey64> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyLweKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyLweKeyswitchKey64) {
+        self.core_engine.destroy_unchecked(&mut entity.key);
+    }
+}
+
+impl DestructionEngine<NoisyGlweCiphertextVector32> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyGlweCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyGlweCiphertextVector32) {
+        self.core_engine.destroy_unchecked(&mut entity.ciphertexts);
+    }
+}
+
+impl DestructionEngine<NoisyGlweCiphertextVector64> for NoiseTrackingEngine {
+    fn destroy(
+        &mut self,
+        mut entity: NoisyGlweCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(&mut entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: &mut NoisyGlweCiphertextVector64) {
+        self.core_engine.destroy_unchecked(&mut entity.ciphertexts);
+    }
+}
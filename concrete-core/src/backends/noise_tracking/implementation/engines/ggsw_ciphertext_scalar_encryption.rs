@@ -0,0 +1,233 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::core::engines::CoreError;
+use crate::backends::core::entities::{GlweSecretKey32, GlweSecretKey64, Plaintext32, Plaintext64};
+use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyFourierGgswCiphertext32, NoisyFourierGgswCiphertext64,
+};
+use crate::specification::engines::{
+    GgswCiphertextConversionEngine, GgswCiphertextScalarEncryptionEngine,
+    GgswCiphertextScalarEncryptionError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GgswCiphertextScalarEncryptionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 32 bits integers. It outputs a GGSW ciphertext in the Fourier domain.
+impl
+    GgswCiphertextScalarEncryptionEngine<GlweSecretKey32, Plaintext32, NoisyFourierGgswCiphertext32>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey32 =
+    ///     core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext: NoisyFourierGgswCiphertext32 =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.variance(), noise);
+    /// assert_eq!(ciphertext.message_squared(), (input as f64).powi(2));
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<NoisyFourierGgswCiphertext32, GgswCiphertextScalarEncryptionError<Self::EngineError>>
+    {
+        if !ALLOWED_POLY_SIZE.contains(&key.polynomial_size().0) {
+            return Err(GgswCiphertextScalarEncryptionError::Engine(
+                NoiseTrackingError::Core(CoreError::UnsupportedPolynomialSize),
+            ));
+        }
+        let decomposition = GgswCiphertextScalarEncryptionError::perform_generic_checks::<u32>(
+            decomposition_level_count,
+            decomposition_base_log,
+        )?;
+        Ok(unsafe {
+            self.encrypt_scalar_ggsw_ciphertext_unchecked(
+                key,
+                input,
+                noise,
+                decomposition.level_count(),
+                decomposition.base_log(),
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> NoisyFourierGgswCiphertext32 {
+        let ciphertext = self.core_engine.encrypt_scalar_ggsw_ciphertext_unchecked(
+            key,
+            input,
+            noise,
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        let fourier_ciphertext = self
+            .core_engine
+            .convert_ggsw_ciphertext_unchecked(&ciphertext);
+        // The message is a small integer, which may be negative.
+        let message = (input.0 .0 as i32) as f64;
+        NoisyFourierGgswCiphertext32 {
+            ciphertext: fourier_ciphertext,
+            variance: noise,
+            message_squared: message * message,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextScalarEncryptionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 64 bits integers. It outputs a GGSW ciphertext in the Fourier domain.
+impl
+    GgswCiphertextScalarEncryptionEngine<GlweSecretKey64, Plaintext64, NoisyFourierGgswCiphertext64>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey64 =
+    ///     core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext: NoisyFourierGgswCiphertext64 =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.variance(), noise);
+    /// assert_eq!(ciphertext.message_squared(), (input as f64).powi(2));
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<NoisyFourierGgswCiphertext64, GgswCiphertextScalarEncryptionError<Self::EngineError>>
+    {
+        if !ALLOWED_POLY_SIZE.contains(&key.polynomial_size().0) {
+            return Err(GgswCiphertextScalarEncryptionError::Engine(
+                NoiseTrackingError::Core(CoreError::UnsupportedPolynomialSize),
+            ));
+        }
+        let decomposition = GgswCiphertextScalarEncryptionError::perform_generic_checks::<u64>(
+            decomposition_level_count,
+            decomposition_base_log,
+        )?;
+        Ok(unsafe {
+            self.encrypt_scalar_ggsw_ciphertext_unchecked(
+                key,
+                input,
+                noise,
+                decomposition.level_count(),
+                decomposition.base_log(),
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> NoisyFourierGgswCiphertext64 {
+        let ciphertext = self.core_engine.encrypt_scalar_ggsw_ciphertext_unchecked(
+            key,
+            input,
+            noise,
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        let fourier_ciphertext = self
+            .core_engine
+            .convert_ggsw_ciphertext_unchecked(&ciphertext);
+        // The message is a small integer, which may be negative.
+        let message = (input.0 .0 as i64) as f64;
+        NoisyFourierGgswCiphertext64 {
+            ciphertext: fourier_ciphertext,
+            variance: noise,
+            message_squared: message * message,
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::backends::core::entities::{
+    GlweSecretKey32, GlweSecretKey64, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::noise_tracking::implementation::engines::NoiseTrackingEngine;
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyGlweCiphertext32, NoisyGlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextDecryptionEngine, GlweCiphertextDecryptionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`NoiseTrackingEngine`] that operates
+/// on 32 bits integers.
+impl GlweCiphertextDecryptionEngine<GlweSecretKey32, NoisyGlweCiphertext32, PlaintextVector32>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey32 =
+    ///     core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    /// let ciphertext: NoisyGlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(polynomial_size.0)
+    /// );
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// core_engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &NoisyGlweCiphertext32,
+    ) -> Result<PlaintextVector32, GlweCiphertextDecryptionError<Self::EngineError>> {
+        GlweCiphertextDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &NoisyGlweCiphertext32,
+    ) -> PlaintextVector32 {
+        self.core_engine
+            .decrypt_glwe_ciphertext_unchecked(key, &input.ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`NoiseTrackingEngine`] that operates
+/// on 64 bits integers.
+impl GlweCiphertextDecryptionEngine<GlweSecretKey64, NoisyGlweCiphertext64, PlaintextVector64>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey64 =
+    ///     core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    /// let ciphertext: NoisyGlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(polynomial_size.0)
+    /// );
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// core_engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &NoisyGlweCiphertext64,
+    ) -> Result<PlaintextVector64, GlweCiphertextDecryptionError<Self::EngineError>> {
+        GlweCiphertextDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &NoisyGlweCiphertext64,
+    ) -> PlaintextVector64 {
+        self.core_engine
+            .decrypt_glwe_ciphertext_unchecked(key, &input.ciphertext)
+    }
+}
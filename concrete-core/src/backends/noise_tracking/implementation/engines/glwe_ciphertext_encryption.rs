@@ -0,0 +1,158 @@
+use concrete_commons::dispersion::Variance;
+
+use crate::backends::core::entities::{
+    GlweSecretKey32, GlweSecretKey64, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyGlweCiphertext32, NoisyGlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextEncryptionEngine, GlweCiphertextEncryptionError,
+};
+
+impl From<NoiseTrackingError> for GlweCiphertextEncryptionError<NoiseTrackingError> {
+    fn from(err: NoiseTrackingError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`NoiseTrackingEngine`] that operates
+/// on 32 bits integers.
+impl GlweCiphertextEncryptionEngine<GlweSecretKey32, PlaintextVector32, NoisyGlweCiphertext32>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey32 =
+    ///     core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext: NoisyGlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.variance(), noise);
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<NoisyGlweCiphertext32, GlweCiphertextEncryptionError<Self::EngineError>> {
+        GlweCiphertextEncryptionError::perform_generic_checks(key, input)?;
+        self.check_noise_budget(noise)?;
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> NoisyGlweCiphertext32 {
+        NoisyGlweCiphertext32 {
+            ciphertext: self
+                .core_engine
+                .encrypt_glwe_ciphertext_unchecked(key, input, noise),
+            variance: noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`NoiseTrackingEngine`] that operates
+/// on 64 bits integers.
+impl GlweCiphertextEncryptionEngine<GlweSecretKey64, PlaintextVector64, NoisyGlweCiphertext64>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey64 =
+    ///     core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext: NoisyGlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.variance(), noise);
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<NoisyGlweCiphertext64, GlweCiphertextEncryptionError<Self::EngineError>> {
+        GlweCiphertextEncryptionError::perform_generic_checks(key, input)?;
+        self.check_noise_budget(noise)?;
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> NoisyGlweCiphertext64 {
+        NoisyGlweCiphertext64 {
+            ciphertext: self
+                .core_engine
+                .encrypt_glwe_ciphertext_unchecked(key, input, noise),
+            variance: noise,
+        }
+    }
+}
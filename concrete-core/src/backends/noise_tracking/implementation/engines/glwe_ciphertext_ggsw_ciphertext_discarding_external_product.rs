@@ -0,0 +1,291 @@
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_npe::estimate_external_product_noise;
+
+use crate::backends::core::engines::CoreError;
+use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyFourierGgswCiphertext32, NoisyFourierGgswCiphertext64, NoisyGlweCiphertext32,
+    NoisyGlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextGgswCiphertextDiscardingExternalProductEngine,
+    GlweCiphertextGgswCiphertextDiscardingExternalProductError,
+};
+use crate::specification::entities::{GgswCiphertextEntity, GlweCiphertextEntity};
+
+impl From<NoiseTrackingError>
+    for GlweCiphertextGgswCiphertextDiscardingExternalProductError<NoiseTrackingError>
+{
+    fn from(err: NoiseTrackingError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextGgswCiphertextDiscardingExternalProductEngine`] for
+/// [`NoiseTrackingEngine`] that operates on 32 bits integers.
+///
+/// The variance of the output is estimated from the square of the message encrypted by the GGSW
+/// ciphertext.
+impl
+    GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+        NoisyGlweCiphertext32,
+        NoisyFourierGgswCiphertext32,
+        NoisyGlweCiphertext32,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// let input_ggsw = 1_u32;
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_glwe = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let ggsw_noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey32 =
+    ///     core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = core_engine.create_plaintext(&input_ggsw)?;
+    /// let plaintext_glwe = core_engine.create_plaintext_vector(&input_glwe)?;
+    /// let ggsw: NoisyFourierGgswCiphertext32 =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext_ggsw, ggsw_noise, level, base_log)?;
+    /// let glwe: NoisyGlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    /// let mut product: NoisyGlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    ///
+    /// // Compute the external product.
+    /// engine.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+    ///     &glwe,
+    ///     &ggsw,
+    ///     &mut product,
+    /// )?;
+    /// #
+    /// assert_eq!(product.polynomial_size(), glwe.polynomial_size());
+    /// // The estimated variance of the output is within the noise budget.
+    /// assert!(product.variance().0 <= engine.noise_budget().0);
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_ggsw)?;
+    /// core_engine.destroy(plaintext_glwe)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(glwe)?;
+    /// engine.destroy(product)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &NoisyGlweCiphertext32,
+        ggsw_input: &NoisyFourierGgswCiphertext32,
+        output: &mut NoisyGlweCiphertext32,
+    ) -> Result<(), GlweCiphertextGgswCiphertextDiscardingExternalProductError<Self::EngineError>>
+    {
+        if !ALLOWED_POLY_SIZE.contains(&glwe_input.polynomial_size().0) {
+            return Err(
+                GlweCiphertextGgswCiphertextDiscardingExternalProductError::Engine(
+                    NoiseTrackingError::Core(CoreError::UnsupportedPolynomialSize),
+                ),
+            );
+        }
+        GlweCiphertextGgswCiphertextDiscardingExternalProductError::perform_generic_checks(
+            glwe_input, ggsw_input, output,
+        )?;
+        let variance = estimate_external_product_noise::<u32, _, _, BinaryKeyKind>(
+            ggsw_input.polynomial_size(),
+            ggsw_input.glwe_dimension(),
+            glwe_input.variance,
+            ggsw_input.variance,
+            ggsw_input.decomposition_base_log(),
+            ggsw_input.decomposition_level_count(),
+            ggsw_input.message_squared,
+        );
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine
+                .discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                    &glwe_input.ciphertext,
+                    &ggsw_input.ciphertext,
+                    &mut output.ciphertext,
+                );
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &NoisyGlweCiphertext32,
+        ggsw_input: &NoisyFourierGgswCiphertext32,
+        output: &mut NoisyGlweCiphertext32,
+    ) {
+        self.core_engine
+            .discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                &glwe_input.ciphertext,
+                &ggsw_input.ciphertext,
+                &mut output.ciphertext,
+            );
+        output.variance = estimate_external_product_noise::<u32, _, _, BinaryKeyKind>(
+            ggsw_input.polynomial_size(),
+            ggsw_input.glwe_dimension(),
+            glwe_input.variance,
+            ggsw_input.variance,
+            ggsw_input.decomposition_base_log(),
+            ggsw_input.decomposition_level_count(),
+            ggsw_input.message_squared,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextGgswCiphertextDiscardingExternalProductEngine`] for
+/// [`NoiseTrackingEngine`] that operates on 64 bits integers.
+///
+/// The variance of the output is estimated from the square of the message encrypted by the GGSW
+/// ciphertext.
+impl
+    GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+        NoisyGlweCiphertext64,
+        NoisyFourierGgswCiphertext64,
+        NoisyGlweCiphertext64,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// let input_ggsw = 1_u64;
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_glwe = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let ggsw_noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey64 =
+    ///     core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = core_engine.create_plaintext(&input_ggsw)?;
+    /// let plaintext_glwe = core_engine.create_plaintext_vector(&input_glwe)?;
+    /// let ggsw: NoisyFourierGgswCiphertext64 =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext_ggsw, ggsw_noise, level, base_log)?;
+    /// let glwe: NoisyGlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    /// let mut product: NoisyGlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    ///
+    /// // Compute the external product.
+    /// engine.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+    ///     &glwe,
+    ///     &ggsw,
+    ///     &mut product,
+    /// )?;
+    /// #
+    /// assert_eq!(product.polynomial_size(), glwe.polynomial_size());
+    /// // The estimated variance of the output is within the noise budget.
+    /// assert!(product.variance().0 <= engine.noise_budget().0);
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_ggsw)?;
+    /// core_engine.destroy(plaintext_glwe)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(glwe)?;
+    /// engine.destroy(product)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &NoisyGlweCiphertext64,
+        ggsw_input: &NoisyFourierGgswCiphertext64,
+        output: &mut NoisyGlweCiphertext64,
+    ) -> Result<(), GlweCiphertextGgswCiphertextDiscardingExternalProductError<Self::EngineError>>
+    {
+        if !ALLOWED_POLY_SIZE.contains(&glwe_input.polynomial_size().0) {
+            return Err(
+                GlweCiphertextGgswCiphertextDiscardingExternalProductError::Engine(
+                    NoiseTrackingError::Core(CoreError::UnsupportedPolynomialSize),
+                ),
+            );
+        }
+        GlweCiphertextGgswCiphertextDiscardingExternalProductError::perform_generic_checks(
+            glwe_input, ggsw_input, output,
+        )?;
+        let variance = estimate_external_product_noise::<u64, _, _, BinaryKeyKind>(
+            ggsw_input.polynomial_size(),
+            ggsw_input.glwe_dimension(),
+            glwe_input.variance,
+            ggsw_input.variance,
+            ggsw_input.decomposition_base_log(),
+            ggsw_input.decomposition_level_count(),
+            ggsw_input.message_squared,
+        );
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine
+                .discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                    &glwe_input.ciphertext,
+                    &ggsw_input.ciphertext,
+                    &mut output.ciphertext,
+                );
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &NoisyGlweCiphertext64,
+        ggsw_input: &NoisyFourierGgswCiphertext64,
+        output: &mut NoisyGlweCiphertext64,
+    ) {
+        self.core_engine
+            .discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                &glwe_input.ciphertext,
+                &ggsw_input.ciphertext,
+                &mut output.ciphertext,
+            );
+        output.variance = estimate_external_product_noise::<u64, _, _, BinaryKeyKind>(
+            ggsw_input.polynomial_size(),
+            ggsw_input.glwe_dimension(),
+            glwe_input.variance,
+            ggsw_input.variance,
+            ggsw_input.decomposition_base_log(),
+            ggsw_input.decomposition_level_count(),
+            ggsw_input.message_squared,
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::GlweSize;
+
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::noise_tracking::implementation::engines::NoiseTrackingEngine;
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyGlweCiphertext32, NoisyGlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextTrivialEncryptionEngine, GlweCiphertextTrivialEncryptionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextTrivialEncryptionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 32 bits integers.
+impl GlweCiphertextTrivialEncryptionEngine<PlaintextVector32, NoisyGlweCiphertext32>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    ///
+    /// // The trivial encryption does not add any noise.
+    /// let ciphertext: NoisyGlweCiphertext32 = engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.variance(), Variance(0.));
+    ///
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_glwe_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector32,
+    ) -> Result<NoisyGlweCiphertext32, GlweCiphertextTrivialEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input) })
+    }
+
+    unsafe fn trivially_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector32,
+    ) -> NoisyGlweCiphertext32 {
+        NoisyGlweCiphertext32 {
+            ciphertext: self
+                .core_engine
+                .trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input),
+            variance: Variance(0.),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextTrivialEncryptionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 64 bits integers.
+impl GlweCiphertextTrivialEncryptionEngine<PlaintextVector64, NoisyGlweCiphertext64>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    ///
+    /// // The trivial encryption does not add any noise.
+    /// let ciphertext: NoisyGlweCiphertext64 = engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.variance(), Variance(0.));
+    ///
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_glwe_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector64,
+    ) -> Result<NoisyGlweCiphertext64, GlweCiphertextTrivialEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input) })
+    }
+
+    unsafe fn trivially_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector64,
+    ) -> NoisyGlweCiphertext64 {
+        NoisyGlweCiphertext64 {
+            ciphertext: self
+                .core_engine
+                .trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input),
+            variance: Variance(0.),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::backends::core::entities::{
+    GlweSecretKey32, GlweSecretKey64, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::noise_tracking::implementation::engines::NoiseTrackingEngine;
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyGlweCiphertextVector32, NoisyGlweCiphertextVector64,
+};
+use crate::specification::engines::{
+    GlweCiphertextVectorDecryptionEngine, GlweCiphertextVectorDecryptionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDecryptionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextVectorDecryptionEngine<
+        GlweSecretKey32,
+        NoisyGlweCiphertextVector32,
+        PlaintextVector32,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey32 = core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: NoisyGlweCiphertextVector32 =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_glwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(decrypted_plaintext_vector.plaintext_count(), PlaintextCount(8));
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// core_engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &NoisyGlweCiphertextVector32,
+    ) -> Result<PlaintextVector32, GlweCiphertextVectorDecryptionError<Self::EngineError>> {
+        GlweCiphertextVectorDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_glwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &NoisyGlweCiphertextVector32,
+    ) -> PlaintextVector32 {
+        self.core_engine
+            .decrypt_glwe_ciphertext_vector_unchecked(key, &input.ciphertexts)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDecryptionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextVectorDecryptionEngine<
+        GlweSecretKey64,
+        NoisyGlweCiphertextVector64,
+        PlaintextVector64,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey64 = core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: NoisyGlweCiphertextVector64 =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_glwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(decrypted_plaintext_vector.plaintext_count(), PlaintextCount(8));
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// core_engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &NoisyGlweCiphertextVector64,
+    ) -> Result<PlaintextVector64, GlweCiphertextVectorDecryptionError<Self::EngineError>> {
+        GlweCiphertextVectorDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_glwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &NoisyGlweCiphertextVector64,
+    ) -> PlaintextVector64 {
+        self.core_engine
+            .decrypt_glwe_ciphertext_vector_unchecked(key, &input.ciphertexts)
+    }
+}
@@ -0,0 +1,173 @@
+use concrete_commons::dispersion::Variance;
+
+use crate::backends::core::entities::{
+    GlweSecretKey32, GlweSecretKey64, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyGlweCiphertextVector32, NoisyGlweCiphertextVector64,
+};
+use crate::specification::engines::{
+    GlweCiphertextVectorEncryptionEngine, GlweCiphertextVectorEncryptionError,
+};
+use crate::specification::entities::GlweCiphertextVectorEntity;
+
+impl From<NoiseTrackingError> for GlweCiphertextVectorEncryptionError<NoiseTrackingError> {
+    fn from(err: NoiseTrackingError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextVectorEncryptionEngine<
+        GlweSecretKey32,
+        PlaintextVector32,
+        NoisyGlweCiphertextVector32,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey32 = core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector: NoisyGlweCiphertextVector32 =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.variances(), &[noise; 2]);
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<NoisyGlweCiphertextVector32, GlweCiphertextVectorEncryptionError<Self::EngineError>>
+    {
+        GlweCiphertextVectorEncryptionError::perform_generic_checks(key, input)?;
+        self.check_noise_budget(noise)?;
+        Ok(unsafe { self.encrypt_glwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> NoisyGlweCiphertextVector32 {
+        let ciphertexts = self
+            .core_engine
+            .encrypt_glwe_ciphertext_vector_unchecked(key, input, noise);
+        let count = ciphertexts.glwe_ciphertext_count().0;
+        NoisyGlweCiphertextVector32 {
+            ciphertexts,
+            variances: vec![noise; count],
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextVectorEncryptionEngine<
+        GlweSecretKey64,
+        PlaintextVector64,
+        NoisyGlweCiphertextVector64,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey64 = core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector: NoisyGlweCiphertextVector64 =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.variances(), &[noise; 2]);
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<NoisyGlweCiphertextVector64, GlweCiphertextVectorEncryptionError<Self::EngineError>>
+    {
+        GlweCiphertextVectorEncryptionError::perform_generic_checks(key, input)?;
+        self.check_noise_budget(noise)?;
+        Ok(unsafe { self.encrypt_glwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> NoisyGlweCiphertextVector64 {
+        let ciphertexts = self
+            .core_engine
+            .encrypt_glwe_ciphertext_vector_unchecked(key, input, noise);
+        let count = ciphertexts.glwe_ciphertext_count().0;
+        NoisyGlweCiphertextVector64 {
+            ciphertexts,
+            variances: vec![noise; count],
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::GlweCiphertextCount;
+
+use crate::backends::core::entities::{GlweSecretKey32, GlweSecretKey64};
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyGlweCiphertextVector32, NoisyGlweCiphertextVector64,
+};
+use crate::specification::engines::{
+    GlweCiphertextVectorZeroEncryptionEngine, GlweCiphertextVectorZeroEncryptionError,
+};
+
+impl From<NoiseTrackingError> for GlweCiphertextVectorZeroEncryptionError<NoiseTrackingError> {
+    fn from(err: NoiseTrackingError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorZeroEncryptionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 32 bits integers.
+impl GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey32, NoisyGlweCiphertextVector32>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let ciphertext_count = GlweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey32 = core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let ciphertext_vector: NoisyGlweCiphertextVector32 =
+    ///     engine.zero_encrypt_glwe_ciphertext_vector(&key, noise, ciphertext_count)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_ciphertext_count(), ciphertext_count);
+    /// assert_eq!(ciphertext_vector.variances(), &[noise; 3]);
+    ///
+    /// core_engine.destroy(key)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &GlweSecretKey32,
+        noise: Variance,
+        count: GlweCiphertextCount,
+    ) -> Result<
+        NoisyGlweCiphertextVector32,
+        GlweCiphertextVectorZeroEncryptionError<Self::EngineError>,
+    > {
+        GlweCiphertextVectorZeroEncryptionError::perform_generic_checks(count)?;
+        self.check_noise_budget(noise)?;
+        Ok(unsafe { self.zero_encrypt_glwe_ciphertext_vector_unchecked(key, noise, count) })
+    }
+
+    unsafe fn zero_encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        noise: Variance,
+        count: GlweCiphertextCount,
+    ) -> NoisyGlweCiphertextVector32 {
+        NoisyGlweCiphertextVector32 {
+            ciphertexts: self
+                .core_engine
+                .zero_encrypt_glwe_ciphertext_vector_unchecked(key, noise, count),
+            variances: vec![noise; count.0],
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorZeroEncryptionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 64 bits integers.
+impl GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey64, NoisyGlweCiphertextVector64>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let ciphertext_count = GlweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: GlweSecretKey64 = core_engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let ciphertext_vector: NoisyGlweCiphertextVector64 =
+    ///     engine.zero_encrypt_glwe_ciphertext_vector(&key, noise, ciphertext_count)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_ciphertext_count(), ciphertext_count);
+    /// assert_eq!(ciphertext_vector.variances(), &[noise; 3]);
+    ///
+    /// core_engine.destroy(key)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &GlweSecretKey64,
+        noise: Variance,
+        count: GlweCiphertextCount,
+    ) -> Result<
+        NoisyGlweCiphertextVector64,
+        GlweCiphertextVectorZeroEncryptionError<Self::EngineError>,
+    > {
+        GlweCiphertextVectorZeroEncryptionError::perform_generic_checks(count)?;
+        self.check_noise_budget(noise)?;
+        Ok(unsafe { self.zero_encrypt_glwe_ciphertext_vector_unchecked(key, noise, count) })
+    }
+
+    unsafe fn zero_encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        noise: Variance,
+        count: GlweCiphertextCount,
+    ) -> NoisyGlweCiphertextVector64 {
+        NoisyGlweCiphertextVector64 {
+            ciphertexts: self
+                .core_engine
+                .zero_encrypt_glwe_ciphertext_vector_unchecked(key, noise, count),
+            variances: vec![noise; count.0],
+        }
+    }
+}
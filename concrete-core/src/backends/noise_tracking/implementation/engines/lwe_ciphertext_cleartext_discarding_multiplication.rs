@@ -0,0 +1,207 @@
+use concrete_npe::estimate_integer_plaintext_multiplication_noise;
+
+use crate::backends::core::entities::{Cleartext32, Cleartext64};
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyLweCiphertext32, NoisyLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextCleartextDiscardingMultiplicationEngine,
+    LweCiphertextCleartextDiscardingMultiplicationError,
+};
+
+impl From<NoiseTrackingError>
+    for LweCiphertextCleartextDiscardingMultiplicationError<NoiseTrackingError>
+{
+    fn from(err: NoiseTrackingError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextDiscardingMultiplicationEngine`] for
+/// [`NoiseTrackingEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextCleartextDiscardingMultiplicationEngine<
+        NoisyLweCiphertext32,
+        Cleartext32,
+        NoisyLweCiphertext32,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let cleartext_input = 4_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let cleartext: Cleartext32 = core_engine.create_cleartext(&cleartext_input)?;
+    /// let key: LweSecretKey32 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let ciphertext_1: NoisyLweCiphertext32 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext_2: NoisyLweCiphertext32 =
+    ///     engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_mul_lwe_ciphertext_cleartext(&mut ciphertext_2, &ciphertext_1, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), lwe_dimension);
+    /// // The variance is multiplied by the square of the cleartext.
+    /// assert_eq!(ciphertext_2.variance(), Variance(2_f64.powf(-21.)));
+    ///
+    /// core_engine.destroy(cleartext)?;
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        input_1: &NoisyLweCiphertext32,
+        input_2: &Cleartext32,
+    ) -> Result<(), LweCiphertextCleartextDiscardingMultiplicationError<Self::EngineError>> {
+        LweCiphertextCleartextDiscardingMultiplicationError::perform_generic_checks(
+            output, input_1,
+        )?;
+        let variance =
+            estimate_integer_plaintext_multiplication_noise(input_1.variance, input_2.0 .0);
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine
+                .discard_mul_lwe_ciphertext_cleartext_unchecked(
+                    &mut output.ciphertext,
+                    &input_1.ciphertext,
+                    input_2,
+                );
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn discard_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        input_1: &NoisyLweCiphertext32,
+        input_2: &Cleartext32,
+    ) {
+        self.core_engine
+            .discard_mul_lwe_ciphertext_cleartext_unchecked(
+                &mut output.ciphertext,
+                &input_1.ciphertext,
+                input_2,
+            );
+        output.variance =
+            estimate_integer_plaintext_multiplication_noise(input_1.variance, input_2.0 .0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextDiscardingMultiplicationEngine`] for
+/// [`NoiseTrackingEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextCleartextDiscardingMultiplicationEngine<
+        NoisyLweCiphertext64,
+        Cleartext64,
+        NoisyLweCiphertext64,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let cleartext_input = 4_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let cleartext: Cleartext64 = core_engine.create_cleartext(&cleartext_input)?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let ciphertext_1: NoisyLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext_2: NoisyLweCiphertext64 =
+    ///     engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_mul_lwe_ciphertext_cleartext(&mut ciphertext_2, &ciphertext_1, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), lwe_dimension);
+    /// // The variance is multiplied by the square of the cleartext.
+    /// assert_eq!(ciphertext_2.variance(), Variance(2_f64.powf(-21.)));
+    ///
+    /// core_engine.destroy(cleartext)?;
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        input_1: &NoisyLweCiphertext64,
+        input_2: &Cleartext64,
+    ) -> Result<(), LweCiphertextCleartextDiscardingMultiplicationError<Self::EngineError>> {
+        LweCiphertextCleartextDiscardingMultiplicationError::perform_generic_checks(
+            output, input_1,
+        )?;
+        let variance =
+            estimate_integer_plaintext_multiplication_noise(input_1.variance, input_2.0 .0);
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine
+                .discard_mul_lwe_ciphertext_cleartext_unchecked(
+                    &mut output.ciphertext,
+                    &input_1.ciphertext,
+                    input_2,
+                );
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn discard_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        input_1: &NoisyLweCiphertext64,
+        input_2: &Cleartext64,
+    ) {
+        self.core_engine
+            .discard_mul_lwe_ciphertext_cleartext_unchecked(
+                &mut output.ciphertext,
+                &input_1.ciphertext,
+                input_2,
+            );
+        output.variance =
+            estimate_integer_plaintext_multiplication_noise(input_1.variance, input_2.0 .0);
+    }
+}
@@ -0,0 +1,177 @@
+use concrete_npe::estimate_integer_plaintext_multiplication_noise;
+
+use crate::backends::core::entities::{Cleartext32, Cleartext64};
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyLweCiphertext32, NoisyLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextCleartextFusingMultiplicationEngine,
+    LweCiphertextCleartextFusingMultiplicationError,
+};
+
+impl From<NoiseTrackingError>
+    for LweCiphertextCleartextFusingMultiplicationError<NoiseTrackingError>
+{
+    fn from(err: NoiseTrackingError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for
+/// [`NoiseTrackingEngine`] that operates on 32 bits integers.
+impl LweCiphertextCleartextFusingMultiplicationEngine<NoisyLweCiphertext32, Cleartext32>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let cleartext_input = 4_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let cleartext: Cleartext32 = core_engine.create_cleartext(&cleartext_input)?;
+    /// let key: LweSecretKey32 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let mut ciphertext: NoisyLweCiphertext32 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// // The variance is multiplied by the square of the cleartext.
+    /// assert_eq!(ciphertext.variance(), Variance(2_f64.powf(-21.)));
+    ///
+    /// // A second multiplication would exceed the noise budget, and leaves the ciphertext as is.
+    /// assert!(engine
+    ///     .fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)
+    ///     .is_err());
+    /// assert_eq!(ciphertext.variance(), Variance(2_f64.powf(-21.)));
+    ///
+    /// core_engine.destroy(cleartext)?;
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        input: &Cleartext32,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        let variance = estimate_integer_plaintext_multiplication_noise(output.variance, input.0 .0);
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine
+                .fuse_mul_lwe_ciphertext_cleartext_unchecked(&mut output.ciphertext, input);
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        input: &Cleartext32,
+    ) {
+        self.core_engine
+            .fuse_mul_lwe_ciphertext_cleartext_unchecked(&mut output.ciphertext, input);
+        output.variance =
+            estimate_integer_plaintext_multiplication_noise(output.variance, input.0 .0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for
+/// [`NoiseTrackingEngine`] that operates on 64 bits integers.
+impl LweCiphertextCleartextFusingMultiplicationEngine<NoisyLweCiphertext64, Cleartext64>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let cleartext_input = 4_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let cleartext: Cleartext64 = core_engine.create_cleartext(&cleartext_input)?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let mut ciphertext: NoisyLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// // The variance is multiplied by the square of the cleartext.
+    /// assert_eq!(ciphertext.variance(), Variance(2_f64.powf(-21.)));
+    ///
+    /// // A second multiplication would exceed the noise budget, and leaves the ciphertext as is.
+    /// assert!(engine
+    ///     .fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)
+    ///     .is_err());
+    /// assert_eq!(ciphertext.variance(), Variance(2_f64.powf(-21.)));
+    ///
+    /// core_engine.destroy(cleartext)?;
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        input: &Cleartext64,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        let variance = estimate_integer_plaintext_multiplication_noise(output.variance, input.0 .0);
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine
+                .fuse_mul_lwe_ciphertext_cleartext_unchecked(&mut output.ciphertext, input);
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        input: &Cleartext64,
+    ) {
+        self.core_engine
+            .fuse_mul_lwe_ciphertext_cleartext_unchecked(&mut output.ciphertext, input);
+        output.variance =
+            estimate_integer_plaintext_multiplication_noise(output.variance, input.0 .0);
+    }
+}
@@ -0,0 +1,189 @@
+use concrete_npe::estimate_addition_noise;
+
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyLweCiphertext32, NoisyLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
+};
+
+impl From<NoiseTrackingError> for LweCiphertextDiscardingAdditionError<NoiseTrackingError> {
+    fn from(err: NoiseTrackingError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextDiscardingAdditionEngine<NoisyLweCiphertext32, NoisyLweCiphertext32>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = 3_u32 << 20;
+    /// let input_2 = 7_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: LweSecretKey32 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = core_engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = core_engine.create_plaintext(&input_2)?;
+    /// let ciphertext_1: NoisyLweCiphertext32 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2: NoisyLweCiphertext32 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    /// let mut ciphertext_3: NoisyLweCiphertext32 =
+    ///     engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_add_lwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.lwe_dimension(), lwe_dimension);
+    /// // The variances of the inputs add up.
+    /// assert_eq!(ciphertext_3.variance(), Variance(2_f64.powf(-24.)));
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_1)?;
+    /// core_engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        input_1: &NoisyLweCiphertext32,
+        input_2: &NoisyLweCiphertext32,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        let variance = estimate_addition_noise::<u32, _, _>(input_1.variance, input_2.variance);
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine.discard_add_lwe_ciphertext_unchecked(
+                &mut output.ciphertext,
+                &input_1.ciphertext,
+                &input_2.ciphertext,
+            );
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        input_1: &NoisyLweCiphertext32,
+        input_2: &NoisyLweCiphertext32,
+    ) {
+        self.core_engine.discard_add_lwe_ciphertext_unchecked(
+            &mut output.ciphertext,
+            &input_1.ciphertext,
+            &input_2.ciphertext,
+        );
+        output.variance = estimate_addition_noise::<u32, _, _>(input_1.variance, input_2.variance);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextDiscardingAdditionEngine<NoisyLweCiphertext64, NoisyLweCiphertext64>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = 3_u64 << 50;
+    /// let input_2 = 7_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = core_engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = core_engine.create_plaintext(&input_2)?;
+    /// let ciphertext_1: NoisyLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2: NoisyLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    /// let mut ciphertext_3: NoisyLweCiphertext64 =
+    ///     engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_add_lwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.lwe_dimension(), lwe_dimension);
+    /// // The variances of the inputs add up.
+    /// assert_eq!(ciphertext_3.variance(), Variance(2_f64.powf(-24.)));
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_1)?;
+    /// core_engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        input_1: &NoisyLweCiphertext64,
+        input_2: &NoisyLweCiphertext64,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        let variance = estimate_addition_noise::<u64, _, _>(input_1.variance, input_2.variance);
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine.discard_add_lwe_ciphertext_unchecked(
+                &mut output.ciphertext,
+                &input_1.ciphertext,
+                &input_2.ciphertext,
+            );
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        input_1: &NoisyLweCiphertext64,
+        input_2: &NoisyLweCiphertext64,
+    ) {
+        self.core_engine.discard_add_lwe_ciphertext_unchecked(
+            &mut output.ciphertext,
+            &input_1.ciphertext,
+            &input_2.ciphertext,
+        );
+        output.variance = estimate_addition_noise::<u64, _, _>(input_1.variance, input_2.variance);
+    }
+}
@@ -0,0 +1,316 @@
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_npe::{
+    estimate_addition_noise, estimate_modulus_switching_noise_with_binary_key, estimate_pbs_noise,
+};
+
+use crate::backends::core::engines::CoreError;
+use crate::backends::core::private::math::fft::ALLOWED_POLY_SIZE;
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyFourierLweBootstrapKey32, NoisyFourierLweBootstrapKey64, NoisyGlweCiphertext32,
+    NoisyGlweCiphertext64, NoisyLweCiphertext32, NoisyLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity,
+};
+
+impl From<NoiseTrackingError> for LweCiphertextDiscardingBootstrapError<NoiseTrackingError> {
+    fn from(err: NoiseTrackingError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 32 bits integers.
+///
+/// The variance of the output is the one of the accumulator, increased by the noise added by the
+/// blind rotation. It does not depend on the variance of the input, but the safe entry point also
+/// checks the variance of the input after its modulus switching to $2N$ against the noise budget.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        NoisyFourierLweBootstrapKey32,
+        NoisyGlweCiphertext32,
+        NoisyLweCiphertext32,
+        NoisyLweCiphertext32,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let bsk_noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let lwe_sk: LweSecretKey32 = core_engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = core_engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: NoisyFourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, bsk_noise)?;
+    /// let lwe_sk_output: LweSecretKey32 = core_engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&lut)?;
+    /// let acc: NoisyGlweCiphertext32 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input: NoisyLweCiphertext32 = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output: NoisyLweCiphertext32 =
+    ///     engine.encrypt_lwe_ciphertext(&lwe_sk_output, &plaintext, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// // The estimated variance of the output is within the noise budget.
+    /// assert!(output.variance().0 <= engine.noise_budget().0);
+    ///
+    /// core_engine.destroy(lwe_sk)?;
+    /// core_engine.destroy(glwe_sk)?;
+    /// core_engine.destroy(lwe_sk_output)?;
+    /// core_engine.destroy(plaintext)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        input: &NoisyLweCiphertext32,
+        acc: &NoisyGlweCiphertext32,
+        bsk: &NoisyFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&acc.polynomial_size().0) {
+            return Err(LweCiphertextDiscardingBootstrapError::Engine(
+                NoiseTrackingError::Core(CoreError::UnsupportedPolynomialSize),
+            ));
+        }
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        self.check_noise_budget(estimate_modulus_switching_noise_with_binary_key::<u32, _>(
+            input.lwe_dimension(),
+            acc.polynomial_size().log2().0 + 1,
+            input.variance,
+        ))?;
+        let variance = estimate_addition_noise::<u32, _, _>(
+            acc.variance,
+            estimate_pbs_noise::<u32, _, BinaryKeyKind>(
+                bsk.input_lwe_dimension(),
+                bsk.polynomial_size(),
+                bsk.glwe_dimension(),
+                bsk.decomposition_base_log(),
+                bsk.decomposition_level_count(),
+                bsk.variance,
+            ),
+        );
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine.discard_bootstrap_lwe_ciphertext_unchecked(
+                &mut output.ciphertext,
+                &input.ciphertext,
+                &acc.ciphertext,
+                &bsk.key,
+            );
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        input: &NoisyLweCiphertext32,
+        acc: &NoisyGlweCiphertext32,
+        bsk: &NoisyFourierLweBootstrapKey32,
+    ) {
+        self.core_engine.discard_bootstrap_lwe_ciphertext_unchecked(
+            &mut output.ciphertext,
+            &input.ciphertext,
+            &acc.ciphertext,
+            &bsk.key,
+        );
+        output.variance = estimate_addition_noise::<u32, _, _>(
+            acc.variance,
+            estimate_pbs_noise::<u32, _, BinaryKeyKind>(
+                bsk.input_lwe_dimension(),
+                bsk.polynomial_size(),
+                bsk.glwe_dimension(),
+                bsk.decomposition_base_log(),
+                bsk.decomposition_level_count(),
+                bsk.variance,
+            ),
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 64 bits integers.
+///
+/// The variance of the output is the one of the accumulator, increased by the noise added by the
+/// blind rotation. It does not depend on the variance of the input, but the safe entry point also
+/// checks the variance of the input after its modulus switching to $2N$ against the noise budget.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        NoisyFourierLweBootstrapKey64,
+        NoisyGlweCiphertext64,
+        NoisyLweCiphertext64,
+        NoisyLweCiphertext64,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let bsk_noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let lwe_sk: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = core_engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: NoisyFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, bsk_noise)?;
+    /// let lwe_sk_output: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&lut)?;
+    /// let acc: NoisyGlweCiphertext64 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input: NoisyLweCiphertext64 = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output: NoisyLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&lwe_sk_output, &plaintext, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// // The estimated variance of the output is within the noise budget.
+    /// assert!(output.variance().0 <= engine.noise_budget().0);
+    ///
+    /// core_engine.destroy(lwe_sk)?;
+    /// core_engine.destroy(glwe_sk)?;
+    /// core_engine.destroy(lwe_sk_output)?;
+    /// core_engine.destroy(plaintext)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        input: &NoisyLweCiphertext64,
+        acc: &NoisyGlweCiphertext64,
+        bsk: &NoisyFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if !ALLOWED_POLY_SIZE.contains(&acc.polynomial_size().0) {
+            return Err(LweCiphertextDiscardingBootstrapError::Engine(
+                NoiseTrackingError::Core(CoreError::UnsupportedPolynomialSize),
+            ));
+        }
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        self.check_noise_budget(estimate_modulus_switching_noise_with_binary_key::<u64, _>(
+            input.lwe_dimension(),
+            acc.polynomial_size().log2().0 + 1,
+            input.variance,
+        ))?;
+        let variance = estimate_addition_noise::<u64, _, _>(
+            acc.variance,
+            estimate_pbs_noise::<u64, _, BinaryKeyKind>(
+                bsk.input_lwe_dimension(),
+                bsk.polynomial_size(),
+                bsk.glwe_dimension(),
+                bsk.decomposition_base_log(),
+                bsk.decomposition_level_count(),
+                bsk.variance,
+            ),
+        );
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine.discard_bootstrap_lwe_ciphertext_unchecked(
+                &mut output.ciphertext,
+                &input.ciphertext,
+                &acc.ciphertext,
+                &bsk.key,
+            );
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        input: &NoisyLweCiphertext64,
+        acc: &NoisyGlweCiphertext64,
+        bsk: &NoisyFourierLweBootstrapKey64,
+    ) {
+        self.core_engine.discard_bootstrap_lwe_ciphertext_unchecked(
+            &mut output.ciphertext,
+            &input.ciphertext,
+            &acc.ciphertext,
+            &bsk.key,
+        );
+        output.variance = estimate_addition_noise::<u64, _, _>(
+            acc.variance,
+            estimate_pbs_noise::<u64, _, BinaryKeyKind>(
+                bsk.input_lwe_dimension(),
+                bsk.polynomial_size(),
+                bsk.glwe_dimension(),
+                bsk.decomposition_base_log(),
+                bsk.decomposition_level_count(),
+                bsk.variance,
+            ),
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_npe::estimate_keyswitch_noise_lwe_to_lwe;
+
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyLweCiphertext32, NoisyLweCiphertext64, NoisyLweKeyswitchKey32, NoisyLweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+impl From<NoiseTrackingError> for LweCiphertextDiscardingKeyswitchError<NoiseTrackingError> {
+    fn from(err: NoiseTrackingError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        NoisyLweKeyswitchKey32,
+        NoisyLweCiphertext32,
+        NoisyLweCiphertext32,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let ksk_noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let input_key: LweSecretKey32 = core_engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = core_engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: NoisyLweKeyswitchKey32 = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     ksk_noise,
+    /// )?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let ciphertext_1: NoisyLweCiphertext32 =
+    ///     engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2: NoisyLweCiphertext32 =
+    ///     engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    /// // The keyswitch adds some noise to the one of the input.
+    /// assert!(ciphertext_2.variance().0 > noise.0);
+    ///
+    /// core_engine.destroy(input_key)?;
+    /// core_engine.destroy(output_key)?;
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        input: &NoisyLweCiphertext32,
+        ksk: &NoisyLweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        let variance = estimate_keyswitch_noise_lwe_to_lwe::<u32, _, _, BinaryKeyKind>(
+            ksk.input_lwe_dimension(),
+            input.variance,
+            ksk.variance,
+            ksk.decomposition_base_log(),
+            ksk.decomposition_level_count(),
+        );
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine.discard_keyswitch_lwe_ciphertext_unchecked(
+                &mut output.ciphertext,
+                &input.ciphertext,
+                &ksk.key,
+            );
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        input: &NoisyLweCiphertext32,
+        ksk: &NoisyLweKeyswitchKey32,
+    ) {
+        self.core_engine.discard_keyswitch_lwe_ciphertext_unchecked(
+            &mut output.ciphertext,
+            &input.ciphertext,
+            &ksk.key,
+        );
+        output.variance = estimate_keyswitch_noise_lwe_to_lwe::<u32, _, _, BinaryKeyKind>(
+            ksk.input_lwe_dimension(),
+            input.variance,
+            ksk.variance,
+            ksk.decomposition_base_log(),
+            ksk.decomposition_level_count(),
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        NoisyLweKeyswitchKey64,
+        NoisyLweCiphertext64,
+        NoisyLweCiphertext64,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let ksk_noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let input_key: LweSecretKey64 = core_engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = core_engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: NoisyLweKeyswitchKey64 = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     ksk_noise,
+    /// )?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let ciphertext_1: NoisyLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2: NoisyLweCiphertext64 =
+    ///     engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    /// // The keyswitch adds some noise to the one of the input.
+    /// assert!(ciphertext_2.variance().0 > noise.0);
+    ///
+    /// core_engine.destroy(input_key)?;
+    /// core_engine.destroy(output_key)?;
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        input: &NoisyLweCiphertext64,
+        ksk: &NoisyLweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        let variance = estimate_keyswitch_noise_lwe_to_lwe::<u64, _, _, BinaryKeyKind>(
+            ksk.input_lwe_dimension(),
+            input.variance,
+            ksk.variance,
+            ksk.decomposition_base_log(),
+            ksk.decomposition_level_count(),
+        );
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine.discard_keyswitch_lwe_ciphertext_unchecked(
+                &mut output.ciphertext,
+                &input.ciphertext,
+                &ksk.key,
+            );
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        input: &NoisyLweCiphertext64,
+        ksk: &NoisyLweKeyswitchKey64,
+    ) {
+        self.core_engine.discard_keyswitch_lwe_ciphertext_unchecked(
+            &mut output.ciphertext,
+            &input.ciphertext,
+            &ksk.key,
+        );
+        output.variance = estimate_keyswitch_noise_lwe_to_lwe::<u64, _, _, BinaryKeyKind>(
+            ksk.input_lwe_dimension(),
+            input.variance,
+            ksk.variance,
+            ksk.decomposition_base_log(),
+            ksk.decomposition_level_count(),
+        );
+    }
+}
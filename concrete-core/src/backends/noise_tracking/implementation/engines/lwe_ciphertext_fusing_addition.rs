@@ -0,0 +1,167 @@
+use concrete_npe::estimate_addition_noise;
+
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyLweCiphertext32, NoisyLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextFusingAdditionEngine, LweCiphertextFusingAdditionError,
+};
+
+impl From<NoiseTrackingError> for LweCiphertextFusingAdditionError<NoiseTrackingError> {
+    fn from(err: NoiseTrackingError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextFusingAdditionEngine<NoisyLweCiphertext32, NoisyLweCiphertext32>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = 3_u32 << 20;
+    /// let input_2 = 7_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: LweSecretKey32 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = core_engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = core_engine.create_plaintext(&input_2)?;
+    /// let ciphertext: NoisyLweCiphertext32 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let mut ciphertext_2: NoisyLweCiphertext32 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext_2, &ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), lwe_dimension);
+    /// // The variances of the inputs add up.
+    /// assert_eq!(ciphertext_2.variance(), Variance(2_f64.powf(-24.)));
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_1)?;
+    /// core_engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        input: &NoisyLweCiphertext32,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        LweCiphertextFusingAdditionError::perform_generic_checks(output, input)?;
+        let variance = estimate_addition_noise::<u32, _, _>(output.variance, input.variance);
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine
+                .fuse_add_lwe_ciphertext_unchecked(&mut output.ciphertext, &input.ciphertext);
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        input: &NoisyLweCiphertext32,
+    ) {
+        self.core_engine
+            .fuse_add_lwe_ciphertext_unchecked(&mut output.ciphertext, &input.ciphertext);
+        output.variance = estimate_addition_noise::<u32, _, _>(output.variance, input.variance);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextFusingAdditionEngine<NoisyLweCiphertext64, NoisyLweCiphertext64>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = 3_u64 << 50;
+    /// let input_2 = 7_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = core_engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = core_engine.create_plaintext(&input_2)?;
+    /// let ciphertext: NoisyLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let mut ciphertext_2: NoisyLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext_2, &ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), lwe_dimension);
+    /// // The variances of the inputs add up.
+    /// assert_eq!(ciphertext_2.variance(), Variance(2_f64.powf(-24.)));
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_1)?;
+    /// core_engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        input: &NoisyLweCiphertext64,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        LweCiphertextFusingAdditionError::perform_generic_checks(output, input)?;
+        let variance = estimate_addition_noise::<u64, _, _>(output.variance, input.variance);
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine
+                .fuse_add_lwe_ciphertext_unchecked(&mut output.ciphertext, &input.ciphertext);
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        input: &NoisyLweCiphertext64,
+    ) {
+        self.core_engine
+            .fuse_add_lwe_ciphertext_unchecked(&mut output.ciphertext, &input.ciphertext);
+        output.variance = estimate_addition_noise::<u64, _, _>(output.variance, input.variance);
+    }
+}
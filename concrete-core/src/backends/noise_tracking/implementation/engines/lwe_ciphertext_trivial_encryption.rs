@@ -0,0 +1,131 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweSize;
+
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::backends::noise_tracking::implementation::engines::NoiseTrackingEngine;
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyLweCiphertext32, NoisyLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextTrivialEncryptionEngine, LweCiphertextTrivialEncryptionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextTrivialEncryptionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextTrivialEncryptionEngine<Plaintext32, NoisyLweCiphertext32>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    ///
+    /// // The trivial encryption does not add any noise.
+    /// let ciphertext: NoisyLweCiphertext32 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension().to_lwe_size(), lwe_size);
+    /// assert_eq!(ciphertext.variance(), Variance(0.));
+    ///
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext32,
+    ) -> Result<NoisyLweCiphertext32, LweCiphertextTrivialEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input) })
+    }
+
+    unsafe fn trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext32,
+    ) -> NoisyLweCiphertext32 {
+        NoisyLweCiphertext32 {
+            ciphertext: self
+                .core_engine
+                .trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input),
+            variance: Variance(0.),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextTrivialEncryptionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextTrivialEncryptionEngine<Plaintext64, NoisyLweCiphertext64>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    ///
+    /// // The trivial encryption does not add any noise.
+    /// let ciphertext: NoisyLweCiphertext64 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension().to_lwe_size(), lwe_size);
+    /// assert_eq!(ciphertext.variance(), Variance(0.));
+    ///
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext64,
+    ) -> Result<NoisyLweCiphertext64, LweCiphertextTrivialEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input) })
+    }
+
+    unsafe fn trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext64,
+    ) -> NoisyLweCiphertext64 {
+        NoisyLweCiphertext64 {
+            ciphertext: self
+                .core_engine
+                .trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input),
+            variance: Variance(0.),
+        }
+    }
+}
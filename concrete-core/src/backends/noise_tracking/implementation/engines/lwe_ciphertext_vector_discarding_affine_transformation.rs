@@ -0,0 +1,248 @@
+use concrete_npe::estimate_weighted_sum_noise;
+
+use crate::backends::core::entities::{
+    CleartextVector32, CleartextVector64, Plaintext32, Plaintext64,
+};
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyLweCiphertext32, NoisyLweCiphertext64, NoisyLweCiphertextVector32,
+    NoisyLweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingAffineTransformationEngine,
+    LweCiphertextVectorDiscardingAffineTransformationError,
+};
+
+impl From<NoiseTrackingError>
+    for LweCiphertextVectorDiscardingAffineTransformationError<NoiseTrackingError>
+{
+    fn from(err: NoiseTrackingError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationEngine`] for
+/// [`NoiseTrackingEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextVectorDiscardingAffineTransformationEngine<
+        NoisyLweCiphertextVector32,
+        CleartextVector32,
+        Plaintext32,
+        NoisyLweCiphertext32,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_vector = vec![3_u32 << 20; 3];
+    /// let weights_input = vec![1_u32, 2, 3];
+    /// let bias_input = 8_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: LweSecretKey32 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let weights: CleartextVector32 = core_engine.create_cleartext_vector(&weights_input)?;
+    /// let bias: Plaintext32 = core_engine.create_plaintext(&bias_input)?;
+    /// let plaintext_vector: PlaintextVector32 =
+    ///     core_engine.create_plaintext_vector(&input_vector)?;
+    /// let ciphertext_vector: NoisyLweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext: NoisyLweCiphertext32 =
+    ///     engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_affine_transform_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &weights,
+    ///     &bias,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), lwe_dimension);
+    /// // The variances of the inputs are weighted by the squares of the weights.
+    /// assert_eq!(
+    ///     output_ciphertext.variance(),
+    ///     Variance(14. * 2_f64.powf(-25.))
+    /// );
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(weights)?;
+    /// core_engine.destroy(bias)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_affine_transform_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        inputs: &NoisyLweCiphertextVector32,
+        weights: &CleartextVector32,
+        bias: &Plaintext32,
+    ) -> Result<(), LweCiphertextVectorDiscardingAffineTransformationError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingAffineTransformationError::perform_generic_checks(
+            output, inputs, weights,
+        )?;
+        // The bias is a plaintext, and does not add any noise.
+        let weights_values: Vec<u32> = weights.0.cleartext_iter().map(|w| w.0).collect();
+        let variance = estimate_weighted_sum_noise(&inputs.variances, &weights_values);
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine
+                .discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                    &mut output.ciphertext,
+                    &inputs.ciphertexts,
+                    weights,
+                    bias,
+                );
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn discard_affine_transform_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext32,
+        inputs: &NoisyLweCiphertextVector32,
+        weights: &CleartextVector32,
+        bias: &Plaintext32,
+    ) {
+        self.core_engine
+            .discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                &mut output.ciphertext,
+                &inputs.ciphertexts,
+                weights,
+                bias,
+            );
+        // The bias is a plaintext, and does not add any noise.
+        let weights_values: Vec<u32> = weights.0.cleartext_iter().map(|w| w.0).collect();
+        output.variance = estimate_weighted_sum_noise(&inputs.variances, &weights_values);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationEngine`] for
+/// [`NoiseTrackingEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingAffineTransformationEngine<
+        NoisyLweCiphertextVector64,
+        CleartextVector64,
+        Plaintext64,
+        NoisyLweCiphertext64,
+    > for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 3];
+    /// let weights_input = vec![1_u64, 2, 3];
+    /// let bias_input = 8_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let weights: CleartextVector64 = core_engine.create_cleartext_vector(&weights_input)?;
+    /// let bias: Plaintext64 = core_engine.create_plaintext(&bias_input)?;
+    /// let plaintext_vector: PlaintextVector64 =
+    ///     core_engine.create_plaintext_vector(&input_vector)?;
+    /// let ciphertext_vector: NoisyLweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext: NoisyLweCiphertext64 =
+    ///     engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_affine_transform_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &weights,
+    ///     &bias,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), lwe_dimension);
+    /// // The variances of the inputs are weighted by the squares of the weights.
+    /// assert_eq!(
+    ///     output_ciphertext.variance(),
+    ///     Variance(14. * 2_f64.powf(-25.))
+    /// );
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(weights)?;
+    /// core_engine.destroy(bias)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_affine_transform_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        inputs: &NoisyLweCiphertextVector64,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) -> Result<(), LweCiphertextVectorDiscardingAffineTransformationError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingAffineTransformationError::perform_generic_checks(
+            output, inputs, weights,
+        )?;
+        // The bias is a plaintext, and does not add any noise.
+        let weights_values: Vec<u64> = weights.0.cleartext_iter().map(|w| w.0).collect();
+        let variance = estimate_weighted_sum_noise(&inputs.variances, &weights_values);
+        self.check_noise_budget(variance)?;
+        unsafe {
+            self.core_engine
+                .discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                    &mut output.ciphertext,
+                    &inputs.ciphertexts,
+                    weights,
+                    bias,
+                );
+            output.variance = variance;
+        }
+        Ok(())
+    }
+
+    unsafe fn discard_affine_transform_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertext64,
+        inputs: &NoisyLweCiphertextVector64,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) {
+        self.core_engine
+            .discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                &mut output.ciphertext,
+                &inputs.ciphertexts,
+                weights,
+                bias,
+            );
+        // The bias is a plaintext, and does not add any noise.
+        let weights_values: Vec<u64> = weights.0.cleartext_iter().map(|w| w.0).collect();
+        output.variance = estimate_weighted_sum_noise(&inputs.variances, &weights_values);
+    }
+}
@@ -0,0 +1,196 @@
+use concrete_commons::dispersion::Variance;
+use concrete_npe::estimate_addition_noise;
+
+use crate::backends::noise_tracking::implementation::engines::{
+    NoiseTrackingEngine, NoiseTrackingError,
+};
+use crate::backends::noise_tracking::implementation::entities::{
+    NoisyLweCiphertextVector32, NoisyLweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorFusingAdditionEngine, LweCiphertextVectorFusingAdditionError,
+};
+
+impl From<NoiseTrackingError> for LweCiphertextVectorFusingAdditionError<NoiseTrackingError> {
+    fn from(err: NoiseTrackingError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorFusingAdditionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextVectorFusingAdditionEngine<NoisyLweCiphertextVector32, NoisyLweCiphertextVector32>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_vector = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: LweSecretKey32 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input_vector)?;
+    /// let ciphertext_vector: NoisyLweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector: NoisyLweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext_vector(&mut output_ciphertext_vector, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// // The variances of the inputs add up.
+    /// assert_eq!(
+    ///     output_ciphertext_vector.variances(),
+    ///     &[Variance(2_f64.powf(-24.)); 8]
+    /// );
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut NoisyLweCiphertextVector32,
+        input: &NoisyLweCiphertextVector32,
+    ) -> Result<(), LweCiphertextVectorFusingAdditionError<Self::EngineError>> {
+        LweCiphertextVectorFusingAdditionError::perform_generic_checks(output, input)?;
+        let variances: Vec<Variance> = output
+            .variances
+            .iter()
+            .zip(input.variances.iter())
+            .map(|(v1, v2)| estimate_addition_noise::<u32, _, _>(*v1, *v2))
+            .collect();
+        for variance in variances.iter() {
+            self.check_noise_budget(*variance)?;
+        }
+        unsafe {
+            self.core_engine.fuse_add_lwe_ciphertext_vector_unchecked(
+                &mut output.ciphertexts,
+                &input.ciphertexts,
+            );
+        }
+        output.variances = variances;
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertextVector32,
+        input: &NoisyLweCiphertextVector32,
+    ) {
+        self.core_engine
+            .fuse_add_lwe_ciphertext_vector_unchecked(&mut output.ciphertexts, &input.ciphertexts);
+        for (output_variance, input_variance) in
+            output.variances.iter_mut().zip(input.variances.iter())
+        {
+            *output_variance =
+                estimate_addition_noise::<u32, _, _>(*output_variance, *input_variance);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorFusingAdditionEngine`] for [`NoiseTrackingEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextVectorFusingAdditionEngine<NoisyLweCiphertextVector64, NoisyLweCiphertextVector64>
+    for NoiseTrackingEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new(())?;
+    /// let mut engine = NoiseTrackingEngine::new(NoiseTrackingParameters {
+    ///     noise_budget: Variance(2_f64.powf(-20.)),
+    /// })?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input_vector)?;
+    /// let ciphertext_vector: NoisyLweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector: NoisyLweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext_vector(&mut output_ciphertext_vector, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// // The variances of the inputs add up.
+    /// assert_eq!(
+    ///     output_ciphertext_vector.variances(),
+    ///     &[Variance(2_f64.powf(-24.)); 8]
+    /// );
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut NoisyLweCiphertextVector64,
+        input: &NoisyLweCiphertextVector64,
+    ) -> Result<(), LweCiphertextVectorFusingAdditionError<Self::EngineError>> {
+        LweCiphertextVectorFusingAdditionError::perform_generic_checks(output, input)?;
+        let variances: Vec<Variance> = output
+            .variances
+            .iter()
+            .zip(input.variances.iter())
+            .map(|(v1, v2)| estimate_addition_noise::<u64, _, _>(*v1, *v2))
+            .collect();
+        for variance in variances.iter() {
+            self.check_noise_budget(*variance)?;
+        }
+        unsafe {
+            self.core_engine.fuse_add_lwe_ciphertext_vector_unchecked(
+                &mut output.ciphertexts,
+                &input.ciphertexts,
+            );
+        }
+        output.variances = variances;
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut NoisyLweCiphertextVector64,
+        input: &NoisyLweCiphertextVector64,
+    ) {
+        self.core_engine
+            .fuse_add_lwe_ciphertext_vector_unchecked(&mut output.ciphertexts, &input.ciphertexts);
+        for (output_variance, input_variance) in
+            output.variances.iter_mut().zip(input.variances.iter())
+        {
+            *output_variance =
+                estimate_addition_noise::<u64, _, _>(*output_variance, *input_variance);
+        }
+    }
+}
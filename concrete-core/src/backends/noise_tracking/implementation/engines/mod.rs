@@ -0,0 +1,121 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the noise tracking
+//! backend.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use concrete_commons::dispersion::Variance;
+
+use crate::backends::core::engines::{CoreEngine, CoreError};
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+
+/// The error which can occur in the execution of FHE operations, due to the noise tracking
+/// implementation.
+#[derive(Debug)]
+pub enum NoiseTrackingError {
+    Core(CoreError),
+    NoiseBudgetExceeded {
+        variance: Variance,
+        budget: Variance,
+    },
+}
+
+impl Display for NoiseTrackingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NoiseTrackingError::Core(error) => {
+                write!(f, "Error occurred in the core engine: {}", error)
+            }
+            NoiseTrackingError::NoiseBudgetExceeded { variance, budget } => {
+                write!(
+                    f,
+                    "The estimated variance of the output ({:e}) exceeds the noise budget ({:e}).",
+                    variance.0, budget.0
+                )
+            }
+        }
+    }
+}
+
+impl Error for NoiseTrackingError {}
+
+/// The parameters of the [`NoiseTrackingEngine`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseTrackingParameters {
+    /// The largest variance a ciphertext produced by the engine is allowed to have.
+    pub noise_budget: Variance,
+}
+
+/// The main engine exposed by the noise tracking backend.
+///
+/// This engine performs the operations with a [`CoreEngine`], and attaches to every LWE and GLWE
+/// ciphertext it produces the variance of its noise, as estimated by `concrete-npe`. The safe
+/// entry point of an operation fails with a [`NoiseTrackingError::NoiseBudgetExceeded`] error,
+/// without touching its outputs, if the estimated variance of the output exceeds the noise budget
+/// given in the [`NoiseTrackingParameters`]. The bootstrap also checks the variance of its input
+/// after the modulus switching. The unsafe entry points do not check the noise budget.
+pub struct NoiseTrackingEngine {
+    core_engine: CoreEngine,
+    noise_budget: Variance,
+}
+
+impl NoiseTrackingEngine {
+    /// Returns the noise budget of the engine.
+    pub fn noise_budget(&self) -> Variance {
+        self.noise_budget
+    }
+
+    pub(crate) fn check_noise_budget(&self, variance: Variance) -> Result<(), NoiseTrackingError> {
+        if variance.0 > self.noise_budget.0 {
+            return Err(NoiseTrackingError::NoiseBudgetExceeded {
+                variance,
+                budget: self.noise_budget,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl AbstractEngineSeal for NoiseTrackingEngine {}
+
+impl AbstractEngine for NoiseTrackingEngine {
+    type EngineError = NoiseTrackingError;
+
+    type Parameters = NoiseTrackingParameters;
+
+    fn new(parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
+        Ok(NoiseTrackingEngine {
+            core_engine: CoreEngine::new(()).map_err(NoiseTrackingError::Core)?,
+            noise_budget: parameters.noise_budget,
+        })
+    }
+}
+
+mod destruction;
+mod ggsw_ciphertext_scalar_encryption;
+mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_trivial_encryption;
+mod glwe_ciphertext_vector_decryption;
+mod glwe_ciphertext_vector_encryption;
+mod glwe_ciphertext_vector_zero_encryption;
+mod lwe_bootstrap_key_creation;
+mod lwe_ciphertext_cleartext_discarding_multiplication;
+mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_decryption;
+mod lwe_ciphertext_discarding_addition;
+mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_encryption;
+mod lwe_ciphertext_fusing_addition;
+mod lwe_ciphertext_trivial_encryption;
+mod lwe_ciphertext_vector_decryption;
+mod lwe_ciphertext_vector_discarding_addition;
+mod lwe_ciphertext_vector_discarding_affine_transformation;
+mod lwe_ciphertext_vector_encryption;
+mod lwe_ciphertext_vector_fusing_addition;
+mod lwe_ciphertext_zero_encryption;
+mod lwe_keyswitch_key_creation;
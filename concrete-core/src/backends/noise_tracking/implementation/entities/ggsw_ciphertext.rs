@@ -0,0 +1,101 @@
+use crate::backends::core::entities::{FourierGgswCiphertext32, FourierGgswCiphertext64};
+use crate::specification::entities::markers::{BinaryKeyDistribution, GgswCiphertextKind};
+use crate::specification::entities::{AbstractEntity, GgswCiphertextEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A structure representing a GGSW ciphertext with 32 bits of precision in the fourier domain,
+/// along with the variance of the noise it was encrypted with, and the square of the message it
+/// encrypts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyFourierGgswCiphertext32 {
+    pub(crate) ciphertext: FourierGgswCiphertext32,
+    pub(crate) variance: Variance,
+    pub(crate) message_squared: f64,
+}
+
+impl NoisyFourierGgswCiphertext32 {
+    /// Returns the variance of the noise the ciphertext was encrypted with.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+
+    /// Returns the square of the message the ciphertext encrypts, interpreted as a signed
+    /// integer.
+    pub fn message_squared(&self) -> f64 {
+        self.message_squared
+    }
+}
+
+impl AbstractEntity for NoisyFourierGgswCiphertext32 {
+    type Kind = GgswCiphertextKind;
+}
+
+impl GgswCiphertextEntity for NoisyFourierGgswCiphertext32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.ciphertext.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.ciphertext.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.ciphertext.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.ciphertext.decomposition_base_log()
+    }
+}
+
+/// A structure representing a GGSW ciphertext with 64 bits of precision in the fourier domain,
+/// along with the variance of the noise it was encrypted with, and the square of the message it
+/// encrypts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyFourierGgswCiphertext64 {
+    pub(crate) ciphertext: FourierGgswCiphertext64,
+    pub(crate) variance: Variance,
+    pub(crate) message_squared: f64,
+}
+
+impl NoisyFourierGgswCiphertext64 {
+    /// Returns the variance of the noise the ciphertext was encrypted with.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+
+    /// Returns the square of the message the ciphertext encrypts, interpreted as a signed
+    /// integer.
+    pub fn message_squared(&self) -> f64 {
+        self.message_squared
+    }
+}
+
+impl AbstractEntity for NoisyFourierGgswCiphertext64 {
+    type Kind = GgswCiphertextKind;
+}
+
+impl GgswCiphertextEntity for NoisyFourierGgswCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.ciphertext.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.ciphertext.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.ciphertext.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.ciphertext.decomposition_base_log()
+    }
+}
@@ -0,0 +1,67 @@
+use crate::backends::core::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::specification::entities::markers::{BinaryKeyDistribution, GlweCiphertextKind};
+use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a GLWE ciphertext with 32 bits of precision, along with the estimated
+/// variance of the noise of its coefficients.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyGlweCiphertext32 {
+    pub(crate) ciphertext: GlweCiphertext32,
+    pub(crate) variance: Variance,
+}
+
+impl NoisyGlweCiphertext32 {
+    /// Returns the estimated variance of the noise of the coefficients of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+}
+
+impl AbstractEntity for NoisyGlweCiphertext32 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for NoisyGlweCiphertext32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.ciphertext.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.ciphertext.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 64 bits of precision, along with the estimated
+/// variance of the noise of its coefficients.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyGlweCiphertext64 {
+    pub(crate) ciphertext: GlweCiphertext64,
+    pub(crate) variance: Variance,
+}
+
+impl NoisyGlweCiphertext64 {
+    /// Returns the estimated variance of the noise of the coefficients of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+}
+
+impl AbstractEntity for NoisyGlweCiphertext64 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for NoisyGlweCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.ciphertext.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.ciphertext.polynomial_size()
+    }
+}
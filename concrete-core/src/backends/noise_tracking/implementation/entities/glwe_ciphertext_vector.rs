@@ -0,0 +1,75 @@
+use crate::backends::core::entities::{GlweCiphertextVector32, GlweCiphertextVector64};
+use crate::specification::entities::markers::{BinaryKeyDistribution, GlweCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, GlweCiphertextVectorEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+
+/// A structure representing a vector of GLWE ciphertexts with 32 bits of precision, along with the
+/// estimated variances of the noises of their coefficients.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyGlweCiphertextVector32 {
+    pub(crate) ciphertexts: GlweCiphertextVector32,
+    pub(crate) variances: Vec<Variance>,
+}
+
+impl NoisyGlweCiphertextVector32 {
+    /// Returns the estimated variances of the noises of the coefficients of the ciphertexts.
+    pub fn variances(&self) -> &[Variance] {
+        &self.variances
+    }
+}
+
+impl AbstractEntity for NoisyGlweCiphertextVector32 {
+    type Kind = GlweCiphertextVectorKind;
+}
+
+impl GlweCiphertextVectorEntity for NoisyGlweCiphertextVector32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.ciphertexts.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.ciphertexts.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        self.ciphertexts.glwe_ciphertext_count()
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertexts with 64 bits of precision, along with the
+/// estimated variances of the noises of their coefficients.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyGlweCiphertextVector64 {
+    pub(crate) ciphertexts: GlweCiphertextVector64,
+    pub(crate) variances: Vec<Variance>,
+}
+
+impl NoisyGlweCiphertextVector64 {
+    /// Returns the estimated variances of the noises of the coefficients of the ciphertexts.
+    pub fn variances(&self) -> &[Variance] {
+        &self.variances
+    }
+}
+
+impl AbstractEntity for NoisyGlweCiphertextVector64 {
+    type Kind = GlweCiphertextVectorKind;
+}
+
+impl GlweCiphertextVectorEntity for NoisyGlweCiphertextVector64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.ciphertexts.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.ciphertexts.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        self.ciphertexts.glwe_ciphertext_count()
+    }
+}
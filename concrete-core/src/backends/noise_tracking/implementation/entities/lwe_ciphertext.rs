@@ -0,0 +1,59 @@
+use crate::backends::core::entities::{LweCiphertext32, LweCiphertext64};
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing an LWE ciphertext with 32 bits of precision, along with the estimated
+/// variance of its noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyLweCiphertext32 {
+    pub(crate) ciphertext: LweCiphertext32,
+    pub(crate) variance: Variance,
+}
+
+impl NoisyLweCiphertext32 {
+    /// Returns the estimated variance of the noise of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+}
+
+impl AbstractEntity for NoisyLweCiphertext32 {
+    type Kind = LweCiphertextKind;
+}
+
+impl LweCiphertextEntity for NoisyLweCiphertext32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.ciphertext.lwe_dimension()
+    }
+}
+
+/// A structure representing an LWE ciphertext with 64 bits of precision, along with the estimated
+/// variance of its noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyLweCiphertext64 {
+    pub(crate) ciphertext: LweCiphertext64,
+    pub(crate) variance: Variance,
+}
+
+impl NoisyLweCiphertext64 {
+    /// Returns the estimated variance of the noise of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+}
+
+impl AbstractEntity for NoisyLweCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+
+impl LweCiphertextEntity for NoisyLweCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.ciphertext.lwe_dimension()
+    }
+}
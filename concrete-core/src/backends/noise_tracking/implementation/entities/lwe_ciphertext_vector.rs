@@ -0,0 +1,67 @@
+use crate::backends::core::entities::{LweCiphertextVector32, LweCiphertextVector64};
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextVectorEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+
+/// A structure representing a vector of LWE ciphertexts with 32 bits of precision, along with the
+/// estimated variances of their noises.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyLweCiphertextVector32 {
+    pub(crate) ciphertexts: LweCiphertextVector32,
+    pub(crate) variances: Vec<Variance>,
+}
+
+impl NoisyLweCiphertextVector32 {
+    /// Returns the estimated variances of the noises of the ciphertexts.
+    pub fn variances(&self) -> &[Variance] {
+        &self.variances
+    }
+}
+
+impl AbstractEntity for NoisyLweCiphertextVector32 {
+    type Kind = LweCiphertextVectorKind;
+}
+
+impl LweCiphertextVectorEntity for NoisyLweCiphertextVector32 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.ciphertexts.lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        self.ciphertexts.lwe_ciphertext_count()
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 64 bits of precision, along with the
+/// estimated variances of their noises.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyLweCiphertextVector64 {
+    pub(crate) ciphertexts: LweCiphertextVector64,
+    pub(crate) variances: Vec<Variance>,
+}
+
+impl NoisyLweCiphertextVector64 {
+    /// Returns the estimated variances of the noises of the ciphertexts.
+    pub fn variances(&self) -> &[Variance] {
+        &self.variances
+    }
+}
+
+impl AbstractEntity for NoisyLweCiphertextVector64 {
+    type Kind = LweCiphertextVectorKind;
+}
+
+impl LweCiphertextVectorEntity for NoisyLweCiphertextVector64 {
+    type KeyDistribution = BinaryKeyDistribution;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.ciphertexts.lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        self.ciphertexts.lwe_ciphertext_count()
+    }
+}
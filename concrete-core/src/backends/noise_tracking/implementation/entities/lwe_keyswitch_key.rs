@@ -0,0 +1,85 @@
+use crate::backends::core::entities::{LweKeyswitchKey32, LweKeyswitchKey64};
+use crate::specification::entities::markers::{BinaryKeyDistribution, LweKeyswitchKeyKind};
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A structure representing an LWE keyswitch key with 32 bits of precision, along with the
+/// variance of the noise it was encrypted with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyLweKeyswitchKey32 {
+    pub(crate) key: LweKeyswitchKey32,
+    pub(crate) variance: Variance,
+}
+
+impl NoisyLweKeyswitchKey32 {
+    /// Returns the variance of the noise the key was encrypted with.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+}
+
+impl AbstractEntity for NoisyLweKeyswitchKey32 {
+    type Kind = LweKeyswitchKeyKind;
+}
+
+impl LweKeyswitchKeyEntity for NoisyLweKeyswitchKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.key.input_lwe_dimension()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.key.output_lwe_dimension()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.key.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.key.decomposition_base_log()
+    }
+}
+
+/// A structure representing an LWE keyswitch key with 64 bits of precision, along with the
+/// variance of the noise it was encrypted with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyLweKeyswitchKey64 {
+    pub(crate) key: LweKeyswitchKey64,
+    pub(crate) variance: Variance,
+}
+
+impl NoisyLweKeyswitchKey64 {
+    /// Returns the variance of the noise the key was encrypted with.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+}
+
+impl AbstractEntity for NoisyLweKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+
+impl LweKeyswitchKeyEntity for NoisyLweKeyswitchKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.key.input_lwe_dimension()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.key.output_lwe_dimension()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.key.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.key.decomposition_base_log()
+    }
+}
@@ -0,0 +1,18 @@
+//! A module containing all the [entities](crate::specification::entities) exposed by the noise
+//! tracking backend.
+
+mod ggsw_ciphertext;
+mod glwe_ciphertext;
+mod glwe_ciphertext_vector;
+mod lwe_bootstrap_key;
+mod lwe_ciphertext;
+mod lwe_ciphertext_vector;
+mod lwe_keyswitch_key;
+
+pub use ggsw_ciphertext::*;
+pub use glwe_ciphertext::*;
+pub use glwe_ciphertext_vector::*;
+pub use lwe_bootstrap_key::*;
+pub use lwe_ciphertext::*;
+pub use lwe_ciphertext_vector::*;
+pub use lwe_keyswitch_key::*;
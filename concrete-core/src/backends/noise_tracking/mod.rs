@@ -0,0 +1,15 @@
+//! A module containing the noise tracking backend implementation.
+//!
+//! This module contains an engine wrapping the engine of the [`core`](crate::backends::core)
+//! backend. Every LWE and GLWE ciphertext produced by this engine carries the variance of its
+//! noise, as estimated by the formulas of `concrete-npe`. This variance is propagated through the
+//! operations, and the engine returns an error whenever an operation would produce a ciphertext
+//! whose variance exceeds a configured noise budget.
+//!
+//! The noiseless entities (secret keys, plaintexts, cleartexts, ...) are the ones of the `core`
+//! backend, and are meant to be created with a
+//! [`CoreEngine`](crate::backends::core::engines::CoreEngine).
+
+mod implementation;
+
+pub use implementation::{engines, entities};
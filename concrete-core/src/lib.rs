@@ -32,7 +32,8 @@
 //!
 //! The different backends can be activated using the feature flags `backend_*`. The `backend_core`
 //! contains an engine executing operations on a single thread of the cpu. It is activated by
-//! default.
+//! default. The `backend_noise_tracking` contains an engine wrapping the core one, which attaches
+//! to every ciphertext the variance of its noise, as estimated by `concrete-npe`.
 //!
 //! # Navigating the code
 //!
@@ -1,11 +1,34 @@
-use super::engine_error;
+use super::{check_decomposition_parameters, engine_error};
 use crate::specification::engines::AbstractEngine;
 use crate::specification::entities::{GgswCiphertextEntity, GlweSecretKeyEntity, PlaintextEntity};
 use concrete_commons::dispersion::Variance;
-use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DecompositionParameters,
+};
 
 engine_error! {
     GgswCiphertextScalarEncryptionError for GgswCiphertextScalarEncryptionEngine @
+    NullDecompositionBaseLog => "The decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> GgswCiphertextScalarEncryptionError<EngineError> {
+    /// Validates the inputs, returning the validated decomposition parameters.
+    pub fn perform_generic_checks<Scalar: UnsignedInteger>(
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<DecompositionParameters, Self> {
+        check_decomposition_parameters::<Scalar, _>(
+            decomposition_base_log,
+            decomposition_level_count,
+            Self::NullDecompositionBaseLog,
+            Self::NullDecompositionLevelCount,
+            Self::DecompositionTooLarge,
+        )
+    }
 }
 
 /// A trait for engines encrypting GGSW ciphertexts containing a single plaintext.
@@ -1,12 +1,34 @@
+use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    DecompositionBaseLog, DecompositionLevelCount, DecompositionParameters, GlweSize,
+    PolynomialSize,
 };
 
-use crate::specification::engines::{engine_error, AbstractEngine};
+use crate::specification::engines::{check_decomposition_parameters, engine_error, AbstractEngine};
 use crate::specification::entities::{GgswCiphertextEntity, PlaintextEntity};
 
 engine_error! {
     GgswCiphertextScalarTrivialEncryptionError for GgswCiphertextScalarTrivialEncryptionEngine @
+    NullDecompositionBaseLog => "The decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> GgswCiphertextScalarTrivialEncryptionError<EngineError> {
+    /// Validates the inputs, returning the validated decomposition parameters.
+    pub fn perform_generic_checks<Scalar: UnsignedInteger>(
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<DecompositionParameters, Self> {
+        check_decomposition_parameters::<Scalar, _>(
+            decomposition_base_log,
+            decomposition_level_count,
+            Self::NullDecompositionBaseLog,
+            Self::NullDecompositionLevelCount,
+            Self::DecompositionTooLarge,
+        )
+    }
 }
 
 /// A trait for engines trivially encrypting GGSW ciphertext containing a single plaintext.
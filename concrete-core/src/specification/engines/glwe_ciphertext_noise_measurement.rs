@@ -0,0 +1,80 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweCiphertextEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    GlweCiphertextNoiseMeasurementError for GlweCiphertextNoiseMeasurementEngine @
+    GlweDimensionMismatch => "The GLWE dimension of the key and the input ciphertext must be the \
+                              same.",
+    PolynomialSizeMismatch => "The polynomial size of the key and the input ciphertext must be \
+                               the same.",
+    PlaintextCountMismatch => "The expected plaintext vector length and the input ciphertext \
+                               polynomial size must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextNoiseMeasurementError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, Ciphertext, PlaintextVector>(
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        SecretKey: GlweSecretKeyEntity,
+        Ciphertext: GlweCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+        PlaintextVector: PlaintextVectorEntity,
+    {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if input.polynomial_size().0 != expected.plaintext_count().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines measuring the noise of GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation decrypts the `input` GLWE ciphertext under
+/// the `key` secret key, and returns the coefficient-wise differences between the decrypted
+/// plaintext vector and the `expected` plaintext vector, centered around zero. The `Noise` type
+/// can be any type that suits the backend implementor to represent those signed differences (a
+/// vector of signed integers, a vector of torus values as floats, or any other thing).
+///
+/// # Formal Definition
+pub trait GlweCiphertextNoiseMeasurementEngine<SecretKey, Ciphertext, PlaintextVector, Noise>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    Ciphertext: GlweCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Measures the noise of a GLWE ciphertext.
+    fn measure_glwe_ciphertext_noise(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &PlaintextVector,
+    ) -> Result<Noise, GlweCiphertextNoiseMeasurementError<Self::EngineError>>;
+
+    /// Unsafely measures the noise of a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextNoiseMeasurementError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn measure_glwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &PlaintextVector,
+    ) -> Noise;
+}
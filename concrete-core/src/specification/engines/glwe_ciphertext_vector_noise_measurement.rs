@@ -0,0 +1,85 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    GlweCiphertextVectorNoiseMeasurementError for GlweCiphertextVectorNoiseMeasurementEngine @
+    GlweDimensionMismatch => "The GLWE dimension of the key and the input ciphertext vector must \
+                              be the same.",
+    PolynomialSizeMismatch => "The polynomial size of the key and the input ciphertext vector \
+                               must be the same.",
+    PlaintextCountMismatch => "The expected plaintext vector length and the input ciphertext \
+                               vector capacity (poly size * length) must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextVectorNoiseMeasurementError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, CiphertextVector, PlaintextVector>(
+        key: &SecretKey,
+        input: &CiphertextVector,
+        expected: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        SecretKey: GlweSecretKeyEntity,
+        CiphertextVector: GlweCiphertextVectorEntity<KeyDistribution = SecretKey::KeyDistribution>,
+        PlaintextVector: PlaintextVectorEntity,
+    {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if expected.plaintext_count().0
+            != (input.polynomial_size().0 * input.glwe_ciphertext_count().0)
+        {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines measuring the noise of GLWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation decrypts the `input` GLWE ciphertext vector
+/// under the `key` secret key, and returns the coefficient-wise differences between the decrypted
+/// plaintext vector and the `expected` plaintext vector, centered around zero. The `Noise` type
+/// can be any type that suits the backend implementor to represent those signed differences (a
+/// vector of signed integers, a vector of torus values as floats, or any other thing).
+///
+/// # Formal Definition
+pub trait GlweCiphertextVectorNoiseMeasurementEngine<
+    SecretKey,
+    CiphertextVector,
+    PlaintextVector,
+    Noise,
+>: AbstractEngine where
+    SecretKey: GlweSecretKeyEntity,
+    CiphertextVector: GlweCiphertextVectorEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Measures the noise of a GLWE ciphertext vector.
+    fn measure_glwe_ciphertext_vector_noise(
+        &mut self,
+        key: &SecretKey,
+        input: &CiphertextVector,
+        expected: &PlaintextVector,
+    ) -> Result<Noise, GlweCiphertextVectorNoiseMeasurementError<Self::EngineError>>;
+
+    /// Unsafely measures the noise of a GLWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextVectorNoiseMeasurementError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn measure_glwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &CiphertextVector,
+        expected: &PlaintextVector,
+    ) -> Noise;
+}
@@ -0,0 +1,38 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweSecretKeyEntity;
+
+engine_error! {
+    GlweSecretKeyConsumingRetrievalError for GlweSecretKeyConsumingRetrievalEngine @
+}
+
+/// A trait for engines retrieving the content of the container from a GLWE secret key consuming it
+/// in the process.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation retrieves the content of the container from the
+/// `input` GLWE secret key consuming it in the process. This makes it possible to export the
+/// coefficients of a key.
+pub trait GlweSecretKeyConsumingRetrievalEngine<SecretKey, Container>: AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+{
+    /// Retrieves the content of the container from a GLWE secret key, consuming it in the process.
+    fn consume_retrieve_glwe_secret_key(
+        &mut self,
+        secret_key: SecretKey,
+    ) -> Result<Container, GlweSecretKeyConsumingRetrievalError<Self::EngineError>>;
+
+    /// Unsafely retrieves the content of the container from a GLWE secret key, consuming it in the
+    /// process.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSecretKeyConsumingRetrievalError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn consume_retrieve_glwe_secret_key_unchecked(
+        &mut self,
+        secret_key: SecretKey,
+    ) -> Container;
+}
@@ -0,0 +1,73 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::parameters::{GlweDimension, KeyDerivationLabel, MasterSeed, PolynomialSize};
+
+engine_error! {
+    GlweSecretKeyDerivationError for GlweSecretKeyDerivationEngine @
+    NullGlweDimension => "The secret key GLWE dimension must be greater than zero.",
+    NullPolynomialSize => "The secret key polynomial size must be greater than zero.",
+    SizeOnePolynomial => "The secret key polynomial size must be greater than one. Otherwise you \
+                          should prefer the LWE scheme."
+}
+
+impl<EngineError: std::error::Error> GlweSecretKeyDerivationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<(), Self> {
+        if glwe_dimension.0 == 0 {
+            return Err(Self::NullGlweDimension);
+        }
+
+        if polynomial_size.0 == 0 {
+            return Err(Self::NullPolynomialSize);
+        }
+
+        if polynomial_size.0 == 1 {
+            return Err(Self::SizeOnePolynomial);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines deriving GLWE secret keys from a master seed.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates a GLWE secret key deterministically
+/// derived from the `master_seed` master seed and the `label` label. Deriving a key twice with the
+/// same master seed and label outputs the same key, while keys derived with different labels are
+/// independent. The key is also independent of any other kind of key derived with the same label,
+/// e.g. of the LWE secret key.
+///
+/// # Formal Definition
+pub trait GlweSecretKeyDerivationEngine<SecretKey>: AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+{
+    /// Derives a GLWE secret key.
+    fn derive_glwe_secret_key(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<SecretKey, GlweSecretKeyDerivationError<Self::EngineError>>;
+
+    /// Unsafely derives a GLWE secret key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSecretKeyDerivationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn derive_glwe_secret_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> SecretKey;
+}
@@ -0,0 +1,79 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::PolynomialSize;
+
+engine_error! {
+    GlweSecretKeyFromContainerCreationError for GlweSecretKeyFromContainerCreationEngine @
+    EmptyContainer => "The container used to create the GLWE secret key is of length 0!",
+    InvalidContainerSize => "The length of the container used to create the GLWE secret key \
+    needs to be a multiple of `polynomial_size`.",
+    InvalidKeyValue => "The container used to create the GLWE secret key contains values which do \
+    not belong to the key distribution."
+}
+
+impl<EngineError: std::error::Error> GlweSecretKeyFromContainerCreationError<EngineError> {
+    /// Validates the inputs, the container is expected to have a length of
+    /// glwe_dimension * polynomial_size, during construction we only get the container and the
+    /// polynomial size so we check the length is consistent, the GLWE dimension is deduced by the
+    /// key implementation from the container and the polynomial size.
+    pub fn perform_generic_checks(
+        container_length: usize,
+        polynomial_size: PolynomialSize,
+    ) -> Result<(), Self> {
+        if container_length == 0 {
+            return Err(Self::EmptyContainer);
+        }
+        if container_length % polynomial_size.0 != 0 {
+            return Err(Self::InvalidContainerSize);
+        }
+        Ok(())
+    }
+
+    /// Validates that all the values of the container belong to the binary key distribution.
+    pub fn perform_binary_key_checks<Scalar>(container: &[Scalar]) -> Result<(), Self>
+    where
+        Scalar: UnsignedInteger,
+    {
+        if container
+            .iter()
+            .any(|value| *value != Scalar::ZERO && *value != Scalar::ONE)
+        {
+            return Err(Self::InvalidKeyValue);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating GLWE secret keys from an arbitrary container.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates a GLWE secret key from the arbitrary
+/// `container`, whose values are used as the coefficients of the key polynomials. Contrary to
+/// [`GlweSecretKeyCreationEngine`](`super::GlweSecretKeyCreationEngine`), no randomness is
+/// sampled: this makes it possible to import keys generated outside of the engine.
+pub trait GlweSecretKeyFromContainerCreationEngine<Container, SecretKey>: AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+{
+    /// Creates a GLWE secret key from an arbitrary container.
+    fn create_glwe_secret_key_from_container(
+        &mut self,
+        container: Container,
+        polynomial_size: PolynomialSize,
+    ) -> Result<SecretKey, GlweSecretKeyFromContainerCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a GLWE secret key from an arbitrary container.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSecretKeyFromContainerCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_glwe_secret_key_from_container_unchecked(
+        &mut self,
+        container: Container,
+        polynomial_size: PolynomialSize,
+    ) -> SecretKey;
+}
@@ -1,10 +1,13 @@
-use super::engine_error;
+use super::{check_decomposition_parameters, engine_error};
 use crate::specification::engines::AbstractEngine;
 use crate::specification::entities::{
     GlweSecretKeyEntity, LweBootstrapKeyEntity, LweSecretKeyEntity,
 };
 use concrete_commons::dispersion::Variance;
-use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DecompositionParameters,
+};
 
 engine_error! {
     LweBootstrapKeyCreationError for LweBootstrapKeyCreationEngine @
@@ -15,21 +18,18 @@ engine_error! {
 }
 
 impl<EngineError: std::error::Error> LweBootstrapKeyCreationError<EngineError> {
-    pub fn perform_generic_checks(
+    /// Validates the inputs, returning the validated decomposition parameters.
+    pub fn perform_generic_checks<Scalar: UnsignedInteger>(
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
-        integer_precision: usize,
-    ) -> Result<(), Self> {
-        if decomposition_base_log.0 == 0 {
-            return Err(Self::NullDecompositionBaseLog);
-        }
-        if decomposition_level_count.0 == 0 {
-            return Err(Self::NullDecompositionLevelCount);
-        }
-        if decomposition_base_log.0 * decomposition_level_count.0 > integer_precision {
-            return Err(Self::DecompositionTooLarge);
-        }
-        Ok(())
+    ) -> Result<DecompositionParameters, Self> {
+        check_decomposition_parameters::<Scalar, _>(
+            decomposition_base_log,
+            decomposition_level_count,
+            Self::NullDecompositionBaseLog,
+            Self::NullDecompositionLevelCount,
+            Self::DecompositionTooLarge,
+        )
     }
 }
 
@@ -0,0 +1,85 @@
+use super::{check_decomposition_parameters, engine_error};
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweSecretKeyEntity, LweBootstrapKeyEntity, LweSecretKeyEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DecompositionParameters, KeyDerivationLabel,
+    MasterSeed,
+};
+
+engine_error! {
+    LweBootstrapKeyDerivationError for LweBootstrapKeyDerivationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> LweBootstrapKeyDerivationError<EngineError> {
+    /// Validates the inputs, returning the validated decomposition parameters.
+    pub fn perform_generic_checks<Scalar: UnsignedInteger>(
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<DecompositionParameters, Self> {
+        check_decomposition_parameters::<Scalar, _>(
+            decomposition_base_log,
+            decomposition_level_count,
+            Self::NullDecompositionBaseLog,
+            Self::NullDecompositionLevelCount,
+            Self::DecompositionTooLarge,
+        )
+    }
+}
+
+/// A trait for engines deriving LWE bootstrap keys from a master seed.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE bootstrap key from the
+/// `input_key` LWE secret key, and the `output_key` GLWE secret key. The randomness used to
+/// encrypt the key (both mask and noise) is deterministically derived from the `master_seed`
+/// master seed and the `label` label, and is independent of the randomness used to derive any
+/// other kind of key with the same label.
+///
+/// # Formal Definition
+pub trait LweBootstrapKeyDerivationEngine<LweSecretKey, GlweSecretKey, BootstrapKey>:
+    AbstractEngine
+where
+    BootstrapKey: LweBootstrapKeyEntity,
+    LweSecretKey: LweSecretKeyEntity<KeyDistribution = BootstrapKey::InputKeyDistribution>,
+    GlweSecretKey: GlweSecretKeyEntity<KeyDistribution = BootstrapKey::OutputKeyDistribution>,
+{
+    /// Derives an LWE bootstrap key.
+    #[allow(clippy::too_many_arguments)]
+    fn derive_lwe_bootstrap_key(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        input_key: &LweSecretKey,
+        output_key: &GlweSecretKey,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<BootstrapKey, LweBootstrapKeyDerivationError<Self::EngineError>>;
+
+    /// Unsafely derives an LWE bootstrap key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweBootstrapKeyDerivationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn derive_lwe_bootstrap_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        input_key: &LweSecretKey,
+        output_key: &GlweSecretKey,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> BootstrapKey;
+}
@@ -0,0 +1,66 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweSecretKeyEntity, PlaintextEntity};
+
+engine_error! {
+    LweCiphertextNoiseMeasurementError for LweCiphertextNoiseMeasurementEngine @
+    LweDimensionMismatch => "The LWE dimension of the key and the input ciphertext must be the \
+                             same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextNoiseMeasurementError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, Ciphertext>(
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        Ciphertext: LweCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines measuring the noise of LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation decrypts the `input` LWE ciphertext under the
+/// `key` secret key, and returns the difference between the decrypted plaintext and the `expected`
+/// plaintext, centered around zero. The `Noise` type can be any type that suits the backend
+/// implementor to represent this signed difference (a signed integer, a torus value as a float, or
+/// any other thing).
+///
+/// # Formal Definition
+pub trait LweCiphertextNoiseMeasurementEngine<SecretKey, Ciphertext, Plaintext, Noise>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    Plaintext: PlaintextEntity,
+{
+    /// Measures the noise of an LWE ciphertext.
+    fn measure_lwe_ciphertext_noise(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &Plaintext,
+    ) -> Result<Noise, LweCiphertextNoiseMeasurementError<Self::EngineError>>;
+
+    /// Unsafely measures the noise of an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextNoiseMeasurementError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn measure_lwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &Plaintext,
+    ) -> Noise;
+}
@@ -0,0 +1,78 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorNoiseMeasurementError for LweCiphertextVectorNoiseMeasurementEngine @
+    LweDimensionMismatch => "The LWE dimension of the key and the input ciphertext vector must be \
+                             the same.",
+    PlaintextCountMismatch => "The expected plaintext vector length and the input ciphertext \
+                               vector length must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorNoiseMeasurementError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, CiphertextVector, PlaintextVector>(
+        key: &SecretKey,
+        input: &CiphertextVector,
+        expected: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        CiphertextVector: LweCiphertextVectorEntity<KeyDistribution = SecretKey::KeyDistribution>,
+        PlaintextVector: PlaintextVectorEntity,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count().0 != expected.plaintext_count().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines measuring the noise of LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation decrypts the `input` LWE ciphertext vector
+/// under the `key` secret key, and returns the piece-wise differences between the decrypted
+/// plaintexts and the `expected` plaintexts, centered around zero. The `Noise` type can be any
+/// type that suits the backend implementor to represent those signed differences (a vector of
+/// signed integers, a vector of torus values as floats, or any other thing).
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorNoiseMeasurementEngine<
+    SecretKey,
+    CiphertextVector,
+    PlaintextVector,
+    Noise,
+>: AbstractEngine where
+    SecretKey: LweSecretKeyEntity,
+    CiphertextVector: LweCiphertextVectorEntity<KeyDistribution = SecretKey::KeyDistribution>,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Measures the noise of an LWE ciphertext vector.
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &SecretKey,
+        input: &CiphertextVector,
+        expected: &PlaintextVector,
+    ) -> Result<Noise, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>>;
+
+    /// Unsafely measures the noise of an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorNoiseMeasurementError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &CiphertextVector,
+        expected: &PlaintextVector,
+    ) -> Noise;
+}
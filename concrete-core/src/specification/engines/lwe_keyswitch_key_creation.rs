@@ -1,9 +1,12 @@
-use super::engine_error;
+use super::{check_decomposition_parameters, engine_error};
 use crate::specification::engines::AbstractEngine;
 
 use crate::specification::entities::{LweKeyswitchKeyEntity, LweSecretKeyEntity};
 use concrete_commons::dispersion::Variance;
-use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DecompositionParameters,
+};
 
 engine_error! {
     LweKeyswitchKeyCreationError for LweKeyswitchKeyCreationEngine @
@@ -14,25 +17,18 @@ engine_error! {
 }
 
 impl<EngineError: std::error::Error> LweKeyswitchKeyCreationError<EngineError> {
-    /// Validates the inputs
-    pub fn perform_generic_checks(
+    /// Validates the inputs, returning the validated decomposition parameters.
+    pub fn perform_generic_checks<Scalar: UnsignedInteger>(
         decomposition_level_count: DecompositionLevelCount,
         decomposition_base_log: DecompositionBaseLog,
-        integer_precision: usize,
-    ) -> Result<(), Self> {
-        if decomposition_base_log.0 == 0 {
-            return Err(Self::NullDecompositionBaseLog);
-        }
-
-        if decomposition_level_count.0 == 0 {
-            return Err(Self::NullDecompositionLevelCount);
-        }
-
-        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
-            return Err(Self::DecompositionTooLarge);
-        }
-
-        Ok(())
+    ) -> Result<DecompositionParameters, Self> {
+        check_decomposition_parameters::<Scalar, _>(
+            decomposition_base_log,
+            decomposition_level_count,
+            Self::NullDecompositionBaseLog,
+            Self::NullDecompositionLevelCount,
+            Self::DecompositionTooLarge,
+        )
     }
 }
 
@@ -0,0 +1,87 @@
+use super::{check_decomposition_parameters, engine_error};
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{LweKeyswitchKeyEntity, LweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DecompositionParameters, KeyDerivationLabel,
+    MasterSeed,
+};
+
+engine_error! {
+    LweKeyswitchKeyDerivationError for LweKeyswitchKeyDerivationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> LweKeyswitchKeyDerivationError<EngineError> {
+    /// Validates the inputs, returning the validated decomposition parameters.
+    pub fn perform_generic_checks<Scalar: UnsignedInteger>(
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<DecompositionParameters, Self> {
+        check_decomposition_parameters::<Scalar, _>(
+            decomposition_base_log,
+            decomposition_level_count,
+            Self::NullDecompositionBaseLog,
+            Self::NullDecompositionLevelCount,
+            Self::DecompositionTooLarge,
+        )
+    }
+}
+
+/// A trait for engines deriving LWE keyswitch keys from a master seed.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE keyswitch key allowing to
+/// switch from the `input_key` LWE secret key to the `output_key` LWE secret key. The randomness
+/// used to encrypt the key (both mask and noise) is deterministically derived from the
+/// `master_seed` master seed and the `label` label, and is independent of the randomness used to
+/// derive any other kind of key with the same label.
+///
+/// # Formal Definition
+pub trait LweKeyswitchKeyDerivationEngine<InputSecretKey, OutputSecretKey, KeyswitchKey>:
+    AbstractEngine
+where
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: LweSecretKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity<
+        InputKeyDistribution = InputSecretKey::KeyDistribution,
+        OutputKeyDistribution = OutputSecretKey::KeyDistribution,
+    >,
+{
+    /// Derives an LWE keyswitch key.
+    #[allow(clippy::too_many_arguments)]
+    fn derive_lwe_keyswitch_key(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<KeyswitchKey, LweKeyswitchKeyDerivationError<Self::EngineError>>;
+
+    /// Unsafely derives an LWE keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweKeyswitchKeyDerivationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn derive_lwe_keyswitch_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> KeyswitchKey;
+}
@@ -0,0 +1,38 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweSecretKeyEntity;
+
+engine_error! {
+    LweSecretKeyConsumingRetrievalError for LweSecretKeyConsumingRetrievalEngine @
+}
+
+/// A trait for engines retrieving the content of the container from an LWE secret key consuming it
+/// in the process.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation retrieves the content of the container from the
+/// `input` LWE secret key consuming it in the process. This makes it possible to export the
+/// coefficients of a key.
+pub trait LweSecretKeyConsumingRetrievalEngine<SecretKey, Container>: AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+{
+    /// Retrieves the content of the container from an LWE secret key, consuming it in the process.
+    fn consume_retrieve_lwe_secret_key(
+        &mut self,
+        secret_key: SecretKey,
+    ) -> Result<Container, LweSecretKeyConsumingRetrievalError<Self::EngineError>>;
+
+    /// Unsafely retrieves the content of the container from an LWE secret key, consuming it in the
+    /// process.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSecretKeyConsumingRetrievalError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn consume_retrieve_lwe_secret_key_unchecked(
+        &mut self,
+        secret_key: SecretKey,
+    ) -> Container;
+}
@@ -0,0 +1,56 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::parameters::{KeyDerivationLabel, LweDimension, MasterSeed};
+
+engine_error! {
+    LweSecretKeyDerivationError for LweSecretKeyDerivationEngine @
+    NullLweDimension => "The LWE dimension must be greater than zero."
+}
+
+impl<EngineError: std::error::Error> LweSecretKeyDerivationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(lwe_dimension: LweDimension) -> Result<(), Self> {
+        if lwe_dimension.0 == 0 {
+            return Err(Self::NullLweDimension);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines deriving LWE secret keys from a master seed.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE secret key deterministically
+/// derived from the `master_seed` master seed and the `label` label. Deriving a key twice with the
+/// same master seed and label outputs the same key, while keys derived with different labels are
+/// independent. The key is also independent of any other kind of key derived with the same label,
+/// e.g. of the GLWE secret key.
+///
+/// # Formal Definition
+pub trait LweSecretKeyDerivationEngine<SecretKey>: AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+{
+    /// Derives an LWE secret key.
+    fn derive_lwe_secret_key(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        lwe_dimension: LweDimension,
+    ) -> Result<SecretKey, LweSecretKeyDerivationError<Self::EngineError>>;
+
+    /// Unsafely derives an LWE secret key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSecretKeyDerivationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn derive_lwe_secret_key_unchecked(
+        &mut self,
+        master_seed: MasterSeed,
+        label: KeyDerivationLabel,
+        lwe_dimension: LweDimension,
+    ) -> SecretKey;
+}
@@ -0,0 +1,66 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::numeric::UnsignedInteger;
+
+engine_error! {
+    LweSecretKeyFromContainerCreationError for LweSecretKeyFromContainerCreationEngine @
+    EmptyContainer => "The container used to create the LWE secret key is of length 0!",
+    InvalidKeyValue => "The container used to create the LWE secret key contains values which do \
+    not belong to the key distribution."
+}
+
+impl<EngineError: std::error::Error> LweSecretKeyFromContainerCreationError<EngineError> {
+    /// Validates the inputs, the LWE dimension of the key is deduced from the length of the
+    /// container, which must then be greater than zero.
+    pub fn perform_generic_checks(container_length: usize) -> Result<(), Self> {
+        if container_length == 0 {
+            return Err(Self::EmptyContainer);
+        }
+        Ok(())
+    }
+
+    /// Validates that all the values of the container belong to the binary key distribution.
+    pub fn perform_binary_key_checks<Scalar>(container: &[Scalar]) -> Result<(), Self>
+    where
+        Scalar: UnsignedInteger,
+    {
+        if container
+            .iter()
+            .any(|value| *value != Scalar::ZERO && *value != Scalar::ONE)
+        {
+            return Err(Self::InvalidKeyValue);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating LWE secret keys from an arbitrary container.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE secret key from the arbitrary
+/// `container`, whose values are used as the key coefficients. Contrary to
+/// [`LweSecretKeyCreationEngine`](`super::LweSecretKeyCreationEngine`), no randomness is sampled:
+/// this makes it possible to import keys generated outside of the engine.
+pub trait LweSecretKeyFromContainerCreationEngine<Container, SecretKey>: AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+{
+    /// Creates an LWE secret key from an arbitrary container.
+    fn create_lwe_secret_key_from_container(
+        &mut self,
+        container: Container,
+    ) -> Result<SecretKey, LweSecretKeyFromContainerCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an LWE secret key from an arbitrary container.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSecretKeyFromContainerCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_lwe_secret_key_from_container_unchecked(
+        &mut self,
+        container: Container,
+    ) -> SecretKey;
+}
@@ -53,6 +53,11 @@
 //! (example: [`LweCiphertextFusingAdditionEngine`]). In those operations though, the data
 //! originally contained in the output is used for computation.
 
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DecompositionParameters, ParameterError,
+};
+
 // This makes it impossible for types outside concrete to implement operations.
 pub(crate) mod sealed {
     pub trait AbstractEngineSeal {}
@@ -139,6 +144,30 @@ macro_rules! engine_error {
 }
 pub(crate) use engine_error;
 
+/// Checks that a decomposition of integers of type `Scalar` is valid, returning the validated
+/// parameters, or the given error variant matching the reason otherwise.
+///
+/// This is shared by the `perform_generic_checks` of the errors of the engines taking
+/// decomposition parameters as inputs.
+pub(crate) fn check_decomposition_parameters<Scalar, Error>(
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    null_base_log: Error,
+    null_level_count: Error,
+    too_large: Error,
+) -> Result<DecompositionParameters, Error>
+where
+    Scalar: UnsignedInteger,
+{
+    DecompositionParameters::try_new::<Scalar>(decomposition_base_log, decomposition_level_count)
+        .map_err(|error| match error {
+            ParameterError::NullDecompositionBaseLog => null_base_log,
+            ParameterError::NullDecompositionLevelCount => null_level_count,
+            // Decomposition parameters can only otherwise be rejected for being too large.
+            _ => too_large,
+        })
+}
+
 mod cleartext_conversion;
 mod cleartext_creation;
 mod cleartext_discarding_conversion;
@@ -167,24 +196,32 @@ mod glwe_ciphertext_discarding_encryption;
 mod glwe_ciphertext_encryption;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_noise_measurement;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
+mod glwe_ciphertext_vector_consuming_retrieval;
 mod glwe_ciphertext_vector_conversion;
+mod glwe_ciphertext_vector_creation;
 mod glwe_ciphertext_vector_decryption;
 mod glwe_ciphertext_vector_discarding_conversion;
 mod glwe_ciphertext_vector_discarding_decryption;
 mod glwe_ciphertext_vector_discarding_encryption;
 mod glwe_ciphertext_vector_encryption;
+mod glwe_ciphertext_vector_noise_measurement;
 mod glwe_ciphertext_vector_trivial_decryption;
 mod glwe_ciphertext_vector_trivial_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
+mod glwe_secret_key_consuming_retrieval;
 mod glwe_secret_key_conversion;
 mod glwe_secret_key_creation;
+mod glwe_secret_key_derivation;
 mod glwe_secret_key_discarding_conversion;
+mod glwe_secret_key_from_container_creation;
 mod glwe_secret_key_to_lwe_secret_key_transmutation;
 mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_creation;
+mod lwe_bootstrap_key_derivation;
 mod lwe_bootstrap_key_discarding_conversion;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
@@ -208,6 +245,7 @@ mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_opposite;
 mod lwe_ciphertext_fusing_subtraction;
 mod lwe_ciphertext_loading;
+mod lwe_ciphertext_noise_measurement;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
@@ -232,16 +270,21 @@ mod lwe_ciphertext_vector_fusing_opposite;
 mod lwe_ciphertext_vector_fusing_subtraction;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 mod lwe_ciphertext_vector_loading;
+mod lwe_ciphertext_vector_noise_measurement;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
 mod lwe_keyswitch_key_conversion;
 mod lwe_keyswitch_key_creation;
+mod lwe_keyswitch_key_derivation;
 mod lwe_keyswitch_key_discarding_conversion;
+mod lwe_secret_key_consuming_retrieval;
 mod lwe_secret_key_conversion;
 mod lwe_secret_key_creation;
+mod lwe_secret_key_derivation;
 mod lwe_secret_key_discarding_conversion;
+mod lwe_secret_key_from_container_creation;
 mod packing_keyswitch_key_creation;
 mod plaintext_conversion;
 mod plaintext_creation;
@@ -284,24 +327,32 @@ pub use glwe_ciphertext_discarding_encryption::*;
 pub use glwe_ciphertext_encryption::*;
 pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
+pub use glwe_ciphertext_noise_measurement::*;
 pub use glwe_ciphertext_trivial_decryption::*;
 pub use glwe_ciphertext_trivial_encryption::*;
+pub use glwe_ciphertext_vector_consuming_retrieval::*;
 pub use glwe_ciphertext_vector_conversion::*;
+pub use glwe_ciphertext_vector_creation::*;
 pub use glwe_ciphertext_vector_decryption::*;
 pub use glwe_ciphertext_vector_discarding_conversion::*;
 pub use glwe_ciphertext_vector_discarding_decryption::*;
 pub use glwe_ciphertext_vector_discarding_encryption::*;
 pub use glwe_ciphertext_vector_encryption::*;
+pub use glwe_ciphertext_vector_noise_measurement::*;
 pub use glwe_ciphertext_vector_trivial_decryption::*;
 pub use glwe_ciphertext_vector_trivial_encryption::*;
 pub use glwe_ciphertext_vector_zero_encryption::*;
 pub use glwe_ciphertext_zero_encryption::*;
+pub use glwe_secret_key_consuming_retrieval::*;
 pub use glwe_secret_key_conversion::*;
 pub use glwe_secret_key_creation::*;
+pub use glwe_secret_key_derivation::*;
 pub use glwe_secret_key_discarding_conversion::*;
+pub use glwe_secret_key_from_container_creation::*;
 pub use glwe_secret_key_to_lwe_secret_key_transmutation::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_creation::*;
+pub use lwe_bootstrap_key_derivation::*;
 pub use lwe_bootstrap_key_discarding_conversion::*;
 pub use lwe_ciphertext_cleartext_discarding_multiplication::*;
 pub use lwe_ciphertext_cleartext_fusing_multiplication::*;
@@ -325,6 +376,7 @@ pub use lwe_ciphertext_fusing_addition::*;
 pub use lwe_ciphertext_fusing_opposite::*;
 pub use lwe_ciphertext_fusing_subtraction::*;
 pub use lwe_ciphertext_loading::*;
+pub use lwe_ciphertext_noise_measurement::*;
 pub use lwe_ciphertext_plaintext_discarding_addition::*;
 pub use lwe_ciphertext_plaintext_discarding_subtraction::*;
 pub use lwe_ciphertext_plaintext_fusing_addition::*;
@@ -349,16 +401,21 @@ pub use lwe_ciphertext_vector_fusing_opposite::*;
 pub use lwe_ciphertext_vector_fusing_subtraction::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch::*;
 pub use lwe_ciphertext_vector_loading::*;
+pub use lwe_ciphertext_vector_noise_measurement::*;
 pub use lwe_ciphertext_vector_trivial_decryption::*;
 pub use lwe_ciphertext_vector_trivial_encryption::*;
 pub use lwe_ciphertext_vector_zero_encryption::*;
 pub use lwe_ciphertext_zero_encryption::*;
 pub use lwe_keyswitch_key_conversion::*;
 pub use lwe_keyswitch_key_creation::*;
+pub use lwe_keyswitch_key_derivation::*;
 pub use lwe_keyswitch_key_discarding_conversion::*;
+pub use lwe_secret_key_consuming_retrieval::*;
 pub use lwe_secret_key_conversion::*;
 pub use lwe_secret_key_creation::*;
+pub use lwe_secret_key_derivation::*;
 pub use lwe_secret_key_discarding_conversion::*;
+pub use lwe_secret_key_from_container_creation::*;
 pub use packing_keyswitch_key_creation::*;
 pub use plaintext_conversion::*;
 pub use plaintext_creation::*;
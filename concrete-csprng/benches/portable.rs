@@ -0,0 +1,31 @@
+//! Compares the portable generators, namely the software aes and the ChaCha20 ones.
+use concrete_csprng::generators::{
+    ChachaRandomGenerator, RandomGenerator, SoftwareRandomGenerator,
+};
+use concrete_csprng::seeders::Seed;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// The number of bytes to generate during one benchmark iteration.
+const N_GEN: usize = 1_000_000;
+
+fn generate<G: RandomGenerator>(c: &mut Criterion, name: &str) {
+    let mut generator = G::new(Seed(0));
+    c.bench_function(name, |b| {
+        b.iter(|| {
+            (0..N_GEN).for_each(|_| {
+                black_box(generator.next());
+            })
+        })
+    });
+}
+
+fn soft_generate(c: &mut Criterion) {
+    generate::<SoftwareRandomGenerator>(c, "soft_generate");
+}
+
+fn chacha_generate(c: &mut Criterion) {
+    generate::<ChachaRandomGenerator>(c, "chacha_generate");
+}
+
+criterion_group!(benches, soft_generate, chacha_generate);
+criterion_main!(benches);
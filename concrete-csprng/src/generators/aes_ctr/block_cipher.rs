@@ -1,20 +1,106 @@
 use crate::generators::aes_ctr::index::AesIndex;
-use crate::generators::aes_ctr::BYTES_PER_BATCH;
+use crate::generators::aes_ctr::{BYTES_PER_AES_CALL, BYTES_PER_BATCH};
+use crate::generators::KeyId;
+use std::convert::TryInto;
+
+// The value xored to a key, to obtain the key used to compute its identifier.
+const KEY_ID_TWEAK: u128 = u128::from_le_bytes(*b"concrete-key-id!");
 
 /// Represents a key used in the AES block cipher.
 #[derive(Clone, Copy)]
 pub struct AesKey(pub u128);
 
-/// A trait for AES block ciphers.
+/// The byte order used to encode the keys and the counters fed to the block ciphers.
+///
+/// The canonical byte order is little-endian, so that a given seed yields the same stream on every
+/// host. The previous versions of this crate used the byte order of the host instead, which only
+/// differs on big-endian hosts. The [`ByteOrder::Native`] order must be explicitly requested, to
+/// reproduce the streams generated by those versions on such hosts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// The canonical little-endian byte order.
+    #[default]
+    LittleEndian,
+    /// The byte order of the host, used by the previous versions of this crate.
+    Native,
+}
+
+impl ByteOrder {
+    /// Encodes a 128 bits value in this byte order.
+    pub fn encode(self, value: u128) -> [u8; BYTES_PER_AES_CALL] {
+        match self {
+            ByteOrder::LittleEndian => value.to_le_bytes(),
+            ByteOrder::Native => value.to_ne_bytes(),
+        }
+    }
+}
+
+#[allow(unused)] // to please clippy when no aes generator is activated
+impl AesKey {
+    /// Returns the bytes of the key, in the given byte order.
+    pub fn to_bytes(self, byte_order: ByteOrder) -> [u8; BYTES_PER_AES_CALL] {
+        byte_order.encode(self.0)
+    }
+
+    /// Computes the identifier of the key, for a block cipher using the given byte order.
+    ///
+    /// The identifier is the first output of a block cipher keyed with a tweaked version of the
+    /// key. This way, it does not reveal any byte of the stream generated with the key itself.
+    pub fn id<Cipher: BlockCipher>(&self, byte_order: ByteOrder) -> KeyId {
+        let mut block_cipher = Cipher::new(AesKey(self.0 ^ KEY_ID_TWEAK), byte_order);
+        let batch = block_cipher.generate_batch(AesIndex(0));
+        KeyId(u128::from_le_bytes(
+            batch[..BYTES_PER_AES_CALL].try_into().unwrap(),
+        ))
+    }
+}
+
+/// A trait for the block ciphers used in counter mode by the generator.
+///
+/// The trait is implemented by the AES block ciphers, and by the ChaCha20 block function, which
+/// both map a 128 bits key and a 128 bits index to a block of random bytes.
 ///
 /// Note:
 /// -----
 ///
 /// The block cipher is used in a batched manner (to reduce amortized cost on special hardware).
 /// For this reason we only expose a `generate_batch` method.
-pub trait AesBlockCipher: Clone + Send + Sync {
-    /// Instantiate a new generator from a secret key.
-    fn new(key: AesKey) -> Self;
+pub trait BlockCipher: Clone + Send + Sync {
+    /// Instantiate a new generator from a secret key, encoding the key and the counters in the
+    /// given byte order.
+    fn new(key: AesKey, byte_order: ByteOrder) -> Self;
     /// Generates the batch corresponding to the given index.
     fn generate_batch(&mut self, index: AesIndex) -> [u8; BYTES_PER_BATCH];
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // A value whose little-endian bytes are 0, 1, 2, ..., 15.
+    const VALUE: u128 = 0x0f0e0d0c0b0a09080706050403020100;
+
+    #[test]
+    fn test_key_byte_order() {
+        assert_eq!(
+            AesKey(VALUE).to_bytes(ByteOrder::LittleEndian),
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+        );
+        assert_eq!(
+            AesKey(VALUE).to_bytes(ByteOrder::Native),
+            VALUE.to_ne_bytes()
+        );
+    }
+
+    #[test]
+    fn test_index_byte_order() {
+        assert_eq!(
+            AesIndex(VALUE).to_bytes(ByteOrder::LittleEndian),
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+        );
+        assert_eq!(
+            AesIndex(VALUE).to_bytes(ByteOrder::Native),
+            VALUE.to_ne_bytes()
+        );
+    }
+}
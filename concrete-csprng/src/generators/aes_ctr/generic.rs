@@ -1,8 +1,11 @@
-use crate::generators::aes_ctr::block_cipher::{AesBlockCipher, AesKey};
+use crate::generators::aes_ctr::block_cipher::{AesKey, BlockCipher, ByteOrder};
 use crate::generators::aes_ctr::index::TableIndex;
 use crate::generators::aes_ctr::states::{BufferPointer, ShiftAction, State};
 use crate::generators::aes_ctr::BYTES_PER_BATCH;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, KeyId, RestoreError,
+    SkipError,
+};
 
 // Usually, to work with iterators and parallel iterators, we would use opaque types such as
 // `impl Iterator<..>`. Unfortunately, it is not yet possible to return existential types in
@@ -10,23 +13,25 @@ use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError};
 // full type name where needed. Hence the following trait aliases definition:
 
 /// A type alias for the children iterator closure type.
-pub type ChildrenClosure<BlockCipher> =
-    fn((usize, (BlockCipher, TableIndex, BytesPerChild))) -> AesCtrGenerator<BlockCipher>;
+pub type ChildrenClosure<Cipher> =
+    fn((usize, (Cipher, KeyId, TableIndex, BytesPerChild))) -> AesCtrGenerator<Cipher>;
 
 /// A type alias for the children iterator type.
-pub type ChildrenIterator<BlockCipher> = std::iter::Map<
+pub type ChildrenIterator<Cipher> = std::iter::Map<
     std::iter::Zip<
         std::ops::Range<usize>,
-        std::iter::Repeat<(BlockCipher, TableIndex, BytesPerChild)>,
+        std::iter::Repeat<(Cipher, KeyId, TableIndex, BytesPerChild)>,
     >,
-    ChildrenClosure<BlockCipher>,
+    ChildrenClosure<Cipher>,
 >;
 
 /// A type implementing the `RandomGenerator` api using the AES block cipher in counter mode.
 #[derive(Clone)]
-pub struct AesCtrGenerator<BlockCipher: AesBlockCipher> {
+pub struct AesCtrGenerator<Cipher: BlockCipher> {
     // The block cipher used in the background
-    pub(crate) block_cipher: BlockCipher,
+    pub(crate) block_cipher: Cipher,
+    // The identifier of the key of the block cipher.
+    pub(crate) key_id: KeyId,
     // The state corresponding to the latest outputted byte.
     pub(crate) state: State,
     // The bound, that is the first illegal index.
@@ -38,7 +43,7 @@ pub struct AesCtrGenerator<BlockCipher: AesBlockCipher> {
 }
 
 #[allow(unused)] // to please clippy when tests are not activated
-impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
+impl<Cipher: BlockCipher> AesCtrGenerator<Cipher> {
     /// Generates a new csprng.
     ///
     /// Note :
@@ -58,9 +63,21 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         key: AesKey,
         start_index: Option<TableIndex>,
         bound_index: Option<TableIndex>,
-    ) -> AesCtrGenerator<BlockCipher> {
+    ) -> AesCtrGenerator<Cipher> {
+        AesCtrGenerator::with_byte_order(key, ByteOrder::LittleEndian, start_index, bound_index)
+    }
+
+    /// Generates a new csprng whose block cipher encodes the key and the counters in the given
+    /// byte order.
+    pub fn with_byte_order(
+        key: AesKey,
+        byte_order: ByteOrder,
+        start_index: Option<TableIndex>,
+        bound_index: Option<TableIndex>,
+    ) -> AesCtrGenerator<Cipher> {
         AesCtrGenerator::from_block_cipher(
-            BlockCipher::new(key),
+            Cipher::new(key, byte_order),
+            key.id::<Cipher>(byte_order),
             start_index.unwrap_or(TableIndex::SECOND),
             bound_index.unwrap_or(TableIndex::LAST),
         )
@@ -68,16 +85,18 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
 
     /// Generates a csprng from an existing block cipher.
     pub fn from_block_cipher(
-        block_cipher: BlockCipher,
+        block_cipher: Cipher,
+        key_id: KeyId,
         start_index: TableIndex,
         bound_index: TableIndex,
-    ) -> AesCtrGenerator<BlockCipher> {
+    ) -> AesCtrGenerator<Cipher> {
         assert!(start_index < bound_index);
         let last = bound_index.decremented();
         let buffer = [0u8; BYTES_PER_BATCH];
         let state = State::new(start_index);
         AesCtrGenerator {
             block_cipher,
+            key_id,
             state,
             bound: bound_index,
             last,
@@ -126,7 +145,7 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         &mut self,
         n_children: ChildrenCount,
         n_bytes: BytesPerChild,
-    ) -> Result<ChildrenIterator<BlockCipher>, ForkError> {
+    ) -> Result<ChildrenIterator<Cipher>, ForkError> {
         if n_children.0 == 0 {
             return Err(ForkError::ZeroChildrenCount);
         }
@@ -143,15 +162,16 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         let output = (0..n_children.0)
             .zip(std::iter::repeat((
                 self.block_cipher.clone(),
+                self.key_id,
                 first_index,
                 n_bytes,
             )))
             .map(
                 // This map is a little weird because we need to cast the closure to a fn pointer
-                // that matches the signature of `ChildrenIterator<BlockCipher>`.
+                // that matches the signature of `ChildrenIterator<Cipher>`.
                 // Unfortunately, the compiler does not manage to coerce this one
                 // automatically.
-                (|(i, (block_cipher, first_index, n_bytes))| {
+                (|(i, (block_cipher, key_id, first_index, n_bytes))| {
                     // The first index to be outputted by the child is the `first_index` shifted by
                     // the proper amount of `child_bytes`.
                     let child_first_index = first_index.increased(n_bytes.0 * i);
@@ -159,10 +179,11 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
                     let child_bound_index = first_index.increased(n_bytes.0 * (i + 1));
                     AesCtrGenerator::from_block_cipher(
                         block_cipher,
+                        key_id,
                         child_first_index,
                         child_bound_index,
                     )
-                }) as ChildrenClosure<BlockCipher>,
+                }) as ChildrenClosure<Cipher>,
             );
         // The parent next index is the bound of the last child.
         let next_index = first_index.increased(n_bytes.0 * n_children.0);
@@ -171,6 +192,73 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         Ok(output)
     }
 
+    /// Tries to skip the next `n_bytes` bytes of the stream.
+    pub fn try_skip(&mut self, n_bytes: ByteCount) -> Result<(), SkipError> {
+        if n_bytes > self.remaining_bytes() {
+            return Err(SkipError::SkipTooLarge);
+        }
+        // The state points to the table index of the last generated byte, so the first index to
+        // be generated after the skip is shifted from the next one. Creating a new state ensures
+        // that the buffer is refreshed on the next call.
+        let mut next_index = self.state.table_index().incremented();
+        next_index.increase_by(n_bytes);
+        self.state = State::new(next_index);
+        Ok(())
+    }
+
+    /// Returns a snapshot of the position of the generator in its stream.
+    pub fn snapshot(&self) -> GeneratorState {
+        GeneratorState {
+            key_id: self.key_id,
+            table_index: self.state.table_index(),
+            bound: self.bound,
+        }
+    }
+
+    /// Restores a generator from its key and a snapshot of its position.
+    ///
+    /// Note :
+    /// ------
+    ///
+    /// The buffer of the restored generator is not filled, but since its state is created from
+    /// the next table index, the first call to `next` refreshes the batch starting at the proper
+    /// aes index.
+    pub fn restore(
+        key: AesKey,
+        state: GeneratorState,
+    ) -> Result<AesCtrGenerator<Cipher>, RestoreError> {
+        AesCtrGenerator::restore_with_byte_order(key, ByteOrder::LittleEndian, state)
+    }
+
+    /// Restores a generator whose block cipher encodes the key and the counters in the given
+    /// byte order.
+    ///
+    /// The key identifier depends on the byte order, hence restoring a snapshot with another byte
+    /// order than the one of the original generator fails on big-endian hosts.
+    pub fn restore_with_byte_order(
+        key: AesKey,
+        byte_order: ByteOrder,
+        state: GeneratorState,
+    ) -> Result<AesCtrGenerator<Cipher>, RestoreError> {
+        if key.id::<Cipher>(byte_order) != state.key_id {
+            return Err(RestoreError::KeyMismatch);
+        }
+        // The state of a generator which reached its bound points to the index just before the
+        // bound, hence the next index can be equal to the bound.
+        let next_index = state.table_index.incremented();
+        if next_index > state.bound || next_index == TableIndex::FIRST {
+            return Err(RestoreError::IndexAfterBound);
+        }
+        Ok(AesCtrGenerator {
+            block_cipher: Cipher::new(key, byte_order),
+            key_id: state.key_id,
+            state: State::new(next_index),
+            bound: state.bound,
+            last: state.bound.decremented(),
+            buffer: [0u8; BYTES_PER_BATCH],
+        })
+    }
+
     pub(crate) fn is_fork_in_bound(
         &self,
         n_child: ChildrenCount,
@@ -182,7 +270,7 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
     }
 }
 
-impl<BlockCipher: AesBlockCipher> Iterator for AesCtrGenerator<BlockCipher> {
+impl<Cipher: BlockCipher> Iterator for AesCtrGenerator<Cipher> {
     type Item = u8;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -261,7 +349,7 @@ pub mod aes_ctr_generic_test {
     /// Check the property:
     ///     On a valid fork, the table index of the first child is the same as the table index of
     ///     the parent before the fork.
-    pub fn prop_fork_first_state_table_index<G: AesBlockCipher>() {
+    pub fn prop_fork_first_state_table_index<G: BlockCipher>() {
         for _ in 0..REPEATS {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
@@ -276,7 +364,7 @@ pub mod aes_ctr_generic_test {
     /// Check the property:
     ///     On a valid fork, the table index of the first byte outputted by the parent after the
     ///     fork, is the bound of the last child of the fork.
-    pub fn prop_fork_last_bound_table_index<G: AesBlockCipher>() {
+    pub fn prop_fork_last_bound_table_index<G: BlockCipher>() {
         for _ in 0..REPEATS {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
@@ -292,7 +380,7 @@ pub mod aes_ctr_generic_test {
 
     /// Check the property:
     ///     On a valid fork, the bound of the parent does not change.
-    pub fn prop_fork_parent_bound_table_index<G: AesBlockCipher>() {
+    pub fn prop_fork_parent_bound_table_index<G: BlockCipher>() {
         for _ in 0..REPEATS {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
@@ -307,7 +395,7 @@ pub mod aes_ctr_generic_test {
     /// Check the property:
     ///     On a valid fork, the parent table index is increased of the number of children
     ///     multiplied by the number of bytes per child.
-    pub fn prop_fork_parent_state_table_index<G: AesBlockCipher>() {
+    pub fn prop_fork_parent_state_table_index<G: BlockCipher>() {
         for _ in 0..REPEATS {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
@@ -323,10 +411,25 @@ pub mod aes_ctr_generic_test {
         }
     }
 
+    /// Check the property:
+    ///     On a generator with enough remaining bytes, skipping i bytes increases the table index
+    ///     of i bytes.
+    pub fn prop_skip_table_index<G: BlockCipher>() {
+        for _ in 0..REPEATS {
+            let (t, nc, nb, i) = any_valid_fork().next().unwrap();
+            let k = any_key().next().unwrap();
+            let mut generator =
+                AesCtrGenerator::<G>::new(k, Some(t), Some(t.increased(nc.0 * nb.0 + i)));
+            generator.try_skip(ByteCount(i as u128)).unwrap();
+            // Decrement accounts for the fact that the table index stored is the previous one
+            assert_eq!(generator.table_index(), t.increased(i).decremented());
+        }
+    }
+
     /// Check the property:
     ///     On a valid fork, the bytes outputted by the children in the fork order form the same
     ///     sequence the parent would have had yielded no fork had happened.
-    pub fn prop_fork<G: AesBlockCipher>() {
+    pub fn prop_fork<G: BlockCipher>() {
         for _ in 0..1000 {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
@@ -347,7 +450,7 @@ pub mod aes_ctr_generic_test {
     /// Check the property:
     ///     On a valid fork, all children got a number of remaining bytes equals to the number of
     ///     bytes per child given as fork input.
-    pub fn prop_fork_children_remaining_bytes<G: AesBlockCipher>() {
+    pub fn prop_fork_children_remaining_bytes<G: BlockCipher>() {
         for _ in 0..REPEATS {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
@@ -363,7 +466,7 @@ pub mod aes_ctr_generic_test {
     /// Check the property:
     ///     On a valid fork, the number of remaining bybtes of the parent is reduced by the number
     ///     of children multiplied by the number of bytes per child.
-    pub fn prop_fork_parent_remaining_bytes<G: AesBlockCipher>() {
+    pub fn prop_fork_parent_remaining_bytes<G: BlockCipher>() {
         for _ in 0..REPEATS {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
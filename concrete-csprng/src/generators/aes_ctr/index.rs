@@ -1,3 +1,4 @@
+use crate::generators::aes_ctr::block_cipher::ByteOrder;
 use crate::generators::aes_ctr::BYTES_PER_AES_CALL;
 use crate::generators::ByteCount;
 use std::cmp::Ordering;
@@ -6,6 +7,16 @@ use std::cmp::Ordering;
 #[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq)]
 pub struct AesIndex(pub u128);
 
+#[allow(unused)] // to please clippy when no aes generator is activated
+impl AesIndex {
+    /// Returns the bytes of the index, in the given byte order.
+    ///
+    /// The bytes are the ones encrypted by the block cipher to produce the output at this index.
+    pub fn to_bytes(self, byte_order: ByteOrder) -> [u8; BYTES_PER_AES_CALL] {
+        byte_order.encode(self.0)
+    }
+}
+
 /// A structure representing a [byte index](#fine-grained-pseudo-random-table-lookup).
 #[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq)]
 pub struct ByteIndex(pub usize);
@@ -37,7 +48,6 @@ impl TableIndex {
     };
 
     /// Creates a table index from an aes index and a byte index.
-    #[allow(unused)] // to please clippy when tests are not activated
     pub fn new(aes_index: AesIndex, byte_index: ByteIndex) -> Self {
         assert!(byte_index.0 < BYTES_PER_AES_CALL);
         TableIndex {
@@ -56,6 +66,18 @@ impl TableIndex {
             .wrapping_add(total as u128 / BYTES_PER_AES_CALL as u128);
     }
 
+    /// Shifts the table index forward of a number of bytes which may not fit in a `usize`.
+    pub fn increase_by(&mut self, shift: ByteCount) {
+        let aes_shift = shift.0 / BYTES_PER_AES_CALL as u128;
+        let total = self.byte_index.0 + (shift.0 % BYTES_PER_AES_CALL as u128) as usize;
+        self.byte_index.0 = total % BYTES_PER_AES_CALL;
+        self.aes_index.0 = self
+            .aes_index
+            .0
+            .wrapping_add(aes_shift)
+            .wrapping_add((total / BYTES_PER_AES_CALL) as u128);
+    }
+
     /// Shifts the table index backward of `shift` bytes.
     pub fn decrease(&mut self, shift: usize) {
         let remainder = shift % BYTES_PER_AES_CALL;
@@ -362,4 +384,33 @@ mod test {
             assert_eq!(t.decreased(i).increased(i), t);
         }
     }
+
+    #[test]
+    /// Check the property:
+    ///     For all table indices t, positive integer i,
+    ///         t.increase_by(ByteCount(i)) = t.increased(i)
+    fn prop_table_index_increase_by() {
+        for _ in 0..REPEATS {
+            let (mut t, i) = any_table_index().zip(any_usize()).next().unwrap();
+            let expected = t.increased(i);
+            t.increase_by(ByteCount(i as u128));
+            assert_eq!(t, expected);
+        }
+    }
+
+    #[test]
+    /// Check the property:
+    ///     For all table indices t, positive integer a < 2¹²⁴,
+    ///         t.increase_by(ByteCount(16 * a)) shifts the aes index of t by a.
+    fn prop_table_index_increase_by_large() {
+        for _ in 0..REPEATS {
+            let (mut t, a) = any_table_index()
+                .map(|t| (t, thread_rng().gen::<u128>() >> 4))
+                .next()
+                .unwrap();
+            let expected = TableIndex::new(AesIndex(t.aes_index.0.wrapping_add(a)), t.byte_index);
+            t.increase_by(ByteCount(a * BYTES_PER_AES_CALL as u128));
+            assert_eq!(t, expected);
+        }
+    }
 }
@@ -0,0 +1,162 @@
+//! Known-answer tests shared by the aes-ctr generators.
+//!
+//! The vectors tie the implementations of [`BlockCipher`] and the generators built on top of
+//! them to fixed byte streams. They were computed with an independent implementation of aes-128
+//! in ecb mode, following the layout described in the [`aes_ctr`](super) module: the counter
+//! `i` is encrypted as the 16 little-endian bytes of `i`, the key is the 16 little-endian bytes of
+//! the seed, and the stream of a fresh generator starts at the second byte of the table.
+//!
+//! The first block of the batch vector is the encryption of the zero block under the key of the
+//! FIPS publication 197, and the first block of the zero seed stream is the well-known encryption
+//! of the zero block under the zero key (`66e94bd4ef8a2c3b884cfa59ca342b2e`), stripped of its
+//! first byte. Any change to the batching, the byte ordering or the forking logic must break
+//! these tests. Since the streams do not depend on the host, the same vectors are checked on
+//! big-endian targets. On little-endian targets, they also hold for the native byte order.
+#![allow(unused)] // to please clippy when tests are not activated
+use crate::generators::aes_ctr::{
+    AesIndex, AesKey, BlockCipher, ByteOrder, AES_CALLS_PER_BATCH, BYTES_PER_AES_CALL,
+};
+use crate::generators::{BytesPerChild, ChildrenCount, RandomGenerator};
+use crate::seeders::Seed;
+
+// The key of the FIPS publication 197 (000102030405060708090a0b0c0d0e0f), as a little-endian seed.
+const FIPS_SEED: Seed = Seed(0x0f0e0d0c0b0a09080706050403020100);
+
+// The batches generated from the counters 0 and 2⁶⁴, under the key of the FIPS publication 197.
+const FIPS_BATCH_0: &str = "c6a13b37878f5b826f4f8162a1c8d879e37cd363dd7c87a09aff0e3e60e09c82\
+                            fb8ae31ba5db9cad97364d8722d473268cb899148f1fa8ff9132d0eb15a936f2\
+                            f08c8d049312eac76f8fa05078178aa1789dc76ccb52ce1c3db90ecb357af60e\
+                            eb3ee851461107fec27297b27ad5e5630a54c3e6eae07eccf451bbcd991a30ec";
+const FIPS_BATCH_2_64: &str = "5f2c80d352d3e8fcb4aea438188d77c857dfae4563c7d0579a1b58d216a7ab82\
+                               f3d70e76cf4dfb71365129fdf6a74bd883cb8b8f0bd4d22a00334cbf47b616e5\
+                               d8f8af82445dad49496d496053a749a21ad381e74202215c7e4d7a1207554f28\
+                               b541d8918b5103c894967b7b3ac69aa12bd9332a2ed072c7aeb09db7b6aa00bb";
+
+// The first 48 bytes outputted by fresh generators.
+const ZERO_SEED_STREAM: &str = "e94bd4ef8a2c3b884cfa59ca342b2e47711816e91d6ff059bbbf2bf58e0fd3bc\
+                                f176a7eaad8085ebacea362462a2814f";
+const FIPS_SEED_STREAM: &str = "a13b37878f5b826f4f8162a1c8d879e37cd363dd7c87a09aff0e3e60e09c82fb\
+                                8ae31ba5db9cad97364d8722d473268c";
+
+// The fork layout of the vectors: the parent outputs 3 bytes, then forks into 3 children of 50
+// bytes, the last one crossing a batch boundary. The second child outputs 2 bytes, then forks
+// into 2 grandchildren of 4 bytes.
+const PARENT_HEAD: &str = "a13b37";
+const CHILDREN: [&str; 3] = [
+    "878f5b826f4f8162a1c8d879e37cd363dd7c87a09aff0e3e60e09c82fb8ae31ba5db9cad97364d8722d473268cb8\
+     99148f1f",
+    "a8ff9132d0eb15a936f2f08c8d049312eac76f8fa05078178aa1789dc76ccb52ce1c3db90ecb357af60eeb3ee851\
+     461107fe",
+    "c27297b27ad5e5630a54c3e6eae07eccf451bbcd991a30ecc70fc62bc9b04594b54fa98224e54fd47d9ac6c53ae5\
+     58a0933e",
+];
+const SECOND_CHILD_HEAD: &str = "a8ff";
+const GRANDCHILDREN: [&str; 2] = ["9132d0eb", "15a936f2"];
+const SECOND_CHILD_TAIL: &str = "f08c8d049312eac76f8fa05078178aa1789dc76ccb52ce1c3db90ecb357af60e\
+                                 eb3ee851461107fe";
+const PARENT_TAIL: &str = "2f03b4d2a2800d1933062742fe018cfe";
+
+fn decode(hex: &str) -> Vec<u8> {
+    assert_eq!(hex.len() % 2, 0);
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn take<G: RandomGenerator>(generator: &mut G, n_bytes: usize) -> Vec<u8> {
+    let output: Vec<u8> = generator.take(n_bytes).collect();
+    assert_eq!(output.len(), n_bytes);
+    output
+}
+
+/// Checks the batches generated by the block cipher against the vectors.
+pub fn test_batch_kat<BC: BlockCipher>() {
+    let mut block_cipher = BC::new(AesKey(FIPS_SEED.0), ByteOrder::LittleEndian);
+    assert_eq!(
+        block_cipher.generate_batch(AesIndex(0)).to_vec(),
+        decode(FIPS_BATCH_0)
+    );
+    assert_eq!(
+        block_cipher.generate_batch(AesIndex(1 << 64)).to_vec(),
+        decode(FIPS_BATCH_2_64)
+    );
+}
+
+/// Checks the batches generated by the block cipher in both byte orders.
+///
+/// The canonical order must match the vectors. The native order encodes the key and the counters
+/// as the canonical order encodes their byte-swapped values on big-endian hosts, and is the same
+/// as the canonical order on little-endian hosts.
+pub fn test_byte_order_kat<BC: BlockCipher>() {
+    let canonical_order = |value: u128| u128::from_le_bytes(value.to_ne_bytes());
+    let mut canonical = BC::new(AesKey(FIPS_SEED.0), ByteOrder::LittleEndian);
+    assert_eq!(
+        canonical.generate_batch(AesIndex(0)).to_vec(),
+        decode(FIPS_BATCH_0)
+    );
+    let mut native = BC::new(AesKey(FIPS_SEED.0), ByteOrder::Native);
+    let mut swapped = BC::new(
+        AesKey(canonical_order(FIPS_SEED.0)),
+        ByteOrder::LittleEndian,
+    );
+    let native_batch = native.generate_batch(AesIndex(0));
+    for (i, block) in native_batch
+        .chunks_exact(BYTES_PER_AES_CALL)
+        .enumerate()
+        .take(AES_CALLS_PER_BATCH)
+    {
+        let expected = swapped.generate_batch(AesIndex(canonical_order(i as u128)));
+        assert_eq!(block, &expected[..BYTES_PER_AES_CALL]);
+    }
+}
+
+/// Checks the streams outputted by fresh generators against the vectors.
+pub fn test_stream_kat<G: RandomGenerator>() {
+    for (seed, stream) in [(Seed(0), ZERO_SEED_STREAM), (FIPS_SEED, FIPS_SEED_STREAM)] {
+        let expected = decode(stream);
+        assert_eq!(take(&mut G::new(seed), expected.len()), expected);
+    }
+}
+
+/// Checks the streams outputted by the generators of a fork tree against the vectors.
+pub fn test_fork_kat<G: RandomGenerator>() {
+    let mut parent = G::new(FIPS_SEED);
+    assert_eq!(take(&mut parent, 3), decode(PARENT_HEAD));
+    let mut children: Vec<G> = parent
+        .try_fork(ChildrenCount(3), BytesPerChild(50))
+        .unwrap()
+        .collect();
+    assert_eq!(take(&mut children[0], 50), decode(CHILDREN[0]));
+    assert_eq!(take(&mut children[2], 50), decode(CHILDREN[2]));
+    assert_eq!(take(&mut parent, 16), decode(PARENT_TAIL));
+
+    let second_child = &mut children[1];
+    assert_eq!(take(second_child, 2), decode(SECOND_CHILD_HEAD));
+    let grandchildren = second_child
+        .try_fork(ChildrenCount(2), BytesPerChild(4))
+        .unwrap();
+    for (mut grandchild, expected) in grandchildren.zip(GRANDCHILDREN.iter()) {
+        assert_eq!(take(&mut grandchild, 4), decode(expected));
+        assert_eq!(grandchild.next(), None);
+    }
+    assert_eq!(take(second_child, 40), decode(SECOND_CHILD_TAIL));
+    assert_eq!(second_child.next(), None);
+}
+
+/// Checks the streams outputted by the children of a parallel fork against the vectors.
+#[cfg(feature = "parallel")]
+pub fn test_parallel_fork_kat<G: crate::generators::ParallelRandomGenerator>() {
+    use rayon::prelude::*;
+    let mut parent = G::new(FIPS_SEED);
+    assert_eq!(take(&mut parent, 3), decode(PARENT_HEAD));
+    let children: Vec<Vec<u8>> = parent
+        .par_try_fork(ChildrenCount(3), BytesPerChild(50))
+        .unwrap()
+        .map(|mut child| take(&mut child, 50))
+        .collect();
+    for (child, expected) in children.iter().zip(CHILDREN.iter()) {
+        assert_eq!(child, &decode(expected));
+    }
+    assert_eq!(take(&mut parent, 16), decode(PARENT_TAIL));
+}
@@ -14,9 +14,10 @@
 //!          t=0            t=1            t=2
 //! ```
 //!
-//! The [`AesCtrGenerator`] structure is generic over the AES block ciphers, which are
-//! represented by the [`AesBlockCipher`] trait. Consequently, implementers only need to implement
-//! the `AesBlockCipher` trait, to benefit from the whole api of the `AesCtrGenerator` structure.
+//! The [`AesCtrGenerator`] structure is generic over the block ciphers, which are represented by
+//! the [`BlockCipher`] trait. Consequently, implementers only need to implement the `BlockCipher`
+//! trait, to benefit from the whole api of the `AesCtrGenerator` structure. Besides the AES block
+//! ciphers, this trait is implemented by the ChaCha20 block function.
 //!
 //! In the following section, we give details on the implementation of this generic generator.
 //!
@@ -208,7 +209,7 @@ pub use index::*;
 mod states;
 pub use states::*;
 
-/// A module containing an abstraction for aes block ciphers.
+/// A module containing an abstraction for block ciphers.
 mod block_cipher;
 pub use block_cipher::*;
 
@@ -216,6 +217,10 @@ pub use block_cipher::*;
 mod generic;
 pub use generic::*;
 
+/// A module containing known-answer tests shared by the aes block ciphers.
+#[cfg(test)]
+pub mod kat;
+
 /// A module extending `generic` to the `rayon` paradigm.
 #[cfg(feature = "parallel")]
 mod parallel;
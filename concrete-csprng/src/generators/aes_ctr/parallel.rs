@@ -1,18 +1,18 @@
 use crate::generators::aes_ctr::{
-    AesBlockCipher, AesCtrGenerator, ChildrenClosure, State, TableIndex,
+    AesCtrGenerator, BlockCipher, ChildrenClosure, State, TableIndex,
 };
-use crate::generators::{BytesPerChild, ChildrenCount, ForkError};
+use crate::generators::{BytesPerChild, ChildrenCount, ForkError, KeyId};
 
 /// A type alias for the parallel children iterator type.
-pub type ParallelChildrenIterator<BlockCipher> = rayon::iter::Map<
+pub type ParallelChildrenIterator<Cipher> = rayon::iter::Map<
     rayon::iter::Zip<
         rayon::range::Iter<usize>,
-        rayon::iter::RepeatN<(BlockCipher, TableIndex, BytesPerChild)>,
+        rayon::iter::RepeatN<(Cipher, KeyId, TableIndex, BytesPerChild)>,
     >,
-    fn((usize, (BlockCipher, TableIndex, BytesPerChild))) -> AesCtrGenerator<BlockCipher>,
+    fn((usize, (Cipher, KeyId, TableIndex, BytesPerChild))) -> AesCtrGenerator<Cipher>,
 >;
 
-impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
+impl<Cipher: BlockCipher> AesCtrGenerator<Cipher> {
     /// Tries to fork the current generator into `n_child` generators each able to output
     /// `child_bytes` random bytes as a parallel iterator.
     ///
@@ -23,9 +23,9 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         &mut self,
         n_children: ChildrenCount,
         n_bytes: BytesPerChild,
-    ) -> Result<ParallelChildrenIterator<BlockCipher>, ForkError>
+    ) -> Result<ParallelChildrenIterator<Cipher>, ForkError>
     where
-        BlockCipher: Send + Sync,
+        Cipher: Send + Sync,
     {
         use rayon::prelude::*;
 
@@ -45,14 +45,14 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         let output = (0..n_children.0)
             .into_par_iter()
             .zip(rayon::iter::repeatn(
-                (self.block_cipher.clone(), first_index, n_bytes),
+                (self.block_cipher.clone(), self.key_id, first_index, n_bytes),
                 n_children.0,
             ))
             .map(
                 // This map is a little weird because we need to cast the closure to a fn pointer
-                // that matches the signature of `ChildrenIterator<BlockCipher>`. Unfortunately,
+                // that matches the signature of `ChildrenIterator<Cipher>`. Unfortunately,
                 // the compiler does not manage to coerce this one automatically.
-                (|(i, (block_cipher, first_index, n_bytes))| {
+                (|(i, (block_cipher, key_id, first_index, n_bytes))| {
                     // The first index to be outputted by the child is the `first_index` shifted by
                     // the proper amount of `child_bytes`.
                     let child_first_index = first_index.increased(n_bytes.0 * i);
@@ -60,10 +60,11 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
                     let child_bound_index = first_index.increased(n_bytes.0 * (i + 1));
                     AesCtrGenerator::from_block_cipher(
                         block_cipher,
+                        key_id,
                         child_first_index,
                         child_bound_index,
                     )
-                }) as ChildrenClosure<BlockCipher>,
+                }) as ChildrenClosure<Cipher>,
             );
         // The parent next index is the bound of the last child.
         let next_index = first_index.increased(n_bytes.0 * n_children.0);
@@ -85,7 +86,7 @@ pub mod aes_ctr_parallel_generic_tests {
     /// Check the property:
     ///     On a valid fork, the table index of the first child is the same as the table index of
     ///     the parent before the fork.
-    pub fn prop_fork_first_state_table_index<G: AesBlockCipher>() {
+    pub fn prop_fork_first_state_table_index<G: BlockCipher>() {
         for _ in 0..REPEATS {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
@@ -104,7 +105,7 @@ pub mod aes_ctr_parallel_generic_tests {
     /// Check the property:
     ///     On a valid fork, the table index of the first byte outputted by the parent after the
     ///     fork, is the bound of the last child of the fork.
-    pub fn prop_fork_last_bound_table_index<G: AesBlockCipher>() {
+    pub fn prop_fork_last_bound_table_index<G: BlockCipher>() {
         for _ in 0..REPEATS {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
@@ -124,7 +125,7 @@ pub mod aes_ctr_parallel_generic_tests {
 
     /// Check the property:
     ///     On a valid fork, the bound of the parent does not change.
-    pub fn prop_fork_parent_bound_table_index<G: AesBlockCipher>() {
+    pub fn prop_fork_parent_bound_table_index<G: BlockCipher>() {
         for _ in 0..REPEATS {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
@@ -143,7 +144,7 @@ pub mod aes_ctr_parallel_generic_tests {
     /// Check the property:
     ///     On a valid fork, the parent table index is increased of the number of children
     ///     multiplied by the number of bytes per child.
-    pub fn prop_fork_parent_state_table_index<G: AesBlockCipher>() {
+    pub fn prop_fork_parent_state_table_index<G: BlockCipher>() {
         for _ in 0..REPEATS {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
@@ -166,7 +167,7 @@ pub mod aes_ctr_parallel_generic_tests {
     /// Check the property:
     ///     On a valid fork, the bytes outputted by the children in the fork order form the same
     ///     sequence the parent would have had outputted no fork had happened.
-    pub fn prop_fork<G: AesBlockCipher>() {
+    pub fn prop_fork<G: BlockCipher>() {
         for _ in 0..1000 {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
@@ -187,7 +188,7 @@ pub mod aes_ctr_parallel_generic_tests {
     /// Check the property:
     ///     On a valid fork, all children got a number of remaining bytes equals to the number of
     ///     bytes per child given as fork input.
-    pub fn prop_fork_children_remaining_bytes<G: AesBlockCipher>() {
+    pub fn prop_fork_children_remaining_bytes<G: BlockCipher>() {
         for _ in 0..REPEATS {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
@@ -203,7 +204,7 @@ pub mod aes_ctr_parallel_generic_tests {
     /// Check the property:
     ///     On a valid fork, the number of remaining bytes of the parent is reduced by the
     ///     number of children multiplied by the number of bytes per child.
-    pub fn prop_fork_parent_remaining_bytes<G: AesBlockCipher>() {
+    pub fn prop_fork_parent_remaining_bytes<G: BlockCipher>() {
         for _ in 0..REPEATS {
             let (t, nc, nb, i) = any_valid_fork().next().unwrap();
             let k = any_key().next().unwrap();
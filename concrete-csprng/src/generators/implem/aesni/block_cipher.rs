@@ -1,4 +1,6 @@
-use crate::generators::aes_ctr::{AesBlockCipher, AesIndex, AesKey, BYTES_PER_BATCH};
+use crate::generators::aes_ctr::{
+    AesIndex, AesKey, BlockCipher, ByteOrder, BYTES_PER_AES_CALL, BYTES_PER_BATCH,
+};
 use std::arch::x86_64::{
     __m128i, _mm_aesenc_si128, _mm_aesenclast_si128, _mm_aeskeygenassist_si128, _mm_load_si128,
     _mm_shuffle_epi32, _mm_slli_si128, _mm_store_si128, _mm_xor_si128,
@@ -10,16 +12,21 @@ use std::mem::transmute;
 pub struct AesniBlockCipher {
     // The set of round keys used for the aes encryption
     round_keys: [__m128i; 11],
+    // The byte order of the key and the counters
+    byte_order: ByteOrder,
 }
 
-impl AesBlockCipher for AesniBlockCipher {
-    fn new(key: AesKey) -> AesniBlockCipher {
+impl BlockCipher for AesniBlockCipher {
+    fn new(key: AesKey, byte_order: ByteOrder) -> AesniBlockCipher {
         if is_x86_feature_detected!("aes")
             && is_x86_feature_detected!("rdseed")
             && is_x86_feature_detected!("sse2")
         {
-            let round_keys = generate_round_keys(key);
-            AesniBlockCipher { round_keys }
+            let round_keys = generate_round_keys(key, byte_order);
+            AesniBlockCipher {
+                round_keys,
+                byte_order,
+            }
         } else {
             panic!("One of the `aes`, `rdseed`, or `sse2` instructions set was not found")
         }
@@ -27,22 +34,22 @@ impl AesBlockCipher for AesniBlockCipher {
 
     fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
         si128arr_to_u8arr(aes_encrypt_many(
-            &u128_to_si128(aes_ctr),
-            &u128_to_si128(aes_ctr + 1),
-            &u128_to_si128(aes_ctr + 2),
-            &u128_to_si128(aes_ctr + 3),
-            &u128_to_si128(aes_ctr + 4),
-            &u128_to_si128(aes_ctr + 5),
-            &u128_to_si128(aes_ctr + 6),
-            &u128_to_si128(aes_ctr + 7),
+            &u8arr_to_si128(AesIndex(aes_ctr).to_bytes(self.byte_order)),
+            &u8arr_to_si128(AesIndex(aes_ctr + 1).to_bytes(self.byte_order)),
+            &u8arr_to_si128(AesIndex(aes_ctr + 2).to_bytes(self.byte_order)),
+            &u8arr_to_si128(AesIndex(aes_ctr + 3).to_bytes(self.byte_order)),
+            &u8arr_to_si128(AesIndex(aes_ctr + 4).to_bytes(self.byte_order)),
+            &u8arr_to_si128(AesIndex(aes_ctr + 5).to_bytes(self.byte_order)),
+            &u8arr_to_si128(AesIndex(aes_ctr + 6).to_bytes(self.byte_order)),
+            &u8arr_to_si128(AesIndex(aes_ctr + 7).to_bytes(self.byte_order)),
             &self.round_keys,
         ))
     }
 }
 
-fn generate_round_keys(key: AesKey) -> [__m128i; 11] {
+fn generate_round_keys(key: AesKey, byte_order: ByteOrder) -> [__m128i; 11] {
     // The secret key is a random value from rdseed.
-    let key = u128_to_si128(key.0);
+    let key = u8arr_to_si128(key.to_bytes(byte_order));
     let mut keys: [__m128i; 11] = [u128_to_si128(0); 11];
     aes_128_key_expansion(key, &mut keys);
     keys
@@ -160,6 +167,10 @@ fn aes_128_key_expansion(key: __m128i, keys: &mut [__m128i; 11]) {
     }
 }
 
+fn u8arr_to_si128(input: [u8; BYTES_PER_AES_CALL]) -> __m128i {
+    unsafe { transmute(input) }
+}
+
 fn u128_to_si128(input: u128) -> __m128i {
     unsafe { transmute(input) }
 }
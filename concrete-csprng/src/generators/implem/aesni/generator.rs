@@ -1,11 +1,40 @@
-use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
+use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ByteOrder, ChildrenIterator};
 use crate::generators::implem::aesni::block_cipher::AesniBlockCipher;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError, RandomGenerator};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator,
+    RestoreError, SkipError,
+};
 use crate::seeders::Seed;
 
 /// A random number generator using the `aesni` instructions.
 pub struct AesniRandomGenerator(pub(super) AesCtrGenerator<AesniBlockCipher>);
 
+impl AesniRandomGenerator {
+    /// Creates a generator encoding the seed and the counters in the given byte order.
+    ///
+    /// [`RandomGenerator::new`] uses the canonical [`ByteOrder::LittleEndian`] order. The
+    /// [`ByteOrder::Native`] order reproduces the streams of the previous versions of this crate
+    /// on big-endian hosts.
+    pub fn with_byte_order(seed: Seed, byte_order: ByteOrder) -> Self {
+        AesniRandomGenerator(AesCtrGenerator::with_byte_order(
+            AesKey(seed.0),
+            byte_order,
+            None,
+            None,
+        ))
+    }
+
+    /// Restores a generator created with [`AesniRandomGenerator::with_byte_order`].
+    pub fn restore_with_byte_order(
+        seed: Seed,
+        byte_order: ByteOrder,
+        state: GeneratorState,
+    ) -> Result<Self, RestoreError> {
+        AesCtrGenerator::restore_with_byte_order(AesKey(seed.0), byte_order, state)
+            .map(AesniRandomGenerator)
+    }
+}
+
 /// The children iterator used by [`AesniRandomGenerator`].
 ///
 /// Outputs children generators one by one.
@@ -36,6 +65,15 @@ impl RandomGenerator for AesniRandomGenerator {
             .try_fork(n_children, n_bytes)
             .map(AesniChildrenIterator)
     }
+    fn try_skip(&mut self, n_bytes: ByteCount) -> Result<(), SkipError> {
+        self.0.try_skip(n_bytes)
+    }
+    fn snapshot(&self) -> GeneratorState {
+        self.0.snapshot()
+    }
+    fn restore(seed: Seed, state: GeneratorState) -> Result<Self, RestoreError> {
+        AesCtrGenerator::restore(AesKey(seed.0), state).map(AesniRandomGenerator)
+    }
 }
 
 impl Iterator for AesniRandomGenerator {
@@ -48,9 +86,12 @@ impl Iterator for AesniRandomGenerator {
 
 #[cfg(test)]
 mod test {
-    use crate::generators::aes_ctr::aes_ctr_generic_test;
+    use crate::generators::aes_ctr::{aes_ctr_generic_test, kat};
     use crate::generators::implem::aesni::block_cipher::AesniBlockCipher;
-    use crate::generators::{generator_generic_test, AesniRandomGenerator};
+    use crate::generators::{
+        generator_generic_test, AesniRandomGenerator, ByteOrder, RandomGenerator,
+    };
+    use crate::seeders::Seed;
 
     #[test]
     fn prop_fork_first_state_table_index() {
@@ -102,6 +143,85 @@ mod test {
         generator_generic_test::test_fork_children::<AesniRandomGenerator>();
     }
 
+    #[test]
+    fn prop_skip_table_index() {
+        aes_ctr_generic_test::prop_skip_table_index::<AesniBlockCipher>();
+    }
+
+    #[test]
+    fn test_skip() {
+        generator_generic_test::test_skip::<AesniRandomGenerator>();
+    }
+
+    #[test]
+    fn test_skip_bounded() {
+        generator_generic_test::test_skip_bounded::<AesniRandomGenerator>();
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        generator_generic_test::test_snapshot_restore::<AesniRandomGenerator>();
+    }
+
+    #[test]
+    fn test_snapshot_restore_child() {
+        generator_generic_test::test_snapshot_restore_child::<AesniRandomGenerator>();
+    }
+
+    #[test]
+    fn test_restore_wrong_seed() {
+        generator_generic_test::test_restore_wrong_seed::<AesniRandomGenerator>();
+    }
+
+    #[test]
+    fn test_statistical_battery() {
+        generator_generic_test::test_statistical_battery::<AesniRandomGenerator>();
+    }
+
+    #[test]
+    fn test_batch_kat() {
+        kat::test_batch_kat::<AesniBlockCipher>();
+    }
+
+    #[test]
+    fn test_byte_order_kat() {
+        kat::test_byte_order_kat::<AesniBlockCipher>();
+    }
+
+    #[test]
+    fn test_with_byte_order() {
+        let seed = Seed(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+        let canonical: Vec<u8> = AesniRandomGenerator::new(seed).take(100).collect();
+        let little_endian: Vec<u8> =
+            AesniRandomGenerator::with_byte_order(seed, ByteOrder::LittleEndian)
+                .take(100)
+                .collect();
+        assert_eq!(canonical, little_endian);
+
+        let mut native = AesniRandomGenerator::with_byte_order(seed, ByteOrder::Native);
+        let head: Vec<u8> = native.by_ref().take(10).collect();
+        let state = native.snapshot();
+        let tail: Vec<u8> = native.take(90).collect();
+        let mut restored =
+            AesniRandomGenerator::restore_with_byte_order(seed, ByteOrder::Native, state).unwrap();
+        assert_eq!(restored.by_ref().take(90).collect::<Vec<u8>>(), tail);
+        if cfg!(target_endian = "little") {
+            assert_eq!([head, tail].concat(), canonical);
+        } else {
+            assert_ne!([head, tail].concat(), canonical);
+        }
+    }
+
+    #[test]
+    fn test_stream_kat() {
+        kat::test_stream_kat::<AesniRandomGenerator>();
+    }
+
+    #[test]
+    fn test_fork_kat() {
+        kat::test_fork_kat::<AesniRandomGenerator>();
+    }
+
     #[test]
     #[should_panic]
     fn test_bounded_panic() {
@@ -55,8 +55,9 @@ impl ParallelRandomGenerator for AesniRandomGenerator {
 #[cfg(test)]
 
 mod test {
-    use crate::generators::aes_ctr::aes_ctr_parallel_generic_tests;
+    use crate::generators::aes_ctr::{aes_ctr_parallel_generic_tests, kat};
     use crate::generators::implem::aesni::block_cipher::AesniBlockCipher;
+    use crate::generators::AesniRandomGenerator;
 
     #[test]
     fn prop_fork_first_state_table_index() {
@@ -92,4 +93,9 @@ mod test {
     fn prop_fork_parent_remaining_bytes() {
         aes_ctr_parallel_generic_tests::prop_fork_parent_remaining_bytes::<AesniBlockCipher>();
     }
+
+    #[test]
+    fn test_parallel_fork_kat() {
+        kat::test_parallel_fork_kat::<AesniRandomGenerator>();
+    }
 }
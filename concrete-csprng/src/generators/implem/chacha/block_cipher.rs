@@ -0,0 +1,241 @@
+use crate::generators::aes_ctr::{
+    AesIndex, AesKey, BlockCipher, ByteOrder, BYTES_PER_AES_CALL, BYTES_PER_BATCH,
+};
+
+// The number of bytes outputted by a single call to the ChaCha20 block function.
+const BYTES_PER_CHACHA_BLOCK: usize = 64;
+
+// The number of aes calls which fit in a ChaCha20 block.
+const AES_CALLS_PER_CHACHA_BLOCK: u128 = (BYTES_PER_CHACHA_BLOCK / BYTES_PER_AES_CALL) as u128;
+
+// The constants of the ChaCha20 state for a 256 bits key ("expand 32-byte k").
+const SIGMA: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+/// A block function based on the ChaCha20 stream cipher.
+///
+/// The counter mode generator only uses its block cipher as a keyed pseudo-random function of
+/// the aes index, which makes it possible to plug the ChaCha20 block function instead. The
+/// standard ChaCha20 block function of RFC 8439 is used, with the following mapping:
+///
+/// + The 256 bits key is made of the 16 little-endian bytes of the seed, followed by 16 zero
+///   bytes.
+/// + The 128 bits made of the block counter and the nonce of RFC 8439 are used together as a
+///   single block index: the counter is the lowest 32 bits of the index, and the nonce is made of
+///   the 96 upper bits of the index, in little-endian order. This is the same as the original
+///   ChaCha20 layout, as used by `rand_chacha`, with a 64 bits block counter equal to the lowest
+///   64 bits of the index, and a 64 bits stream identifier equal to the upper 64 bits.
+/// + Since a ChaCha20 block is 64 bytes long, the aes index `a` is mapped to the bytes
+///   `16 * (a % 4)` to `16 * (a % 4) + 15` of the ChaCha20 block `a / 4`.
+///
+/// Hence, the stream of a fresh generator is the ChaCha20 key stream of its key, with the block
+/// counter and the nonce starting at zero, stripped of its first byte.
+#[derive(Clone)]
+pub struct ChachaBlockCipher {
+    // The key, as little endian words.
+    key: [u32; 8],
+}
+
+impl ChachaBlockCipher {
+    // Creates a block function from a full 256 bits key, given as little endian words.
+    fn from_key_words(key: [u32; 8]) -> ChachaBlockCipher {
+        ChachaBlockCipher { key }
+    }
+
+    // Computes the ChaCha20 block of a given index.
+    fn block(&self, index: u128) -> [u8; BYTES_PER_CHACHA_BLOCK] {
+        let k = self.key;
+        let state = [
+            SIGMA[0],
+            SIGMA[1],
+            SIGMA[2],
+            SIGMA[3],
+            k[0],
+            k[1],
+            k[2],
+            k[3],
+            k[4],
+            k[5],
+            k[6],
+            k[7],
+            index as u32,
+            (index >> 32) as u32,
+            (index >> 64) as u32,
+            (index >> 96) as u32,
+        ];
+        chacha20_block(&state)
+    }
+}
+
+impl BlockCipher for ChachaBlockCipher {
+    // The ChaCha20 block function works on 32 bits words, hence does not depend on the byte order.
+    fn new(key: AesKey, _byte_order: ByteOrder) -> ChachaBlockCipher {
+        let key = key.0;
+        ChachaBlockCipher::from_key_words([
+            key as u32,
+            (key >> 32) as u32,
+            (key >> 64) as u32,
+            (key >> 96) as u32,
+            0,
+            0,
+            0,
+            0,
+        ])
+    }
+
+    fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
+        let mut output = [0u8; BYTES_PER_BATCH];
+        let mut block_index = aes_ctr / AES_CALLS_PER_CHACHA_BLOCK;
+        let mut block = self.block(block_index);
+        for (i, chunk) in output.chunks_exact_mut(BYTES_PER_AES_CALL).enumerate() {
+            let aes_index = aes_ctr.wrapping_add(i as u128);
+            // A batch spans at most three ChaCha20 blocks, which are computed when needed.
+            if aes_index / AES_CALLS_PER_CHACHA_BLOCK != block_index {
+                block_index = aes_index / AES_CALLS_PER_CHACHA_BLOCK;
+                block = self.block(block_index);
+            }
+            let offset = (aes_index % AES_CALLS_PER_CHACHA_BLOCK) as usize * BYTES_PER_AES_CALL;
+            chunk.copy_from_slice(&block[offset..offset + BYTES_PER_AES_CALL]);
+        }
+        output
+    }
+}
+
+// The ChaCha quarter round, as defined in RFC 8439.
+#[inline(always)]
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+// The ChaCha20 block function, as defined in RFC 8439, applied to a full input state.
+fn chacha20_block(input: &[u32; 16]) -> [u8; BYTES_PER_CHACHA_BLOCK] {
+    let mut state = *input;
+    for _ in 0..10 {
+        // Column rounds
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        // Diagonal rounds
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+    let mut output = [0u8; BYTES_PER_CHACHA_BLOCK];
+    for (i, chunk) in output.chunks_exact_mut(4).enumerate() {
+        chunk.copy_from_slice(&state[i].wrapping_add(input[i]).to_le_bytes());
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_quarter_round() {
+        // Test vector from the section 2.1.1 of RFC 8439
+        let mut state = [0u32; 16];
+        state[0] = 0x1111_1111;
+        state[1] = 0x0102_0304;
+        state[2] = 0x9b8d_6f43;
+        state[3] = 0x0123_4567;
+        quarter_round(&mut state, 0, 1, 2, 3);
+        assert_eq!(
+            &state[0..4],
+            &[0xea2a_92f4, 0xcb1c_f8ce, 0x4581_472e, 0x5881_c4bb]
+        );
+    }
+
+    #[test]
+    fn test_chacha20_block() {
+        // Test vector from the section 2.3.2 of RFC 8439
+        let input = [
+            0x6170_7865,
+            0x3320_646e,
+            0x7962_2d32,
+            0x6b20_6574,
+            0x0302_0100,
+            0x0706_0504,
+            0x0b0a_0908,
+            0x0f0e_0d0c,
+            0x1312_1110,
+            0x1716_1514,
+            0x1b1a_1918,
+            0x1f1e_1d1c,
+            0x0000_0001,
+            0x0900_0000,
+            0x4a00_0000,
+            0x0000_0000,
+        ];
+        let expected: [u8; BYTES_PER_CHACHA_BLOCK] = [
+            0x10, 0xf1, 0xe7, 0xe4, 0xd1, 0x3b, 0x59, 0x15, 0x50, 0x0f, 0xdd, 0x1f, 0xa3, 0x20,
+            0x71, 0xc4, 0xc7, 0xd1, 0xf4, 0xc7, 0x33, 0xc0, 0x68, 0x03, 0x04, 0x22, 0xaa, 0x9a,
+            0xc3, 0xd4, 0x6c, 0x4e, 0xd2, 0x82, 0x64, 0x46, 0x07, 0x9f, 0xaa, 0x09, 0x14, 0xc2,
+            0xd7, 0x05, 0xd9, 0x8b, 0x02, 0xa2, 0xb5, 0x12, 0x9c, 0xd1, 0xde, 0x16, 0x4e, 0xb9,
+            0xcb, 0xd0, 0x83, 0xe8, 0xa2, 0x50, 0x3c, 0x4e,
+        ];
+        assert_eq!(chacha20_block(&input), expected);
+    }
+
+    #[test]
+    fn test_chacha20_key_stream() {
+        // Test vector from the section 2.4.2 of RFC 8439: the key stream is the ciphertext xored
+        // with the plaintext. The counter 1 and the nonce 000000000000004a00000000 give the block
+        // index 1 + 0x4a000000 * 2^64, whose first aes index is four times larger.
+        let key = [
+            0x0302_0100,
+            0x0706_0504,
+            0x0b0a_0908,
+            0x0f0e_0d0c,
+            0x1312_1110,
+            0x1716_1514,
+            0x1b1a_1918,
+            0x1f1e_1d1c,
+        ];
+        let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you only one \
+                          tip for the future, sunscreen would be it.";
+        let ciphertext: [u8; 114] = [
+            0x6e, 0x2e, 0x35, 0x9a, 0x25, 0x68, 0xf9, 0x80, 0x41, 0xba, 0x07, 0x28, 0xdd, 0x0d,
+            0x69, 0x81, 0xe9, 0x7e, 0x7a, 0xec, 0x1d, 0x43, 0x60, 0xc2, 0x0a, 0x27, 0xaf, 0xcc,
+            0xfd, 0x9f, 0xae, 0x0b, 0xf9, 0x1b, 0x65, 0xc5, 0x52, 0x47, 0x33, 0xab, 0x8f, 0x59,
+            0x3d, 0xab, 0xcd, 0x62, 0xb3, 0x57, 0x16, 0x39, 0xd6, 0x24, 0xe6, 0x51, 0x52, 0xab,
+            0x8f, 0x53, 0x0c, 0x35, 0x9f, 0x08, 0x61, 0xd8, 0x07, 0xca, 0x0d, 0xbf, 0x50, 0x0d,
+            0x6a, 0x61, 0x56, 0xa3, 0x8e, 0x08, 0x8a, 0x22, 0xb6, 0x5e, 0x52, 0xbc, 0x51, 0x4d,
+            0x16, 0xcc, 0xf8, 0x06, 0x81, 0x8c, 0xe9, 0x1a, 0xb7, 0x79, 0x37, 0x36, 0x5a, 0xf9,
+            0x0b, 0xbf, 0x74, 0xa3, 0x5b, 0xe6, 0xb4, 0x0b, 0x8e, 0xed, 0xf2, 0x78, 0x5e, 0x42,
+            0x87, 0x4d,
+        ];
+        let block_index = 1 + (0x4a00_0000u128 << 64);
+        let mut cipher = ChachaBlockCipher::from_key_words(key);
+        let batch = cipher.generate_batch(AesIndex(block_index * AES_CALLS_PER_CHACHA_BLOCK));
+        let key_stream: Vec<u8> = plaintext
+            .iter()
+            .zip(ciphertext.iter())
+            .map(|(p, c)| p ^ c)
+            .collect();
+        assert_eq!(&batch[..key_stream.len()], &key_stream[..]);
+    }
+
+    #[test]
+    fn test_batches_are_consistent() {
+        // Checks that batches starting at any aes index are made of the same aes calls.
+        let mut cipher = ChachaBlockCipher::new(
+            AesKey(0x0f0e_0d0c_0b0a_0908_0706_0504_0302_0100),
+            ByteOrder::LittleEndian,
+        );
+        let reference = cipher.generate_batch(AesIndex(0));
+        for shift in 1..8 {
+            let batch = cipher.generate_batch(AesIndex(shift));
+            let shift = shift as usize * BYTES_PER_AES_CALL;
+            assert_eq!(&batch[..BYTES_PER_BATCH - shift], &reference[shift..]);
+        }
+    }
+}
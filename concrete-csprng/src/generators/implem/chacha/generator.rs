@@ -0,0 +1,173 @@
+use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
+use crate::generators::implem::chacha::block_cipher::ChachaBlockCipher;
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator,
+    RestoreError, SkipError,
+};
+use crate::seeders::Seed;
+
+/// A random number generator using the ChaCha20 stream cipher.
+pub struct ChachaRandomGenerator(pub(super) AesCtrGenerator<ChachaBlockCipher>);
+
+/// The children iterator used by [`ChachaRandomGenerator`].
+///
+/// Outputs children generators one by one.
+pub struct ChachaChildrenIterator(ChildrenIterator<ChachaBlockCipher>);
+
+impl Iterator for ChachaChildrenIterator {
+    type Item = ChachaRandomGenerator;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(ChachaRandomGenerator)
+    }
+}
+
+impl RandomGenerator for ChachaRandomGenerator {
+    type ChildrenIter = ChachaChildrenIterator;
+    fn new(seed: Seed) -> Self {
+        ChachaRandomGenerator(AesCtrGenerator::new(AesKey(seed.0), None, None))
+    }
+    fn remaining_bytes(&self) -> ByteCount {
+        self.0.remaining_bytes()
+    }
+    fn try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ChildrenIter, ForkError> {
+        self.0
+            .try_fork(n_children, n_bytes)
+            .map(ChachaChildrenIterator)
+    }
+    fn try_skip(&mut self, n_bytes: ByteCount) -> Result<(), SkipError> {
+        self.0.try_skip(n_bytes)
+    }
+    fn snapshot(&self) -> GeneratorState {
+        self.0.snapshot()
+    }
+    fn restore(seed: Seed, state: GeneratorState) -> Result<Self, RestoreError> {
+        AesCtrGenerator::restore(AesKey(seed.0), state).map(ChachaRandomGenerator)
+    }
+}
+
+impl Iterator for ChachaRandomGenerator {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generators::aes_ctr::aes_ctr_generic_test;
+    use crate::generators::generator_generic_test;
+
+    #[test]
+    fn prop_fork_first_state_table_index() {
+        aes_ctr_generic_test::prop_fork_first_state_table_index::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_last_bound_table_index() {
+        aes_ctr_generic_test::prop_fork_last_bound_table_index::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_bound_table_index() {
+        aes_ctr_generic_test::prop_fork_parent_bound_table_index::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_state_table_index() {
+        aes_ctr_generic_test::prop_fork_parent_state_table_index::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork() {
+        aes_ctr_generic_test::prop_fork::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_children_remaining_bytes() {
+        aes_ctr_generic_test::prop_fork_children_remaining_bytes::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_remaining_bytes() {
+        aes_ctr_generic_test::prop_fork_parent_remaining_bytes::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn test_uniformity() {
+        generator_generic_test::test_roughly_uniform::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_fork() {
+        generator_generic_test::test_fork_children::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_generator_determinism() {
+        generator_generic_test::test_generator_determinism::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    fn prop_skip_table_index() {
+        aes_ctr_generic_test::prop_skip_table_index::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn test_skip() {
+        generator_generic_test::test_skip::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_skip_bounded() {
+        generator_generic_test::test_skip_bounded::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        generator_generic_test::test_snapshot_restore::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_snapshot_restore_child() {
+        generator_generic_test::test_snapshot_restore_child::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_restore_wrong_seed() {
+        generator_generic_test::test_restore_wrong_seed::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_chacha20_stream() {
+        // The stream of a fresh generator is the standard ChaCha20 key stream, without its first
+        // byte. The key is made of the little endian bytes of the seed, padded with zeros.
+        use rand_chacha::rand_core::{RngCore, SeedableRng};
+        let seed = Seed(0x0f0e_0d0c_0b0a_0908_0706_0504_0302_0100);
+        let mut key = [0u8; 32];
+        key[..16].copy_from_slice(&seed.0.to_le_bytes());
+        let mut expected = vec![0u8; 1 << 12];
+        rand_chacha::ChaCha20Rng::from_seed(key).fill_bytes(&mut expected);
+        let actual: Vec<u8> = ChachaRandomGenerator::new(seed)
+            .take(expected.len() - 1)
+            .collect();
+        assert_eq!(actual, expected[1..]);
+    }
+
+    #[test]
+    fn test_statistical_battery() {
+        generator_generic_test::test_statistical_battery::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bounded_panic() {
+        generator_generic_test::test_bounded_none_should_panic::<ChachaRandomGenerator>();
+    }
+}
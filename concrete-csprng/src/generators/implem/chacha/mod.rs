@@ -0,0 +1,19 @@
+//! A module using the ChaCha20 stream cipher to implement a random number generator.
+//!
+//! This generator does not rely on the AES block cipher at all, which makes it an independent
+//! construction, and a portable fallback faster than the software AES on platforms without
+//! dedicated instructions (see the `portable` benchmark).
+//!
+//! The output is the standard ChaCha20 key stream of RFC 8439, keyed with the seed. The mapping of
+//! the seed and of the table index to the ChaCha20 key, counter and nonce is documented on the
+//! block function.
+
+mod block_cipher;
+
+mod generator;
+pub use generator::*;
+
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::*;
@@ -7,3 +7,8 @@ pub use aesni::*;
 mod soft;
 #[cfg(feature = "generator_soft")]
 pub use soft::*;
+
+#[cfg(feature = "generator_chacha")]
+mod chacha;
+#[cfg(feature = "generator_chacha")]
+pub use chacha::*;
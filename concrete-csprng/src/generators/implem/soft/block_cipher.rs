@@ -1,22 +1,24 @@
 use crate::generators::aes_ctr::{
-    AesBlockCipher, AesIndex, AesKey, AES_CALLS_PER_BATCH, BYTES_PER_AES_CALL, BYTES_PER_BATCH,
+    AesIndex, AesKey, BlockCipher, ByteOrder, AES_CALLS_PER_BATCH, BYTES_PER_AES_CALL,
+    BYTES_PER_BATCH,
 };
 use aes_soft::cipher::generic_array::GenericArray;
-use aes_soft::cipher::{BlockCipher, NewBlockCipher};
+use aes_soft::cipher::{BlockCipher as _, NewBlockCipher};
 use aes_soft::Aes128;
 
 #[derive(Clone)]
 pub struct SoftwareBlockCipher {
     // Aes structure
     aes: Aes128,
+    // The byte order of the counters
+    byte_order: ByteOrder,
 }
 
-impl AesBlockCipher for SoftwareBlockCipher {
-    fn new(key: AesKey) -> SoftwareBlockCipher {
-        let key: [u8; BYTES_PER_AES_CALL] = key.0.to_ne_bytes();
-        let key = GenericArray::clone_from_slice(&key[..]);
+impl BlockCipher for SoftwareBlockCipher {
+    fn new(key: AesKey, byte_order: ByteOrder) -> SoftwareBlockCipher {
+        let key = GenericArray::clone_from_slice(&key.to_bytes(byte_order)[..]);
         let aes = Aes128::new(&key);
-        SoftwareBlockCipher { aes }
+        SoftwareBlockCipher { aes, byte_order }
     }
 
     fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
@@ -30,6 +32,7 @@ impl AesBlockCipher for SoftwareBlockCipher {
             aes_ctr + 6,
             aes_ctr + 7,
             &self.aes,
+            self.byte_order,
         )
     }
 }
@@ -47,15 +50,16 @@ fn aes_encrypt_many(
     message_7: u128,
     message_8: u128,
     cipher: &Aes128,
+    byte_order: ByteOrder,
 ) -> [u8; BYTES_PER_BATCH] {
-    let mut b1 = GenericArray::clone_from_slice(&message_1.to_ne_bytes()[..]);
-    let mut b2 = GenericArray::clone_from_slice(&message_2.to_ne_bytes()[..]);
-    let mut b3 = GenericArray::clone_from_slice(&message_3.to_ne_bytes()[..]);
-    let mut b4 = GenericArray::clone_from_slice(&message_4.to_ne_bytes()[..]);
-    let mut b5 = GenericArray::clone_from_slice(&message_5.to_ne_bytes()[..]);
-    let mut b6 = GenericArray::clone_from_slice(&message_6.to_ne_bytes()[..]);
-    let mut b7 = GenericArray::clone_from_slice(&message_7.to_ne_bytes()[..]);
-    let mut b8 = GenericArray::clone_from_slice(&message_8.to_ne_bytes()[..]);
+    let mut b1 = GenericArray::clone_from_slice(&AesIndex(message_1).to_bytes(byte_order)[..]);
+    let mut b2 = GenericArray::clone_from_slice(&AesIndex(message_2).to_bytes(byte_order)[..]);
+    let mut b3 = GenericArray::clone_from_slice(&AesIndex(message_3).to_bytes(byte_order)[..]);
+    let mut b4 = GenericArray::clone_from_slice(&AesIndex(message_4).to_bytes(byte_order)[..]);
+    let mut b5 = GenericArray::clone_from_slice(&AesIndex(message_5).to_bytes(byte_order)[..]);
+    let mut b6 = GenericArray::clone_from_slice(&AesIndex(message_6).to_bytes(byte_order)[..]);
+    let mut b7 = GenericArray::clone_from_slice(&AesIndex(message_7).to_bytes(byte_order)[..]);
+    let mut b8 = GenericArray::clone_from_slice(&AesIndex(message_8).to_bytes(byte_order)[..]);
 
     cipher.encrypt_block(&mut b1);
     cipher.encrypt_block(&mut b2);
@@ -95,20 +99,29 @@ mod test {
         // Checks that encrypting many plaintext at the same time gives the correct output.
         let key: [u8; BYTES_PER_AES_CALL] = CIPHER_KEY.to_ne_bytes();
         let aes = Aes128::new(&GenericArray::from(key));
-        let ciphertexts = aes_encrypt_many(
-            PLAINTEXT, PLAINTEXT, PLAINTEXT, PLAINTEXT, PLAINTEXT, PLAINTEXT, PLAINTEXT, PLAINTEXT,
-            &aes,
-        );
-        let ciphertexts: [u8; BYTES_PER_BATCH] = ciphertexts[..].try_into().unwrap();
-        for i in 0..8 {
-            assert_eq!(
-                u128::from_ne_bytes(
-                    ciphertexts[BYTES_PER_AES_CALL * i..BYTES_PER_AES_CALL * (i + 1)]
-                        .try_into()
-                        .unwrap()
-                ),
-                CIPHERTEXT
+        // The messages are encoded in the byte order of the block cipher.
+        for (byte_order, message) in [
+            (ByteOrder::Native, PLAINTEXT),
+            (
+                ByteOrder::LittleEndian,
+                u128::from_le_bytes(PLAINTEXT.to_ne_bytes()),
+            ),
+        ] {
+            let ciphertexts = aes_encrypt_many(
+                message, message, message, message, message, message, message, message, &aes,
+                byte_order,
             );
+            let ciphertexts: [u8; BYTES_PER_BATCH] = ciphertexts[..].try_into().unwrap();
+            for i in 0..8 {
+                assert_eq!(
+                    u128::from_ne_bytes(
+                        ciphertexts[BYTES_PER_AES_CALL * i..BYTES_PER_AES_CALL * (i + 1)]
+                            .try_into()
+                            .unwrap()
+                    ),
+                    CIPHERTEXT
+                );
+            }
         }
     }
 }
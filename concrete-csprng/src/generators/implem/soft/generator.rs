@@ -1,11 +1,40 @@
-use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
+use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ByteOrder, ChildrenIterator};
 use crate::generators::implem::soft::block_cipher::SoftwareBlockCipher;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError, RandomGenerator};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator,
+    RestoreError, SkipError,
+};
 use crate::seeders::Seed;
 
 /// A random number generator using a software implementation.
 pub struct SoftwareRandomGenerator(pub(super) AesCtrGenerator<SoftwareBlockCipher>);
 
+impl SoftwareRandomGenerator {
+    /// Creates a generator encoding the seed and the counters in the given byte order.
+    ///
+    /// [`RandomGenerator::new`] uses the canonical [`ByteOrder::LittleEndian`] order. The
+    /// [`ByteOrder::Native`] order reproduces the streams of the previous versions of this crate
+    /// on big-endian hosts.
+    pub fn with_byte_order(seed: Seed, byte_order: ByteOrder) -> Self {
+        SoftwareRandomGenerator(AesCtrGenerator::with_byte_order(
+            AesKey(seed.0),
+            byte_order,
+            None,
+            None,
+        ))
+    }
+
+    /// Restores a generator created with [`SoftwareRandomGenerator::with_byte_order`].
+    pub fn restore_with_byte_order(
+        seed: Seed,
+        byte_order: ByteOrder,
+        state: GeneratorState,
+    ) -> Result<Self, RestoreError> {
+        AesCtrGenerator::restore_with_byte_order(AesKey(seed.0), byte_order, state)
+            .map(SoftwareRandomGenerator)
+    }
+}
+
 /// The children iterator used by [`SoftwareRandomGenerator`].
 ///
 /// Outputs children generators one by one.
@@ -36,6 +65,15 @@ impl RandomGenerator for SoftwareRandomGenerator {
             .try_fork(n_children, n_bytes)
             .map(SoftwareChildrenIterator)
     }
+    fn try_skip(&mut self, n_bytes: ByteCount) -> Result<(), SkipError> {
+        self.0.try_skip(n_bytes)
+    }
+    fn snapshot(&self) -> GeneratorState {
+        self.0.snapshot()
+    }
+    fn restore(seed: Seed, state: GeneratorState) -> Result<Self, RestoreError> {
+        AesCtrGenerator::restore(AesKey(seed.0), state).map(SoftwareRandomGenerator)
+    }
 }
 
 impl Iterator for SoftwareRandomGenerator {
@@ -49,7 +87,7 @@ impl Iterator for SoftwareRandomGenerator {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::generators::aes_ctr::aes_ctr_generic_test;
+    use crate::generators::aes_ctr::{aes_ctr_generic_test, kat};
     use crate::generators::generator_generic_test;
 
     #[test]
@@ -102,6 +140,86 @@ mod test {
         generator_generic_test::test_generator_determinism::<SoftwareRandomGenerator>();
     }
 
+    #[test]
+    fn prop_skip_table_index() {
+        aes_ctr_generic_test::prop_skip_table_index::<SoftwareBlockCipher>();
+    }
+
+    #[test]
+    fn test_skip() {
+        generator_generic_test::test_skip::<SoftwareRandomGenerator>();
+    }
+
+    #[test]
+    fn test_skip_bounded() {
+        generator_generic_test::test_skip_bounded::<SoftwareRandomGenerator>();
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        generator_generic_test::test_snapshot_restore::<SoftwareRandomGenerator>();
+    }
+
+    #[test]
+    fn test_snapshot_restore_child() {
+        generator_generic_test::test_snapshot_restore_child::<SoftwareRandomGenerator>();
+    }
+
+    #[test]
+    fn test_restore_wrong_seed() {
+        generator_generic_test::test_restore_wrong_seed::<SoftwareRandomGenerator>();
+    }
+
+    #[test]
+    fn test_statistical_battery() {
+        generator_generic_test::test_statistical_battery::<SoftwareRandomGenerator>();
+    }
+
+    #[test]
+    fn test_batch_kat() {
+        kat::test_batch_kat::<SoftwareBlockCipher>();
+    }
+
+    #[test]
+    fn test_byte_order_kat() {
+        kat::test_byte_order_kat::<SoftwareBlockCipher>();
+    }
+
+    #[test]
+    fn test_with_byte_order() {
+        let seed = Seed(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+        let canonical: Vec<u8> = SoftwareRandomGenerator::new(seed).take(100).collect();
+        let little_endian: Vec<u8> =
+            SoftwareRandomGenerator::with_byte_order(seed, ByteOrder::LittleEndian)
+                .take(100)
+                .collect();
+        assert_eq!(canonical, little_endian);
+
+        let mut native = SoftwareRandomGenerator::with_byte_order(seed, ByteOrder::Native);
+        let head: Vec<u8> = native.by_ref().take(10).collect();
+        let state = native.snapshot();
+        let tail: Vec<u8> = native.take(90).collect();
+        let mut restored =
+            SoftwareRandomGenerator::restore_with_byte_order(seed, ByteOrder::Native, state)
+                .unwrap();
+        assert_eq!(restored.by_ref().take(90).collect::<Vec<u8>>(), tail);
+        if cfg!(target_endian = "little") {
+            assert_eq!([head, tail].concat(), canonical);
+        } else {
+            assert_ne!([head, tail].concat(), canonical);
+        }
+    }
+
+    #[test]
+    fn test_stream_kat() {
+        kat::test_stream_kat::<SoftwareRandomGenerator>();
+    }
+
+    #[test]
+    fn test_fork_kat() {
+        kat::test_fork_kat::<SoftwareRandomGenerator>();
+    }
+
     #[test]
     #[should_panic]
     fn test_bounded_panic() {
@@ -55,7 +55,7 @@ impl ParallelRandomGenerator for SoftwareRandomGenerator {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::generators::aes_ctr::aes_ctr_parallel_generic_tests;
+    use crate::generators::aes_ctr::{aes_ctr_parallel_generic_tests, kat};
 
     #[test]
     fn prop_fork_first_state_table_index() {
@@ -91,4 +91,9 @@ mod test {
     fn prop_fork_parent_remaining_bytes() {
         aes_ctr_parallel_generic_tests::prop_fork_parent_remaining_bytes::<SoftwareBlockCipher>();
     }
+
+    #[test]
+    fn test_parallel_fork_kat() {
+        kat::test_parallel_fork_kat::<SoftwareRandomGenerator>();
+    }
 }
@@ -1,7 +1,9 @@
 //! A module containing random generators objects.
 //!
 //! See [crate-level](`crate`) explanations.
+use crate::generators::aes_ctr::{AesIndex, ByteIndex, TableIndex, BYTES_PER_AES_CALL};
 use crate::seeders::Seed;
+use std::convert::TryInto;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
@@ -51,6 +53,126 @@ impl Display for ForkError {
 }
 impl Error for ForkError {}
 
+/// An error occuring when skipping bytes of a generator.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SkipError {
+    SkipTooLarge,
+}
+
+impl Display for SkipError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SkipError::SkipTooLarge => {
+                write!(f, "The generator would have to skip bytes after its bound.")
+            }
+        }
+    }
+}
+impl Error for SkipError {}
+
+/// An identifier of the key used by a generator.
+///
+/// It allows to check that a generator state is restored with the proper seed, without revealing
+/// the seed itself, nor any byte of the stream generated from it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyId(pub u128);
+
+/// A snapshot of the position of a generator in its pseudo-random stream.
+///
+/// The snapshot does not contain any secret: the generator can only be restored from it with the
+/// seed it was created with, using [`RandomGenerator::restore`]. It can be serialized to, and
+/// deserialized from, a fixed-size array of bytes with [`GeneratorState::to_bytes`] and
+/// [`GeneratorState::from_bytes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GeneratorState {
+    // The identifier of the key used by the generator.
+    pub(crate) key_id: KeyId,
+    // The table index of the latest outputted byte.
+    pub(crate) table_index: TableIndex,
+    // The bound, that is the first illegal index.
+    pub(crate) bound: TableIndex,
+}
+
+impl GeneratorState {
+    /// The number of bytes of a serialized generator state.
+    pub const SERIALIZED_SIZE: usize = 50;
+
+    /// Returns the identifier of the key used by the generator.
+    pub fn key_id(&self) -> KeyId {
+        self.key_id
+    }
+
+    /// Serializes the state to an array of bytes.
+    ///
+    /// The layout is the following, with all integers encoded in little endian:
+    /// + bytes 0 to 15: the key id,
+    /// + bytes 16 to 31: the aes index of the latest outputted byte,
+    /// + byte 32: the byte index of the latest outputted byte,
+    /// + bytes 33 to 48: the aes index of the bound,
+    /// + byte 49: the byte index of the bound.
+    pub fn to_bytes(&self) -> [u8; GeneratorState::SERIALIZED_SIZE] {
+        let mut bytes = [0u8; GeneratorState::SERIALIZED_SIZE];
+        bytes[0..16].copy_from_slice(&self.key_id.0.to_le_bytes());
+        bytes[16..32].copy_from_slice(&self.table_index.aes_index.0.to_le_bytes());
+        bytes[32] = self.table_index.byte_index.0 as u8;
+        bytes[33..49].copy_from_slice(&self.bound.aes_index.0.to_le_bytes());
+        bytes[49] = self.bound.byte_index.0 as u8;
+        bytes
+    }
+
+    /// Deserializes a state from an array of bytes produced by [`GeneratorState::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<GeneratorState, RestoreError> {
+        if bytes.len() != GeneratorState::SERIALIZED_SIZE {
+            return Err(RestoreError::InvalidEncoding);
+        }
+        let read_u128 =
+            |range: std::ops::Range<usize>| u128::from_le_bytes(bytes[range].try_into().unwrap());
+        let read_table_index = |aes_index: u128, byte_index: u8| {
+            if (byte_index as usize) < BYTES_PER_AES_CALL {
+                Ok(TableIndex::new(
+                    AesIndex(aes_index),
+                    ByteIndex(byte_index as usize),
+                ))
+            } else {
+                Err(RestoreError::InvalidEncoding)
+            }
+        };
+        Ok(GeneratorState {
+            key_id: KeyId(read_u128(0..16)),
+            table_index: read_table_index(read_u128(16..32), bytes[32])?,
+            bound: read_table_index(read_u128(33..49), bytes[49])?,
+        })
+    }
+}
+
+/// An error occuring when restoring a generator from a snapshot of its state.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RestoreError {
+    InvalidEncoding,
+    KeyMismatch,
+    IndexAfterBound,
+}
+
+impl Display for RestoreError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestoreError::InvalidEncoding => {
+                write!(f, "The bytes do not encode a valid generator state.")
+            }
+            RestoreError::KeyMismatch => {
+                write!(
+                    f,
+                    "The state was not taken on a generator created with the given seed."
+                )
+            }
+            RestoreError::IndexAfterBound => {
+                write!(f, "The index of the state is located after its bound.")
+            }
+        }
+    }
+}
+impl Error for RestoreError {}
+
 /// A trait for cryptographically secure pseudo-random generators.
 ///
 /// See the [crate-level](#crate) documentation for details.
@@ -95,6 +217,38 @@ pub trait RandomGenerator: Iterator<Item = u8> {
         n_children: ChildrenCount,
         n_bytes: BytesPerChild,
     ) -> Result<Self::ChildrenIter, ForkError>;
+
+    /// Tries to skip the next `n_bytes` bytes of the stream, without generating them.
+    ///
+    /// Since the stream is generated in counter mode, this operation is cheap whatever the number
+    /// of bytes skipped. After a successful call, the next byte outputted by the generator is the
+    /// one it would have outputted after `n_bytes` calls to `next_byte`.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// To be successful, the number of remaining bytes of the generator must be at least
+    /// `n_bytes`. Otherwise, the generator is left untouched.
+    fn try_skip(&mut self, n_bytes: ByteCount) -> Result<(), SkipError>;
+
+    /// Returns a snapshot of the current position of the generator in its stream.
+    fn snapshot(&self) -> GeneratorState;
+
+    /// Restores a generator from the seed it was created with, and a snapshot of its state.
+    ///
+    /// The restored generator outputs the exact same bytes as the generator would have outputted
+    /// after the snapshot was taken, up to the same bound. Since the children of a fork use the
+    /// same key as their parent, their state can be restored from the seed of the root generator.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// The snapshot only records the position of the generator in the stream. If both the
+    /// restored generator and the generator the snapshot was taken on are used afterwards, they
+    /// output the same bytes, which is usually not what you want.
+    fn restore(seed: Seed, state: GeneratorState) -> Result<Self, RestoreError>
+    where
+        Self: Sized;
 }
 
 /// A trait extending [`RandomGenerator`] to the parallel iterators of `rayon`.
@@ -119,6 +273,7 @@ pub trait ParallelRandomGenerator: RandomGenerator {
 }
 
 mod aes_ctr;
+pub use aes_ctr::ByteOrder;
 
 mod implem;
 pub use implem::*;
@@ -209,6 +364,128 @@ pub mod generator_generic_test {
         }
     }
 
+    /// Checks that skipping bytes yields the same stream as generating them.
+    pub fn test_skip<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let seed = any_seed().next().unwrap();
+            let mut skipping_generator = G::new(seed);
+            let mut generating_generator = G::new(seed);
+            let n_bytes = rand::thread_rng().gen::<usize>() % 4096;
+            skipping_generator
+                .try_skip(ByteCount(n_bytes as u128))
+                .unwrap();
+            for _ in 0..n_bytes {
+                generating_generator.next().unwrap();
+            }
+            assert_eq!(
+                skipping_generator.remaining_bytes(),
+                generating_generator.remaining_bytes()
+            );
+            for _ in 0..1024 {
+                assert_eq!(skipping_generator.next(), generating_generator.next());
+            }
+        }
+    }
+
+    /// Checks that a bounded generator can skip up to its bound, but not beyond.
+    pub fn test_skip_bounded<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let ((seed, n_children), n_bytes) = any_seed()
+                .zip(some_children_count())
+                .zip(some_bytes_per_child())
+                .next()
+                .unwrap();
+            let mut gen = G::new(seed);
+            let mut bounded = gen.try_fork(n_children, n_bytes).unwrap().next().unwrap();
+            assert_eq!(
+                bounded.try_skip(ByteCount(n_bytes.0 as u128 + 1)),
+                Err(SkipError::SkipTooLarge)
+            );
+            assert_eq!(bounded.remaining_bytes(), ByteCount(n_bytes.0 as u128));
+            bounded.try_skip(ByteCount(n_bytes.0 as u128)).unwrap();
+            assert_eq!(bounded.remaining_bytes(), ByteCount(0));
+            assert!(bounded.next().is_none());
+        }
+    }
+
+    /// Checks that a generator restored from a serialized snapshot outputs the same bytes as the
+    /// uninterrupted generator.
+    pub fn test_snapshot_restore<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let seed = any_seed().next().unwrap();
+            let mut generator = G::new(seed);
+            let skipped = rand::thread_rng().gen::<usize>() % 1024;
+            for _ in 0..skipped {
+                generator.next().unwrap();
+            }
+            let bytes = generator.snapshot().to_bytes();
+            let state = GeneratorState::from_bytes(&bytes).unwrap();
+            let mut restored = G::restore(seed, state).unwrap();
+            assert_eq!(restored.remaining_bytes(), generator.remaining_bytes());
+            for _ in 0..1024 {
+                assert_eq!(restored.next(), generator.next());
+            }
+        }
+    }
+
+    /// Checks that a child generator restored from a snapshot and the seed of its root generator,
+    /// outputs the same bytes as the uninterrupted child, up to the same bound.
+    pub fn test_snapshot_restore_child<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let ((seed, n_children), n_bytes) = any_seed()
+                .zip(some_children_count())
+                .zip(some_bytes_per_child())
+                .next()
+                .unwrap();
+            let mut gen = G::new(seed);
+            let mut child = gen.try_fork(n_children, n_bytes).unwrap().last().unwrap();
+            let skipped = rand::thread_rng().gen::<usize>() % (n_bytes.0 + 1);
+            for _ in 0..skipped {
+                child.next().unwrap();
+            }
+            let mut restored = G::restore(seed, child.snapshot()).unwrap();
+            assert_eq!(restored.remaining_bytes(), child.remaining_bytes());
+            let restored_output: Vec<u8> = restored.collect();
+            let child_output: Vec<u8> = child.collect();
+            assert_eq!(restored_output.len(), n_bytes.0 - skipped);
+            assert_eq!(restored_output, child_output);
+        }
+    }
+
+    /// Checks that restoring a generator with a different seed fails.
+    pub fn test_restore_wrong_seed<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let mut seeds = any_seed();
+            let (seed, other_seed) = (seeds.next().unwrap(), seeds.next().unwrap());
+            let state = G::new(seed).snapshot();
+            assert!(matches!(
+                G::restore(other_seed, state),
+                Err(RestoreError::KeyMismatch)
+            ));
+        }
+    }
+
+    /// Checks that the outputs of the generator and of its children pass the statistical battery.
+    ///
+    /// The seeds are fixed so that the test is deterministic, and the significance level is lowered
+    /// so that the seeds need not be cherry-picked.
+    pub fn test_statistical_battery<G: RandomGenerator>() {
+        use crate::statistics::{run_battery, run_battery_on_children};
+        const SIGNIFICANCE_LEVEL: f64 = 1e-4;
+        for seed in 0..4 {
+            let mut generator = G::new(Seed(seed));
+            for result in run_battery(&mut generator, 1 << 16) {
+                assert!(result.is_passed(SIGNIFICANCE_LEVEL), "{}", result);
+            }
+            let results =
+                run_battery_on_children(&mut generator, ChildrenCount(64), BytesPerChild(1024))
+                    .unwrap();
+            for result in results {
+                assert!(result.is_passed(SIGNIFICANCE_LEVEL), "{}", result);
+            }
+        }
+    }
+
     /// Checks that a bounded prng returns none when exceeding the allowed number of bytes.
     pub fn test_bounded_none_should_panic<G: RandomGenerator>() {
         let ((seed, n_children), n_bytes) = any_seed()
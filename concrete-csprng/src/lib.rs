@@ -112,3 +112,4 @@
 //! in the ISO/IEC 18033-4 document.
 pub mod generators;
 pub mod seeders;
+pub mod statistics;
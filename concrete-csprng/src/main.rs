@@ -1,13 +1,75 @@
 //! This program uses the concrete csprng to generate an infinite stream of random bytes on
 //! the program stdout. For testing purpose.
-use concrete_csprng::generators::{AesniRandomGenerator, RandomGenerator};
+//!
+//! When called as `generate test [N_BYTES] [N_CHILDREN]`, it instead runs the statistical battery
+//! on `N_BYTES` bytes (1 MiB by default) outputted by the generator, then on the interleaved
+//! outputs of `N_CHILDREN` children (64 by default) outputting `N_BYTES / N_CHILDREN` bytes each,
+//! and prints the p-values on stdout. The program exits with a non-zero code if a test fails.
+//!
+//! The aesni generator is used by default. When the `generator_soft` or `generator_chacha`
+//! features are activated, the software or chacha generators can be used instead by passing the
+//! `--generator=soft` or `--generator=chacha` option before the subcommand.
+#[cfg(feature = "generator_chacha")]
+use concrete_csprng::generators::ChachaRandomGenerator;
+#[cfg(feature = "generator_soft")]
+use concrete_csprng::generators::SoftwareRandomGenerator;
+use concrete_csprng::generators::{
+    AesniRandomGenerator, BytesPerChild, ChildrenCount, RandomGenerator,
+};
 use concrete_csprng::seeders::{RdseedSeeder, Seeder};
+use concrete_csprng::statistics::{
+    run_battery, run_battery_on_children, TestResult, SIGNIFICANCE_LEVEL,
+};
 use std::io::prelude::*;
 use std::io::stdout;
 
+const DEFAULT_N_BYTES: usize = 1 << 20;
+const DEFAULT_N_CHILDREN: usize = 64;
+
 pub fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let generator = match args
+        .first()
+        .and_then(|arg| arg.strip_prefix("--generator="))
+    {
+        Some(name) => {
+            let name = name.to_owned();
+            args.remove(0);
+            name
+        }
+        None => "aesni".to_owned(),
+    };
+    match generator.as_str() {
+        "aesni" => run::<AesniRandomGenerator>(&args),
+        #[cfg(feature = "generator_soft")]
+        "soft" => run::<SoftwareRandomGenerator>(&args),
+        #[cfg(feature = "generator_chacha")]
+        "chacha" => run::<ChachaRandomGenerator>(&args),
+        other => {
+            eprintln!("Unknown or unavailable generator `{}`.", other);
+            std::process::exit(2);
+        }
+    }
+}
+
+fn run<G: RandomGenerator>(args: &[String]) {
+    match args.first().map(String::as_str) {
+        None => generate::<G>(),
+        Some("test") => test::<G>(&args[1..]),
+        Some(other) => {
+            eprintln!(
+                "Unknown subcommand `{}`. Usage: generate [--generator=NAME] [test [N_BYTES] \
+                 [N_CHILDREN]]",
+                other
+            );
+            std::process::exit(2);
+        }
+    }
+}
+
+fn generate<G: RandomGenerator>() {
     let mut seeder = RdseedSeeder;
-    let mut generator = AesniRandomGenerator::new(seeder.seed());
+    let mut generator = G::new(seeder.seed());
     let mut stdout = stdout();
     let mut buffer = [0u8; 16];
     loop {
@@ -18,3 +80,50 @@ pub fn main() {
         stdout.write_all(&buffer).unwrap();
     }
 }
+
+fn test<G: RandomGenerator>(args: &[String]) {
+    let parse = |index: usize, default: usize| {
+        args.get(index).map_or(default, |arg| {
+            arg.parse().unwrap_or_else(|_| {
+                eprintln!("Invalid argument `{}`: expected a positive integer.", arg);
+                std::process::exit(2);
+            })
+        })
+    };
+    let n_bytes = parse(0, DEFAULT_N_BYTES);
+    let n_children = parse(1, DEFAULT_N_CHILDREN);
+    if n_bytes == 0 || n_children == 0 || n_bytes < n_children {
+        eprintln!("N_BYTES and N_CHILDREN must be positive, and N_CHILDREN at most N_BYTES.");
+        std::process::exit(2);
+    }
+
+    let mut seeder = RdseedSeeder;
+    let mut generator = G::new(seeder.seed());
+    println!("Generator output ({} bytes):", n_bytes);
+    let mut passed = report(&run_battery(&mut generator, n_bytes));
+    println!(
+        "Children outputs ({} children of {} bytes):",
+        n_children,
+        n_bytes / n_children
+    );
+    let results = run_battery_on_children(
+        &mut generator,
+        ChildrenCount(n_children),
+        BytesPerChild(n_bytes / n_children),
+    )
+    .unwrap();
+    passed &= report(&results);
+    if !passed {
+        std::process::exit(1);
+    }
+}
+
+// Prints the results, and returns whether they all passed.
+fn report(results: &[TestResult]) -> bool {
+    for result in results {
+        println!("    {}", result);
+    }
+    results
+        .iter()
+        .all(|result| result.is_passed(SIGNIFICANCE_LEVEL))
+}
@@ -0,0 +1,63 @@
+use crate::generators::RandomGenerator;
+use crate::seeders::{Seed, Seeder};
+use std::convert::TryInto;
+
+/// A seeder which deterministically derives a sequence of seeds from a master seed.
+///
+/// The seeds are expanded from the master seed with a random generator of type `G`, which makes
+/// the sequence reproducible, while preventing to recover the master seed (or any other seed of
+/// the sequence) from some seeds of the sequence.
+///
+/// Important:
+/// ----------
+///
+/// The quality of the seeds is only as good as the quality of the master seed. Moreover, the seeds
+/// are the first bytes outputted by a generator of type `G` seeded with the master seed: the master
+/// seed should not also be used to seed a generator directly.
+pub struct DeterministicSeeder<G: RandomGenerator> {
+    generator: G,
+}
+
+impl<G: RandomGenerator> DeterministicSeeder<G> {
+    /// Creates a new seeder from a master seed.
+    pub fn new(master_seed: Seed) -> DeterministicSeeder<G> {
+        DeterministicSeeder {
+            generator: G::new(master_seed),
+        }
+    }
+}
+
+impl<G: RandomGenerator> Seeder for DeterministicSeeder<G> {
+    fn seed(&mut self) -> Seed {
+        let bytes: Vec<u8> = (&mut self.generator).take(Seed::BYTES).collect();
+        let bytes = bytes
+            .try_into()
+            .expect("The generator of the deterministic seeder reached its bound.");
+        Seed::from_bytes(bytes)
+    }
+}
+
+#[cfg(all(test, feature = "generator_soft"))]
+mod test {
+    use super::*;
+    use crate::generators::SoftwareRandomGenerator;
+    use crate::seeders::generic_tests::check_seeder_fixed_sequences_different;
+
+    #[test]
+    fn check_bounded_sequence_difference() {
+        check_seeder_fixed_sequences_different(|i| {
+            DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(i))
+        });
+    }
+
+    #[test]
+    fn check_sequence_determinism() {
+        for i in 0..1000 {
+            let mut first = DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(i));
+            let mut second = DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(i));
+            for _ in 0..100 {
+                assert_eq!(first.seed(), second.seed());
+            }
+        }
+    }
+}
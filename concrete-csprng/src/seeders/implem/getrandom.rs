@@ -0,0 +1,38 @@
+use crate::seeders::{Seed, SeedError, Seeder};
+
+/// A seeder which uses the `getrandom` interface of the operating system.
+///
+/// On linux, this relies on the `getrandom(2)` syscall, and on the equivalent system interfaces on
+/// other platforms. Contrary to [`UnixSeeder`](super::UnixSeeder), it does not need any file
+/// to be accessible, which makes it suited to restricted environments such as containers.
+pub struct GetrandomSeeder;
+
+impl Seeder for GetrandomSeeder {
+    fn seed(&mut self) -> Seed {
+        self.try_seed()
+            .expect("Failed to get a seed from the getrandom interface.")
+    }
+
+    fn try_seed(&mut self) -> Result<Seed, SeedError> {
+        let mut buf = [0u8; Seed::BYTES];
+        getrandom::getrandom(&mut buf[..])
+            .map_err(|error| SeedError::SourceFailure(error.into()))?;
+        Ok(Seed::from_bytes(buf))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::seeders::generic_tests::check_seeder_fixed_sequences_different;
+
+    #[test]
+    fn check_bounded_sequence_difference() {
+        check_seeder_fixed_sequences_different(|_| GetrandomSeeder);
+    }
+
+    #[test]
+    fn check_try_seed_succeeds() {
+        assert!(GetrandomSeeder.try_seed().is_ok());
+    }
+}
@@ -1,3 +1,18 @@
+#[cfg(feature = "seeder_deterministic")]
+mod deterministic;
+#[cfg(feature = "seeder_deterministic")]
+pub use deterministic::DeterministicSeeder;
+
+#[cfg(feature = "seeder_getrandom")]
+mod getrandom;
+#[cfg(feature = "seeder_getrandom")]
+pub use self::getrandom::GetrandomSeeder;
+
+#[cfg(feature = "seeder_read")]
+mod read;
+#[cfg(feature = "seeder_read")]
+pub use read::ReadSeeder;
+
 #[cfg(feature = "seeder_x86_64_rdseed")]
 mod rdseed;
 #[cfg(feature = "seeder_x86_64_rdseed")]
@@ -0,0 +1,74 @@
+use crate::seeders::{Seed, SeedError, Seeder};
+use std::io::Read;
+
+/// A seeder which reads the seeds from an arbitrary source of bytes.
+///
+/// Each seed is made of the next 16 bytes of the source, interpreted as a little-endian integer.
+/// This makes it possible to seed from a file, a pipe, or a test vector.
+///
+/// Important:
+/// ----------
+///
+/// The seeder does not process the bytes in any way: the quality of the seeds is the one of the
+/// source.
+pub struct ReadSeeder<R: Read> {
+    source: R,
+}
+
+impl<R: Read> ReadSeeder<R> {
+    /// Creates a new seeder reading from `source`.
+    pub fn new(source: R) -> ReadSeeder<R> {
+        ReadSeeder { source }
+    }
+
+    /// Returns the source of the seeder.
+    pub fn into_inner(self) -> R {
+        self.source
+    }
+}
+
+impl<R: Read> Seeder for ReadSeeder<R> {
+    fn seed(&mut self) -> Seed {
+        self.try_seed()
+            .expect("Failed to read a seed from the source.")
+    }
+
+    fn try_seed(&mut self) -> Result<Seed, SeedError> {
+        let mut buf = [0u8; Seed::BYTES];
+        self.source
+            .read_exact(&mut buf[..])
+            .map_err(SeedError::SourceFailure)?;
+        Ok(Seed::from_bytes(buf))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn check_seeds_from_test_vector() {
+        let bytes: Vec<u8> = (0..48).collect();
+        let mut seeder = ReadSeeder::new(Cursor::new(bytes));
+        assert_eq!(seeder.seed(), Seed(0x0f0e0d0c0b0a09080706050403020100));
+        assert_eq!(seeder.seed(), Seed(0x1f1e1d1c1b1a19181716151413121110));
+        assert_eq!(seeder.seed(), Seed(0x2f2e2d2c2b2a29282726252423222120));
+    }
+
+    #[test]
+    fn check_exhausted_source_errors() {
+        let mut seeder = ReadSeeder::new(Cursor::new(vec![0u8; 15]));
+        assert!(matches!(
+            seeder.try_seed(),
+            Err(SeedError::SourceFailure(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_exhausted_source_panics() {
+        let mut seeder = ReadSeeder::new(Cursor::new(vec![0u8; 15]));
+        seeder.seed();
+    }
+}
@@ -1,4 +1,4 @@
-use crate::seeders::{Seed, Seeder};
+use crate::seeders::{Seed, SeedError, Seeder};
 use std::fs::File;
 use std::io::Read;
 
@@ -18,33 +18,45 @@ impl UnixSeeder {
     /// This secret is used to ensure the quality of the seed in scenarios where `/dev/random` may
     /// be compromised.
     pub fn new(secret: u128) -> UnixSeeder {
-        let file = std::fs::File::open("/dev/random").expect("Failed to open /dev/random .");
+        UnixSeeder::try_new(secret).expect("Failed to open /dev/random .")
+    }
+
+    /// Tries to create a new seeder from a user defined secret, returning an error if
+    /// `/dev/random` can not be opened.
+    ///
+    /// See [`UnixSeeder::new`] for details about the secret.
+    pub fn try_new(secret: u128) -> Result<UnixSeeder, SeedError> {
+        let file = std::fs::File::open("/dev/random").map_err(SeedError::SourceFailure)?;
         let counter = std::time::UNIX_EPOCH
             .elapsed()
             .expect("Failed to initialize unix seeder.")
             .as_nanos();
-        UnixSeeder {
+        Ok(UnixSeeder {
             secret,
             counter,
             file,
-        }
+        })
     }
 }
 
 impl Seeder for UnixSeeder {
     fn seed(&mut self) -> Seed {
-        let output = self.secret ^ self.counter ^ dev_random(&mut self.file);
+        self.try_seed().expect("Failed to read from /dev/random .")
+    }
+
+    fn try_seed(&mut self) -> Result<Seed, SeedError> {
+        let output = self.secret ^ self.counter ^ dev_random(&mut self.file)?;
         self.counter = self.counter.wrapping_add(1);
-        Seed(output)
+        Ok(Seed(output))
     }
 }
 
-fn dev_random(random: &mut File) -> u128 {
+fn dev_random(random: &mut File) -> Result<u128, SeedError> {
     let mut buf = [0u8; 16];
     random
         .read_exact(&mut buf[..])
-        .expect("Failed to read from /dev/random .");
-    u128::from_ne_bytes(buf)
+        .map_err(SeedError::SourceFailure)?;
+    Ok(u128::from_ne_bytes(buf))
 }
 
 #[cfg(test)]
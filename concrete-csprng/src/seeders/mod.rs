@@ -5,14 +5,73 @@
 //! conditioned by the quality of this seed. This module proposes different mechanisms to deliver
 //! seeds that can accomodate varying scenarios.
 
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
 /// A seed value, used to initialize a generator.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Seed(pub u128);
 
+impl Seed {
+    /// The number of bytes of a seed.
+    pub const BYTES: usize = 16;
+
+    /// Creates a seed from its bytes, in little-endian order.
+    ///
+    /// This is the canonical byte order of the seeds: the key of the block cipher of a generator
+    /// created from this seed is made of the same bytes, in the same order, on every host.
+    pub fn from_bytes(bytes: [u8; Seed::BYTES]) -> Seed {
+        Seed(u128::from_le_bytes(bytes))
+    }
+
+    /// Returns the bytes of the seed, in little-endian order.
+    pub fn to_bytes(self) -> [u8; Seed::BYTES] {
+        self.0.to_le_bytes()
+    }
+}
+
+/// An error occuring when a seeder fails to deliver a seed.
+#[derive(Debug)]
+pub enum SeedError {
+    /// The source of entropy of the seeder could not be accessed or read.
+    SourceFailure(std::io::Error),
+}
+
+impl Display for SeedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SeedError::SourceFailure(error) => {
+                write!(f, "The seeder failed to access its source: {}", error)
+            }
+        }
+    }
+}
+
+impl Error for SeedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SeedError::SourceFailure(error) => Some(error),
+        }
+    }
+}
+
 /// A trait representing a seeding strategy.
 pub trait Seeder {
     /// Generates a new seed.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// Seeders relying on a source which may be unavailable panic when the source fails. Use
+    /// [`Seeder::try_seed`] to handle such failures.
     fn seed(&mut self) -> Seed;
+
+    /// Tries to generate a new seed, returning an error if the source of the seeder failed.
+    ///
+    /// The default implementation is meant for seeders which can not fail.
+    fn try_seed(&mut self) -> Result<Seed, SeedError> {
+        Ok(self.seed())
+    }
 }
 
 mod implem;
@@ -0,0 +1,512 @@
+//! A module containing a battery of statistical tests for the output of random generators.
+//!
+//! The tests are the ones of the NIST SP 800-22 publication (frequency, runs, serial, approximate
+//! entropy and spectral tests), completed with the poker test of the FIPS 140-1 publication. Each
+//! test is run on a sequence of bits, and outputs a p-value: the probability that a perfect random
+//! generator outputs a sequence less random than the tested one. A sequence is considered
+//! non-random when one of its p-values falls below a significance level, usually
+//! [`SIGNIFICANCE_LEVEL`].
+//!
+//! The tests can be run one by one on a sequence of bytes, or all at once, either on a sequence of
+//! bytes with [`run_battery_on_bytes`], on the output of a generator with [`run_battery`], or on
+//! the outputs of the children of a generator with [`run_battery_on_children`]. The bits of a
+//! sequence of bytes are read from the most significant bit of the first byte to the least
+//! significant bit of the last byte.
+//!
+//! Note:
+//! -----
+//!
+//! Even for a perfect generator, a test fails with a probability equal to the significance level.
+//! A single failure is not a proof of non-randomness, but failures repeating over several
+//! sequences are.
+use crate::generators::{BytesPerChild, ChildrenCount, ForkError, RandomGenerator};
+use std::fmt::{Display, Formatter};
+
+mod special;
+pub use special::{erfc, igamc};
+
+/// The significance level recommended by the NIST SP 800-22 publication.
+pub const SIGNIFICANCE_LEVEL: f64 = 0.01;
+
+/// The block length, in bits, used by the serial test of the battery.
+pub const SERIAL_BLOCK_LENGTH: usize = 8;
+
+/// The block length, in bits, used by the approximate entropy test of the battery.
+pub const APPROXIMATE_ENTROPY_BLOCK_LENGTH: usize = 8;
+
+/// The outcome of a statistical test.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TestResult {
+    /// The name of the test.
+    pub name: &'static str,
+    /// The p-value of the test.
+    pub p_value: f64,
+}
+
+impl TestResult {
+    /// Returns whether the test passed at the given significance level.
+    pub fn is_passed(&self, significance_level: f64) -> bool {
+        self.p_value >= significance_level
+    }
+}
+
+impl Display for TestResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:<20} p-value = {:.6} {}",
+            self.name,
+            self.p_value,
+            if self.is_passed(SIGNIFICANCE_LEVEL) {
+                "PASSED"
+            } else {
+                "FAILED"
+            }
+        )
+    }
+}
+
+/// Runs the frequency (monobit) test on the bits of `bytes`.
+///
+/// This test checks that the proportions of zeros and ones are close to 1/2.
+pub fn frequency(bytes: &[u8]) -> TestResult {
+    Bits::new(bytes).frequency()
+}
+
+/// Runs the runs test on the bits of `bytes`.
+///
+/// This test checks that the number of runs of consecutive identical bits is the one expected
+/// from a random sequence. As prescribed by the NIST publication, the p-value is zero when the
+/// sequence is too unbalanced to pass the frequency test.
+pub fn runs(bytes: &[u8]) -> TestResult {
+    Bits::new(bytes).runs()
+}
+
+/// Runs the serial test on the bits of `bytes`, with overlapping blocks of `m` bits.
+///
+/// This test checks that all the blocks of `m` bits occur with the same frequency. It outputs two
+/// p-values, computed from the first and second differences of the ψ² statistics.
+///
+/// Panics if `m` is smaller than 3.
+pub fn serial(bytes: &[u8], m: usize) -> (TestResult, TestResult) {
+    Bits::new(bytes).serial(m)
+}
+
+/// Runs the poker test on the bits of `bytes`.
+///
+/// This test checks that the 16 possible values of the 4 bits nibbles occur with the same
+/// frequency, using a χ² test with 15 degrees of freedom.
+pub fn poker(bytes: &[u8]) -> TestResult {
+    let mut counts = [0usize; 16];
+    for byte in bytes {
+        counts[(byte >> 4) as usize] += 1;
+        counts[(byte & 0xf) as usize] += 1;
+    }
+    let k = (bytes.len() * 2) as f64;
+    let sum: f64 = counts.iter().map(|c| (*c as f64).powi(2)).sum();
+    let chi_squared = 16. / k * sum - k;
+    TestResult {
+        name: "poker",
+        p_value: igamc(15. / 2., chi_squared / 2.),
+    }
+}
+
+/// Runs the approximate entropy test on the bits of `bytes`, with overlapping blocks of `m` bits.
+///
+/// This test compares the frequencies of the overlapping blocks of `m` and `m + 1` bits against
+/// the ones expected from a random sequence.
+///
+/// Panics if `m` is zero.
+pub fn approximate_entropy(bytes: &[u8], m: usize) -> TestResult {
+    Bits::new(bytes).approximate_entropy(m)
+}
+
+/// Runs the spectral (discrete Fourier transform) test on the bits of `bytes`.
+///
+/// This test detects periodic features in the sequence, by checking that the number of peaks of
+/// its discrete Fourier transform exceeding a threshold is the expected one.
+///
+/// Note:
+/// -----
+///
+/// The transform is computed in quasi-linear time when the number of bits is a power of two, and
+/// in quadratic time otherwise.
+pub fn spectral(bytes: &[u8]) -> TestResult {
+    Bits::new(bytes).spectral()
+}
+
+/// Runs the whole battery of tests on the bits of `bytes`.
+///
+/// The serial and approximate entropy tests use blocks of [`SERIAL_BLOCK_LENGTH`] and
+/// [`APPROXIMATE_ENTROPY_BLOCK_LENGTH`] bits respectively, and the spectral test is run on the
+/// longest prefix of the sequence whose length is a power of two.
+///
+/// Panics if `bytes` is empty.
+pub fn run_battery_on_bytes(bytes: &[u8]) -> Vec<TestResult> {
+    assert!(
+        !bytes.is_empty(),
+        "The battery can not run on an empty sequence."
+    );
+    let bits = Bits::new(bytes);
+    let (serial_first, serial_second) = bits.serial(SERIAL_BLOCK_LENGTH);
+    let spectral_length = 1 << (usize::BITS - 1 - bits.len.leading_zeros());
+    vec![
+        bits.frequency(),
+        bits.runs(),
+        serial_first,
+        serial_second,
+        poker(bytes),
+        bits.approximate_entropy(APPROXIMATE_ENTROPY_BLOCK_LENGTH),
+        bits.truncate(spectral_length).spectral(),
+    ]
+}
+
+/// Runs the whole battery of tests on the next `n_bytes` bytes outputted by a generator.
+///
+/// Panics if the generator reaches its bound before outputting `n_bytes` bytes.
+pub fn run_battery<G: RandomGenerator>(generator: &mut G, n_bytes: usize) -> Vec<TestResult> {
+    let bytes: Vec<u8> = generator.take(n_bytes).collect();
+    assert_eq!(
+        bytes.len(),
+        n_bytes,
+        "The generator reached its bound before outputting {} bytes.",
+        n_bytes
+    );
+    run_battery_on_bytes(&bytes)
+}
+
+/// Runs the whole battery of tests on the outputs of the children of a generator.
+///
+/// The generator is forked into `n_children` children outputting `n_bytes` bytes each, and the
+/// battery is run on the interleaved outputs of the children: the first byte of every child in
+/// the fork order, then the second byte of every child, and so on. Since the concatenated outputs
+/// of the children are the output of the parent, this layout is the one which can reveal
+/// correlations between the children.
+pub fn run_battery_on_children<G: RandomGenerator>(
+    generator: &mut G,
+    n_children: ChildrenCount,
+    n_bytes: BytesPerChild,
+) -> Result<Vec<TestResult>, ForkError> {
+    let mut children: Vec<G> = generator.try_fork(n_children, n_bytes)?.collect();
+    let mut bytes = Vec::with_capacity(n_children.0 * n_bytes.0);
+    for _ in 0..n_bytes.0 {
+        bytes.extend(children.iter_mut().map(|child| child.next().unwrap()));
+    }
+    Ok(run_battery_on_bytes(&bytes))
+}
+
+// A sequence of bits, stored in a slice of bytes, possibly ending in the middle of a byte.
+#[derive(Clone, Copy)]
+struct Bits<'a> {
+    bytes: &'a [u8],
+    len: usize,
+}
+
+impl<'a> Bits<'a> {
+    fn new(bytes: &'a [u8]) -> Bits<'a> {
+        Bits {
+            bytes,
+            len: bytes.len() * 8,
+        }
+    }
+
+    fn truncate(self, len: usize) -> Bits<'a> {
+        assert!(len <= self.len);
+        Bits { len, ..self }
+    }
+
+    #[inline]
+    fn get(&self, i: usize) -> usize {
+        ((self.bytes[i / 8] >> (7 - i % 8)) & 1) as usize
+    }
+
+    fn count_ones(&self) -> usize {
+        (0..self.len).map(|i| self.get(i)).sum()
+    }
+
+    fn frequency(&self) -> TestResult {
+        let n = self.len as f64;
+        let sum = 2. * self.count_ones() as f64 - n;
+        TestResult {
+            name: "frequency",
+            p_value: erfc(sum.abs() / n.sqrt() / std::f64::consts::SQRT_2),
+        }
+    }
+
+    fn runs(&self) -> TestResult {
+        let n = self.len as f64;
+        let pi = self.count_ones() as f64 / n;
+        let p_value = if (pi - 0.5).abs() >= 2. / n.sqrt() {
+            0.
+        } else {
+            let changes = (1..self.len)
+                .filter(|i| self.get(*i) != self.get(i - 1))
+                .count();
+            let v_obs = (changes + 1) as f64;
+            let deviation = (v_obs - 2. * n * pi * (1. - pi)).abs();
+            erfc(deviation / (2. * (2. * n).sqrt() * pi * (1. - pi)))
+        };
+        TestResult {
+            name: "runs",
+            p_value,
+        }
+    }
+
+    // Counts the occurrences of every overlapping block of `m` bits, the sequence being extended
+    // with its `m - 1` first bits so that every position starts a block.
+    fn block_counts(&self, m: usize) -> Vec<usize> {
+        let mut counts = vec![0usize; 1 << m];
+        if m == 0 {
+            counts[0] = self.len;
+            return counts;
+        }
+        let mask = (1usize << m) - 1;
+        let mut block = (0..m - 1).fold(0, |acc, i| (acc << 1) | self.get(i % self.len));
+        for i in 0..self.len {
+            block = ((block << 1) | self.get((i + m - 1) % self.len)) & mask;
+            counts[block] += 1;
+        }
+        counts
+    }
+
+    // Computes the ψ² statistic of the serial test for blocks of `m` bits.
+    fn psi_squared(&self, m: usize) -> f64 {
+        if m == 0 {
+            return 0.;
+        }
+        let n = self.len as f64;
+        let sum: f64 = self
+            .block_counts(m)
+            .iter()
+            .map(|c| (*c as f64).powi(2))
+            .sum();
+        sum * (1usize << m) as f64 / n - n
+    }
+
+    fn serial(&self, m: usize) -> (TestResult, TestResult) {
+        assert!(m >= 3, "The serial test needs blocks of at least 3 bits.");
+        let psi_m = self.psi_squared(m);
+        let psi_m_1 = self.psi_squared(m - 1);
+        let psi_m_2 = self.psi_squared(m - 2);
+        let first_difference = psi_m - psi_m_1;
+        let second_difference = psi_m - 2. * psi_m_1 + psi_m_2;
+        (
+            TestResult {
+                name: "serial (first)",
+                p_value: igamc((1usize << (m - 2)) as f64, first_difference / 2.),
+            },
+            TestResult {
+                name: "serial (second)",
+                p_value: igamc((1usize << (m - 3)) as f64, second_difference / 2.),
+            },
+        )
+    }
+
+    // Computes the φ statistic of the approximate entropy test for blocks of `m` bits.
+    fn phi(&self, m: usize) -> f64 {
+        let n = self.len as f64;
+        self.block_counts(m)
+            .iter()
+            .filter(|c| **c > 0)
+            .map(|c| {
+                let frequency = *c as f64 / n;
+                frequency * frequency.ln()
+            })
+            .sum()
+    }
+
+    fn approximate_entropy(&self, m: usize) -> TestResult {
+        assert!(
+            m >= 1,
+            "The approximate entropy test needs blocks of at least 1 bit."
+        );
+        let n = self.len as f64;
+        let ap_en = self.phi(m) - self.phi(m + 1);
+        let chi_squared = 2. * n * (std::f64::consts::LN_2 - ap_en);
+        TestResult {
+            name: "approximate entropy",
+            p_value: igamc((1usize << (m - 1)) as f64, chi_squared / 2.),
+        }
+    }
+
+    fn spectral(&self) -> TestResult {
+        let n = self.len as f64;
+        let input: Vec<f64> = (0..self.len)
+            .map(|i| 2. * self.get(i) as f64 - 1.)
+            .collect();
+        let threshold = (20f64.ln() * n).sqrt();
+        let expected_peaks = 0.95 * n / 2.;
+        let peaks = dft_moduli(&input)
+            .iter()
+            .filter(|modulus| **modulus < threshold)
+            .count() as f64;
+        let d = (peaks - expected_peaks) / (n * 0.95 * 0.05 / 4.).sqrt();
+        TestResult {
+            name: "spectral",
+            p_value: erfc(d.abs() / std::f64::consts::SQRT_2),
+        }
+    }
+}
+
+// Computes the moduli of the first half of the discrete Fourier transform of a real sequence.
+//
+// An iterative radix-2 fast Fourier transform is used when the length is a power of two, and the
+// direct quadratic transform otherwise.
+fn dft_moduli(input: &[f64]) -> Vec<f64> {
+    let n = input.len();
+    if !n.is_power_of_two() {
+        return (0..n / 2)
+            .map(|k| {
+                let (re, im) = input.iter().enumerate().fold((0., 0.), |(re, im), (j, x)| {
+                    let angle = -2. * std::f64::consts::PI * (k * j % n) as f64 / n as f64;
+                    (re + x * angle.cos(), im + x * angle.sin())
+                });
+                f64::hypot(re, im)
+            })
+            .collect();
+    }
+    let mut re = input.to_vec();
+    let mut im = vec![0f64; n];
+    // We permute the input in bit-reversed order.
+    let log_n = n.trailing_zeros();
+    if log_n > 0 {
+        for i in 0..n {
+            let j = i.reverse_bits() >> (usize::BITS - log_n);
+            if j > i {
+                re.swap(i, j);
+            }
+        }
+    }
+    // We compute the butterflies, stage by stage.
+    let mut len = 2;
+    while len <= n {
+        let angle = -2. * std::f64::consts::PI / len as f64;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * k as f64).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let t_re = re[b] * cos - im[b] * sin;
+                let t_im = re[b] * sin + im[b] * cos;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+            }
+        }
+        len <<= 1;
+    }
+    (0..n / 2).map(|k| re[k].hypot(im[k])).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::Rng;
+
+    // The sequence used in the examples of the NIST SP 800-22 publication.
+    const EPSILON: &str = "11001001000011111101101010100010001000010110100011\
+                           00001000110100110001001100011001100010100010111000";
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!(
+            (value - expected).abs() < 1e-6,
+            "{} is not close to {}",
+            value,
+            expected
+        );
+    }
+
+    // Packs a string of bits into bytes, the last byte being padded with zeros.
+    fn pack(bits: &str) -> Vec<u8> {
+        bits.as_bytes()
+            .chunks(8)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .chain(std::iter::repeat(&b'0'))
+                    .take(8)
+                    .fold(0u8, |acc, b| (acc << 1) | (b - b'0'))
+            })
+            .collect()
+    }
+
+    fn with_bits<T>(bits: &str, test: impl FnOnce(Bits) -> T) -> T {
+        let bytes = pack(bits);
+        test(Bits::new(&bytes).truncate(bits.len()))
+    }
+
+    #[test]
+    fn test_frequency_nist_example() {
+        let result = with_bits(EPSILON, |bits| bits.frequency());
+        assert_close(result.p_value, 0.109_599);
+    }
+
+    #[test]
+    fn test_runs_nist_example() {
+        let result = with_bits(EPSILON, |bits| bits.runs());
+        assert_close(result.p_value, 0.500_798);
+    }
+
+    #[test]
+    fn test_serial_nist_example() {
+        let (first, second) = with_bits("0011011101", |bits| bits.serial(3));
+        assert_close(first.p_value, 0.808_792);
+        assert_close(second.p_value, 0.670_320);
+    }
+
+    #[test]
+    fn test_approximate_entropy_nist_example() {
+        let result = with_bits(EPSILON, |bits| bits.approximate_entropy(2));
+        assert_close(result.p_value, 0.235_301);
+    }
+
+    #[test]
+    fn test_spectral_nist_example() {
+        // The example of the publication counts 46 peaks below the threshold, which is not what
+        // its reference implementation outputs. The transform of the sequence has 48 of them,
+        // which gives d = 0.458831.
+        let result = with_bits(EPSILON, |bits| bits.spectral());
+        assert_close(result.p_value, 0.646_355);
+    }
+
+    #[test]
+    fn test_fast_transform() {
+        // The fast transform must agree with the direct one on power of two lengths.
+        let mut rng = rand::thread_rng();
+        let input: Vec<f64> = (0..256).map(|_| rng.gen_range(-1f64..1.)).collect();
+        let fast = dft_moduli(&input);
+        let direct: Vec<f64> = (0..128)
+            .map(|k| {
+                let (re, im) = input.iter().enumerate().fold((0., 0.), |(re, im), (j, x)| {
+                    let angle = -2. * std::f64::consts::PI * (k * j) as f64 / 256.;
+                    (re + x * angle.cos(), im + x * angle.sin())
+                });
+                f64::hypot(re, im)
+            })
+            .collect();
+        for (f, d) in fast.iter().zip(direct.iter()) {
+            assert!((f - d).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_poker() {
+        // Every nibble occurs the same number of times.
+        let balanced: Vec<u8> = (0..=255).collect();
+        assert_close(poker(&balanced).p_value, 1.);
+        // Only one nibble occurs.
+        assert!(poker(&[0u8; 1024]).p_value < SIGNIFICANCE_LEVEL);
+    }
+
+    #[test]
+    fn test_battery_detects_non_random_sequences() {
+        let constant = vec![0u8; 4096];
+        assert!(run_battery_on_bytes(&constant)
+            .iter()
+            .all(|result| !result.is_passed(SIGNIFICANCE_LEVEL)));
+        let periodic: Vec<u8> = (0..4096).map(|i| i as u8).collect();
+        assert!(run_battery_on_bytes(&periodic)
+            .iter()
+            .any(|result| !result.is_passed(SIGNIFICANCE_LEVEL)));
+    }
+}
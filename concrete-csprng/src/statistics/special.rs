@@ -0,0 +1,150 @@
+//! Special functions needed to compute the p-values of the statistical tests.
+//!
+//! The implementations follow the ones of _Numerical Recipes in C_ (2nd edition, section 6.2),
+//! and are accurate to roughly ten significant digits, which is more than enough for p-values.
+
+// The maximal number of iterations of the series and continued fraction expansions.
+const MAX_ITERATIONS: usize = 1_000;
+
+// The relative precision at which the expansions are stopped.
+const EPSILON: f64 = 1e-15;
+
+// A number close to the smallest representable floating point number.
+const FPMIN: f64 = 1e-300;
+
+/// Computes the natural logarithm of the gamma function, for positive inputs.
+pub fn ln_gamma(x: f64) -> f64 {
+    // Lanczos approximation, with g = 7 and n = 9.
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Reflection formula
+        let pi = std::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1. - x);
+    }
+    let x = x - 1.;
+    let t = x + 7.5;
+    let series = COEFFICIENTS
+        .iter()
+        .enumerate()
+        .skip(1)
+        .fold(COEFFICIENTS[0], |acc, (i, c)| acc + c / (x + i as f64));
+    0.5 * (2. * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+/// Computes the regularized upper incomplete gamma function Q(a, x) = Γ(a, x) / Γ(a).
+///
+/// This function is called `igamc` in the reference implementation of the NIST test suite.
+pub fn igamc(a: f64, x: f64) -> f64 {
+    assert!(a > 0., "igamc is only defined for positive values of a.");
+    if x <= 0. {
+        1.
+    } else if x < a + 1. {
+        1. - lower_series(a, x)
+    } else {
+        upper_continued_fraction(a, x)
+    }
+}
+
+/// Computes the complementary error function, for non-negative inputs.
+pub fn erfc(x: f64) -> f64 {
+    assert!(x >= 0., "erfc is only implemented for non-negative values.");
+    igamc(0.5, x * x)
+}
+
+// Computes P(a, x) with its series expansion, which converges quickly for x < a + 1.
+fn lower_series(a: f64, x: f64) -> f64 {
+    let mut ap = a;
+    let mut del = 1. / a;
+    let mut sum = del;
+    for _ in 0..MAX_ITERATIONS {
+        ap += 1.;
+        del *= x / ap;
+        sum += del;
+        if del.abs() < sum.abs() * EPSILON {
+            break;
+        }
+    }
+    sum * (-x + a * x.ln() - ln_gamma(a)).exp()
+}
+
+// Computes Q(a, x) with its continued fraction expansion, which converges quickly for
+// x >= a + 1.
+fn upper_continued_fraction(a: f64, x: f64) -> f64 {
+    let mut b = x + 1. - a;
+    let mut c = 1. / FPMIN;
+    let mut d = 1. / b;
+    let mut h = d;
+    for i in 1..MAX_ITERATIONS {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.;
+        d = an * d + b;
+        if d.abs() < FPMIN {
+            d = FPMIN;
+        }
+        c = b + an / c;
+        if c.abs() < FPMIN {
+            c = FPMIN;
+        }
+        d = 1. / d;
+        let del = d * c;
+        h *= del;
+        if (del - 1.).abs() < EPSILON {
+            break;
+        }
+    }
+    (-x + a * x.ln() - ln_gamma(a)).exp() * h
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!(
+            (value - expected).abs() < 1e-9,
+            "{} is not close to {}",
+            value,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_ln_gamma_factorials() {
+        let mut factorial = 1f64;
+        for n in 1..20 {
+            assert_close(ln_gamma(n as f64), factorial.ln());
+            factorial *= n as f64;
+        }
+        assert_close(ln_gamma(0.5), std::f64::consts::PI.sqrt().ln());
+    }
+
+    #[test]
+    fn test_erfc() {
+        assert_close(erfc(0.), 1.);
+        assert_close(erfc(0.5), 0.479_500_122_186_953_5);
+        assert_close(erfc(1.), 0.157_299_207_050_285_13);
+        assert_close(erfc(2.), 0.004_677_734_981_047_266);
+    }
+
+    #[test]
+    fn test_igamc() {
+        // For a = 1, Q(1, x) = exp(-x).
+        for x in [0.1f64, 1., 2.5, 10.] {
+            assert_close(igamc(1., x), (-x).exp());
+        }
+        // For a = 2, Q(2, x) = (1 + x) exp(-x).
+        for x in [0.1f64, 1., 2.5, 10.] {
+            assert_close(igamc(2., x), (1. + x) * (-x).exp());
+        }
+    }
+}
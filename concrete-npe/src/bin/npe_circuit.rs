@@ -0,0 +1,91 @@
+//! Analyzes the noise of a circuit described in a JSON file.
+//!
+//! The file contains a [`Circuit`](concrete_npe::Circuit), e.g.:
+//! ```json
+//! {
+//!   "parameters": {
+//!     "lwe_dimension": 630, "glwe_dimension": 1, "polynomial_size": 1024,
+//!     "lwe_noise": 3.0517578125e-05, "glwe_noise": 2.9802322387695312e-08,
+//!     "pbs_base_log": 7, "pbs_level": 3, "ks_base_log": 4, "ks_level": 3,
+//!     "message_modulus": 4, "padding": 1
+//!   },
+//!   "nodes": [
+//!     { "operation": "input", "key": "large", "noise": 9.313225746154785e-10 },
+//!     { "operation": "cleartext_multiplication", "input": 0, "cleartext": 3 },
+//!     { "operation": "keyswitch", "input": 1 },
+//!     { "operation": "bootstrap", "input": 2 }
+//!   ]
+//! }
+//! ```
+use clap::{App, Arg};
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_npe::{analyze_circuit, Circuit, CircuitReport};
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("Error: {}", error);
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let matches = App::new("npe-circuit")
+        .about("Estimates the noise and the failure probability of every node of a circuit")
+        .arg(
+            Arg::with_name("precision")
+                .long("precision")
+                .short("p")
+                .takes_value(true)
+                .possible_values(&["32", "64"])
+                .default_value("64")
+                .help("The bit width of the ciphertexts"),
+        )
+        .arg(
+            Arg::with_name("circuit")
+                .required(true)
+                .help("The JSON file describing the circuit"),
+        )
+        .get_matches();
+
+    let file = File::open(matches.value_of("circuit").unwrap())?;
+    let circuit: Circuit = serde_json::from_reader(BufReader::new(file))?;
+    let report = match matches.value_of("precision").unwrap() {
+        "32" => analyze_circuit::<u32>(&circuit)?,
+        _ => analyze_circuit::<u64>(&circuit)?,
+    };
+    print_report(&report);
+    Ok(())
+}
+
+fn print_report(report: &CircuitReport) {
+    println!(
+        "| {:>5} | {:<24} | {:<5} | {:>14} | {:>17} |",
+        "node", "operation", "key", "log2(std dev)", "log2(failure)"
+    );
+    for (index, node) in report.nodes.iter().enumerate() {
+        println!(
+            "| {:>5} | {:<24} | {:<5} | {:>14.4} | {:>17.4} |",
+            index,
+            node.operation,
+            format!("{:?}", node.key).to_lowercase(),
+            node.variance.get_log_standard_dev(),
+            node.log2_failure_probability
+        );
+    }
+    println!();
+    println!("bootstraps: {}", report.bootstrap_count);
+    println!("keyswitches: {}", report.keyswitch_count);
+    if let Some(index) = report.worst_variance_node() {
+        println!("largest variance: node {}", index);
+    }
+    if let Some(index) = report.worst_node() {
+        println!("largest failure probability: node {}", index);
+    }
+    println!(
+        "log2 of the circuit failure probability: {:.4}",
+        report.log2_failure_probability()
+    );
+}
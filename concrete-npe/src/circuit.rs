@@ -0,0 +1,603 @@
+//! Contains material needed to analyze the noise of a whole homomorphic circuit.
+//!
+//! A circuit is described as a directed acyclic graph of operations, whose nodes are given in a
+//! topological order: every node may only use the outputs of the nodes preceding it. The
+//! ciphertexts of the circuit are encrypted either under the small LWE secret key, which encrypts
+//! the inputs of the bootstraps, or under the large one, i.e. the GLWE secret key seen as an LWE
+//! secret key, which encrypts the outputs of the bootstraps. Keyswitches go from the large key to
+//! the small one.
+//!
+//! The analysis propagates the dispersions through the circuit with the estimators of this crate,
+//! and computes the probability of failure of every node. For a bootstrap, this is the probability
+//! for the bootstrap to compute a wrong result, which depends on the noise of its input after the
+//! modulus switching. For the other nodes, this is the probability for their output to be
+//! decrypted incorrectly.
+//!
+//! With the `serde_serialize` feature, circuits can be read from configuration files, and the
+//! `npe-circuit` binary (built with the `circuit_cli` feature) analyzes circuits given as JSON
+//! files.
+
+use std::fmt;
+
+use concrete_commons::dispersion::{DispersionParameter, StandardDev, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::failure::*;
+use crate::operators::*;
+
+/// The LWE secret key a ciphertext of a circuit is encrypted under.
+#[cfg_attr(
+    feature = "serde_serialize",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitKey {
+    /// The LWE secret key of dimension `lwe_dimension`, which encrypts the inputs of the
+    /// bootstraps.
+    Small,
+    /// The GLWE secret key seen as an LWE secret key of dimension
+    /// `glwe_dimension * polynomial_size`, which encrypts the outputs of the bootstraps.
+    Large,
+}
+
+/// The parameters a circuit is evaluated with.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircuitParameters {
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    /// The noise of the encryptions under the small key, e.g. in the keyswitching key.
+    pub lwe_noise: StandardDev,
+    /// The noise of the encryptions under the GLWE secret key, e.g. in the bootstrapping key.
+    pub glwe_noise: StandardDev,
+    pub pbs_base_log: DecompositionBaseLog,
+    pub pbs_level: DecompositionLevelCount,
+    pub ks_base_log: DecompositionBaseLog,
+    pub ks_level: DecompositionLevelCount,
+    /// The modulus of the messages carried by the ciphertexts.
+    pub message_modulus: MessageModulus,
+    /// The number of bits of padding above the messages.
+    pub padding: PaddingBitCount,
+}
+
+impl CircuitParameters {
+    fn key_dimension(&self, key: CircuitKey) -> LweDimension {
+        match key {
+            CircuitKey::Small => self.lwe_dimension,
+            CircuitKey::Large => LweDimension(self.glwe_dimension.0 * self.polynomial_size.0),
+        }
+    }
+}
+
+/// An operation of a circuit. The inputs of an operation are given as indices of nodes of the
+/// circuit.
+#[cfg_attr(
+    feature = "serde_serialize",
+    derive(Serialize, Deserialize),
+    serde(tag = "operation", rename_all = "snake_case")
+)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum CircuitNode {
+    /// A fresh encryption of an input of the circuit.
+    Input { key: CircuitKey, noise: StandardDev },
+    /// The sum of several ciphertexts encrypted under the same key.
+    Addition { inputs: Vec<usize> },
+    /// The product of a ciphertext with an integer cleartext.
+    CleartextMultiplication { input: usize, cleartext: i64 },
+    /// A keyswitch of a ciphertext from the large key to the small key.
+    Keyswitch { input: usize },
+    /// A programmable bootstrap of a ciphertext from the small key to the large key.
+    Bootstrap { input: usize },
+}
+
+impl CircuitNode {
+    fn inputs(&self) -> &[usize] {
+        match self {
+            CircuitNode::Input { .. } => &[],
+            CircuitNode::Addition { inputs } => inputs.as_slice(),
+            CircuitNode::CleartextMultiplication { input, .. }
+            | CircuitNode::Keyswitch { input }
+            | CircuitNode::Bootstrap { input } => std::slice::from_ref(input),
+        }
+    }
+
+    fn operation_name(&self) -> &'static str {
+        match self {
+            CircuitNode::Input { .. } => "input",
+            CircuitNode::Addition { .. } => "addition",
+            CircuitNode::CleartextMultiplication { .. } => "cleartext_multiplication",
+            CircuitNode::Keyswitch { .. } => "keyswitch",
+            CircuitNode::Bootstrap { .. } => "bootstrap",
+        }
+    }
+}
+
+/// A circuit, given as a list of nodes in topological order.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Circuit {
+    pub parameters: CircuitParameters,
+    pub nodes: Vec<CircuitNode>,
+}
+
+/// An error occurring when a circuit is malformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitError {
+    /// The node uses a node which does not precede it.
+    UnknownInput { node: usize, input: usize },
+    /// The node is an addition without inputs.
+    EmptyAddition { node: usize },
+    /// The inputs of the node are not encrypted under the key expected by its operation.
+    KeyMismatch { node: usize },
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::UnknownInput { node, input } => write!(
+                f,
+                "The node {} uses the node {}, which does not precede it.",
+                node, input
+            ),
+            CircuitError::EmptyAddition { node } => {
+                write!(f, "The addition of node {} has no inputs.", node)
+            }
+            CircuitError::KeyMismatch { node } => write!(
+                f,
+                "The inputs of node {} are not encrypted under the expected key.",
+                node
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CircuitError {}
+
+/// The result of the analysis of a node of a circuit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NodeReport {
+    /// The name of the operation of the node.
+    pub operation: &'static str,
+    /// The key the output of the node is encrypted under.
+    pub key: CircuitKey,
+    /// The dispersion of the output of the node.
+    pub variance: Variance,
+    /// The base 2 logarithm of the probability of failure of the node.
+    pub log2_failure_probability: f64,
+}
+
+/// The result of the analysis of a circuit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitReport {
+    /// The reports of the nodes, in the order of the circuit.
+    pub nodes: Vec<NodeReport>,
+    /// The number of bootstraps of the circuit.
+    pub bootstrap_count: usize,
+    /// The number of keyswitches of the circuit.
+    pub keyswitch_count: usize,
+    // The indices of the nodes whose failure makes the circuit fail.
+    critical_nodes: Vec<usize>,
+}
+
+impl CircuitReport {
+    /// Returns the index of the node with the largest probability of failure.
+    pub fn worst_node(&self) -> Option<usize> {
+        (0..self.nodes.len()).max_by(|a, b| {
+            self.nodes[*a]
+                .log2_failure_probability
+                .partial_cmp(&self.nodes[*b].log2_failure_probability)
+                .unwrap()
+        })
+    }
+
+    /// Returns the index of the node with the largest output variance.
+    pub fn worst_variance_node(&self) -> Option<usize> {
+        (0..self.nodes.len()).max_by(|a, b| {
+            self.nodes[*a]
+                .variance
+                .0
+                .partial_cmp(&self.nodes[*b].variance.0)
+                .unwrap()
+        })
+    }
+
+    /// Returns the base 2 logarithm of the probability for the circuit to compute a wrong result,
+    /// i.e. for one of its bootstraps or of its outputs to fail (union bound).
+    pub fn log2_failure_probability(&self) -> f64 {
+        let max = self
+            .critical_nodes
+            .iter()
+            .map(|index| self.nodes[*index].log2_failure_probability)
+            .fold(f64::NEG_INFINITY, f64::max);
+        if max == f64::NEG_INFINITY {
+            return max;
+        }
+        // The probabilities are summed relatively to the largest one, to avoid underflows.
+        let sum: f64 = self
+            .critical_nodes
+            .iter()
+            .map(|index| 2_f64.powf(self.nodes[*index].log2_failure_probability - max))
+            .sum();
+        f64::min(0., max + sum.log2())
+    }
+}
+
+/// Analyzes the noise of a circuit evaluated on ciphertexts of type `T`.
+///
+/// The secret keys are binary. The output of a bootstrap does not depend on the accumulator,
+/// which is supposed to be trivially encrypted.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::StandardDev;
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+/// };
+/// use concrete_npe::{
+///     analyze_circuit, Circuit, CircuitKey, CircuitNode, CircuitParameters, MessageModulus,
+///     PaddingBitCount,
+/// };
+/// let parameters = CircuitParameters {
+///     lwe_dimension: LweDimension(630),
+///     glwe_dimension: GlweDimension(1),
+///     polynomial_size: PolynomialSize(1024),
+///     lwe_noise: StandardDev(2_f64.powi(-15)),
+///     glwe_noise: StandardDev(2_f64.powi(-25)),
+///     pbs_base_log: DecompositionBaseLog(7),
+///     pbs_level: DecompositionLevelCount(3),
+///     ks_base_log: DecompositionBaseLog(4),
+///     ks_level: DecompositionLevelCount(3),
+///     message_modulus: MessageModulus(4),
+///     padding: PaddingBitCount(1),
+/// };
+/// // Computes bootstrap(keyswitch(2 * (x + y))).
+/// let circuit = Circuit {
+///     parameters,
+///     nodes: vec![
+///         CircuitNode::Input {
+///             key: CircuitKey::Large,
+///             noise: StandardDev(2_f64.powi(-30)),
+///         },
+///         CircuitNode::Input {
+///             key: CircuitKey::Large,
+///             noise: StandardDev(2_f64.powi(-30)),
+///         },
+///         CircuitNode::Addition { inputs: vec![0, 1] },
+///         CircuitNode::CleartextMultiplication {
+///             input: 2,
+///             cleartext: 2,
+///         },
+///         CircuitNode::Keyswitch { input: 3 },
+///         CircuitNode::Bootstrap { input: 4 },
+///     ],
+/// };
+/// let report = analyze_circuit::<u64>(&circuit).unwrap();
+/// assert_eq!(report.bootstrap_count, 1);
+/// assert_eq!(report.keyswitch_count, 1);
+/// assert_eq!(report.nodes[5].key, CircuitKey::Large);
+/// // The keyswitch is the noisiest operation of this circuit.
+/// assert_eq!(report.worst_variance_node(), Some(4));
+/// ```
+pub fn analyze_circuit<T>(circuit: &Circuit) -> Result<CircuitReport, CircuitError>
+where
+    T: UnsignedInteger,
+{
+    let parameters = &circuit.parameters;
+    let log2_failure_probability = |variance: Variance| {
+        estimate_log2_failure_probability::<T, _>(
+            variance,
+            parameters.message_modulus,
+            parameters.padding,
+        )
+    };
+    let mut nodes: Vec<NodeReport> = Vec::with_capacity(circuit.nodes.len());
+    let mut is_used = vec![false; circuit.nodes.len()];
+    let (mut bootstrap_count, mut keyswitch_count) = (0, 0);
+
+    for (index, node) in circuit.nodes.iter().enumerate() {
+        for input in node.inputs().iter() {
+            if *input >= index {
+                return Err(CircuitError::UnknownInput {
+                    node: index,
+                    input: *input,
+                });
+            }
+            is_used[*input] = true;
+        }
+        let (key, variance, failure) = match node {
+            CircuitNode::Input { key, noise } => {
+                let variance = Variance(noise.get_variance());
+                (*key, variance, log2_failure_probability(variance))
+            }
+            CircuitNode::Addition { inputs } => {
+                let first = inputs
+                    .first()
+                    .ok_or(CircuitError::EmptyAddition { node: index })?;
+                let key = nodes[*first].key;
+                if inputs.iter().any(|input| nodes[*input].key != key) {
+                    return Err(CircuitError::KeyMismatch { node: index });
+                }
+                let variances: Vec<Variance> =
+                    inputs.iter().map(|input| nodes[*input].variance).collect();
+                let variance = estimate_several_additions_noise::<T, _>(variances.as_slice());
+                (key, variance, log2_failure_probability(variance))
+            }
+            CircuitNode::CleartextMultiplication { input, cleartext } => {
+                let input = &nodes[*input];
+                // The square of the cleartext is computed in floating point, as it may not fit in
+                // 64 bits.
+                let variance = Variance::from_variance(
+                    (*cleartext as f64).powi(2) * input.variance.get_variance(),
+                );
+                (input.key, variance, log2_failure_probability(variance))
+            }
+            CircuitNode::Keyswitch { input } => {
+                let input = &nodes[*input];
+                if input.key != CircuitKey::Large {
+                    return Err(CircuitError::KeyMismatch { node: index });
+                }
+                keyswitch_count += 1;
+                let variance = estimate_keyswitch_noise_lwe_to_lwe::<T, _, _, BinaryKeyKind>(
+                    parameters.key_dimension(CircuitKey::Large),
+                    input.variance,
+                    parameters.lwe_noise,
+                    parameters.ks_base_log,
+                    parameters.ks_level,
+                );
+                (
+                    CircuitKey::Small,
+                    variance,
+                    log2_failure_probability(variance),
+                )
+            }
+            CircuitNode::Bootstrap { input } => {
+                let input = &nodes[*input];
+                if input.key != CircuitKey::Small {
+                    return Err(CircuitError::KeyMismatch { node: index });
+                }
+                bootstrap_count += 1;
+                // The input is switched to the modulus 2N before the blind rotation.
+                let log2_modulus = parameters.polynomial_size.log2().0 + 1;
+                let switched_variance = estimate_modulus_switching_noise_with_binary_key::<T, _>(
+                    parameters.lwe_dimension,
+                    log2_modulus,
+                    input.variance,
+                );
+                let variance = estimate_pbs_noise::<T, _, BinaryKeyKind>(
+                    parameters.lwe_dimension,
+                    parameters.polynomial_size,
+                    parameters.glwe_dimension,
+                    parameters.pbs_base_log,
+                    parameters.pbs_level,
+                    parameters.glwe_noise,
+                );
+                (
+                    CircuitKey::Large,
+                    variance,
+                    log2_failure_probability(switched_variance),
+                )
+            }
+        };
+        nodes.push(NodeReport {
+            operation: node.operation_name(),
+            key,
+            variance,
+            log2_failure_probability: failure,
+        });
+    }
+
+    let critical_nodes = circuit
+        .nodes
+        .iter()
+        .enumerate()
+        .filter(|(index, node)| matches!(node, CircuitNode::Bootstrap { .. }) || !is_used[*index])
+        .map(|(index, _)| index)
+        .collect();
+    Ok(CircuitReport {
+        nodes,
+        bootstrap_count,
+        keyswitch_count,
+        critical_nodes,
+    })
+}
+
+#[cfg(test)]
+mod tests_analyze_circuit {
+    use super::*;
+    use crate::tools::tests::assert_float_eq;
+
+    fn parameters() -> CircuitParameters {
+        CircuitParameters {
+            lwe_dimension: LweDimension(630),
+            glwe_dimension: GlweDimension(1),
+            polynomial_size: PolynomialSize(1024),
+            lwe_noise: StandardDev(2_f64.powi(-15)),
+            glwe_noise: StandardDev(2_f64.powi(-25)),
+            pbs_base_log: DecompositionBaseLog(7),
+            pbs_level: DecompositionLevelCount(3),
+            ks_base_log: DecompositionBaseLog(4),
+            ks_level: DecompositionLevelCount(3),
+            message_modulus: MessageModulus(4),
+            padding: PaddingBitCount(1),
+        }
+    }
+
+    fn input(key: CircuitKey) -> CircuitNode {
+        CircuitNode::Input {
+            key,
+            noise: StandardDev(2_f64.powi(-20)),
+        }
+    }
+
+    #[test]
+    fn linear_operations_match_the_estimators() {
+        let circuit = Circuit {
+            parameters: parameters(),
+            nodes: vec![
+                input(CircuitKey::Small),
+                input(CircuitKey::Small),
+                CircuitNode::Addition {
+                    inputs: vec![0, 1, 1],
+                },
+                CircuitNode::CleartextMultiplication {
+                    input: 2,
+                    cleartext: -3,
+                },
+            ],
+        };
+        let report = analyze_circuit::<u64>(&circuit).unwrap();
+        assert_float_eq!(
+            report.nodes[2].variance.get_variance() / 2_f64.powi(-40),
+            3.,
+            eps = 1e-9
+        );
+        assert_float_eq!(
+            report.nodes[3].variance.get_variance() / 2_f64.powi(-40),
+            27.,
+            eps = 1e-9
+        );
+        assert_eq!(report.worst_variance_node(), Some(3));
+        // Only the last node is an output of the circuit.
+        assert_eq!(
+            report.log2_failure_probability(),
+            report.nodes[3].log2_failure_probability
+        );
+    }
+
+    #[test]
+    fn large_cleartexts_do_not_overflow() {
+        let circuit = Circuit {
+            parameters: parameters(),
+            nodes: vec![
+                input(CircuitKey::Small),
+                CircuitNode::CleartextMultiplication {
+                    input: 0,
+                    cleartext: -(1 << 40),
+                },
+            ],
+        };
+        let report = analyze_circuit::<u64>(&circuit).unwrap();
+        assert_float_eq!(
+            report.nodes[1].variance.get_variance() / 2_f64.powi(-40),
+            2_f64.powi(80),
+            eps = 1e-9
+        );
+    }
+
+    #[test]
+    fn bootstrap_resets_the_noise() {
+        let circuit = Circuit {
+            parameters: parameters(),
+            nodes: vec![
+                input(CircuitKey::Large),
+                CircuitNode::Keyswitch { input: 0 },
+                CircuitNode::Bootstrap { input: 1 },
+                CircuitNode::Keyswitch { input: 2 },
+                CircuitNode::Bootstrap { input: 3 },
+            ],
+        };
+        let report = analyze_circuit::<u64>(&circuit).unwrap();
+        assert_eq!(report.bootstrap_count, 2);
+        assert_eq!(report.keyswitch_count, 2);
+        assert_eq!(report.nodes[2].variance, report.nodes[4].variance);
+        assert_eq!(report.nodes[1].key, CircuitKey::Small);
+        assert_eq!(report.nodes[2].key, CircuitKey::Large);
+        // The failure of the bootstraps depends on the modulus switching, which adds noise.
+        assert!(
+            report.nodes[2].log2_failure_probability > report.nodes[1].log2_failure_probability
+        );
+        assert!(report.log2_failure_probability() >= report.nodes[2].log2_failure_probability);
+    }
+
+    #[test]
+    fn malformed_circuits_are_rejected() {
+        let check = |nodes: Vec<CircuitNode>, error: CircuitError| {
+            let circuit = Circuit {
+                parameters: parameters(),
+                nodes,
+            };
+            assert_eq!(analyze_circuit::<u64>(&circuit), Err(error));
+        };
+        check(
+            vec![CircuitNode::Keyswitch { input: 0 }],
+            CircuitError::UnknownInput { node: 0, input: 0 },
+        );
+        check(
+            vec![CircuitNode::Addition { inputs: vec![] }],
+            CircuitError::EmptyAddition { node: 0 },
+        );
+        check(
+            vec![
+                input(CircuitKey::Small),
+                input(CircuitKey::Large),
+                CircuitNode::Addition { inputs: vec![0, 1] },
+            ],
+            CircuitError::KeyMismatch { node: 2 },
+        );
+        check(
+            vec![
+                input(CircuitKey::Large),
+                CircuitNode::Bootstrap { input: 0 },
+            ],
+            CircuitError::KeyMismatch { node: 1 },
+        );
+    }
+
+    // The example of the documentation of the `npe-circuit` binary.
+    #[cfg(feature = "circuit_cli")]
+    #[test]
+    fn documentation_example_is_deserialized() {
+        let json = r#"{
+          "parameters": {
+            "lwe_dimension": 630, "glwe_dimension": 1, "polynomial_size": 1024,
+            "lwe_noise": 3.0517578125e-05, "glwe_noise": 2.9802322387695312e-08,
+            "pbs_base_log": 7, "pbs_level": 3, "ks_base_log": 4, "ks_level": 3,
+            "message_modulus": 4, "padding": 1
+          },
+          "nodes": [
+            { "operation": "input", "key": "large", "noise": 9.313225746154785e-10 },
+            { "operation": "cleartext_multiplication", "input": 0, "cleartext": 3 },
+            { "operation": "keyswitch", "input": 1 },
+            { "operation": "bootstrap", "input": 2 }
+          ]
+        }"#;
+        let circuit: Circuit = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            circuit.parameters,
+            CircuitParameters {
+                lwe_noise: StandardDev(2_f64.powi(-15)),
+                glwe_noise: StandardDev(2_f64.powi(-25)),
+                ..parameters()
+            }
+        );
+        match circuit.nodes[0] {
+            CircuitNode::Input { key, noise } => {
+                assert_eq!(key, CircuitKey::Large);
+                assert_float_eq!(noise.get_log_standard_dev(), -30., eps = 1e-9);
+            }
+            ref node => panic!("Unexpected first node {:?}", node),
+        }
+        assert_eq!(
+            circuit.nodes[1..],
+            [
+                CircuitNode::CleartextMultiplication {
+                    input: 0,
+                    cleartext: 3,
+                },
+                CircuitNode::Keyswitch { input: 1 },
+                CircuitNode::Bootstrap { input: 2 },
+            ]
+        );
+        let report = analyze_circuit::<u64>(&circuit).unwrap();
+        assert_eq!(report.bootstrap_count, 1);
+        assert_eq!(report.keyswitch_count, 1);
+    }
+}
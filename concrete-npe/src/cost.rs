@@ -0,0 +1,423 @@
+//! Contains material needed to estimate the cost of the homomorphic operators.
+//!
+//! The cost of an operator is given as a number of elementary operations, split between the
+//! operations performed inside the FFTs and the other ones, along with the number of FFTs and the
+//! number of bytes of keys and ciphertexts read and written. The costs are the ones of the
+//! FFT-based implementation of the core backend of `concrete-core`, in which a polynomial of size
+//! $N$ is represented in the Fourier domain by $N$ complex numbers.
+//!
+//! A [`CostModel`] converts a cost into an estimated run time. Its coefficients can be calibrated
+//! from timings measured on the local machine, e.g. with the `calibrate-cost-model` binary of
+//! `concrete-core-bench`.
+
+use std::mem::size_of;
+use std::ops::{Add, Mul};
+use std::time::Duration;
+
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+// The size of a complex number in the Fourier domain.
+const FOURIER_COEFFICIENT_SIZE: f64 = 16.;
+
+/// The estimated cost of an operator.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OperationCost {
+    /// The number of elementary operations performed outside the FFTs.
+    pub scalar_operations: f64,
+    /// The number of FFTs, forward and backward.
+    pub fft_count: f64,
+    /// The number of elementary operations performed inside the FFTs.
+    pub fft_operations: f64,
+    /// The number of bytes of keys and ciphertexts read and written.
+    pub memory_traffic: f64,
+}
+
+impl OperationCost {
+    /// Returns the total number of elementary operations.
+    pub fn total_operations(&self) -> f64 {
+        self.scalar_operations + self.fft_operations
+    }
+}
+
+impl Add for OperationCost {
+    type Output = OperationCost;
+
+    fn add(self, other: OperationCost) -> OperationCost {
+        OperationCost {
+            scalar_operations: self.scalar_operations + other.scalar_operations,
+            fft_count: self.fft_count + other.fft_count,
+            fft_operations: self.fft_operations + other.fft_operations,
+            memory_traffic: self.memory_traffic + other.memory_traffic,
+        }
+    }
+}
+
+impl Mul<f64> for OperationCost {
+    type Output = OperationCost;
+
+    fn mul(self, count: f64) -> OperationCost {
+        OperationCost {
+            scalar_operations: self.scalar_operations * count,
+            fft_count: self.fft_count * count,
+            fft_operations: self.fft_operations * count,
+            memory_traffic: self.memory_traffic * count,
+        }
+    }
+}
+
+/// Estimates the cost of a keyswitch of ciphertexts of type `T`: each level of the decomposition
+/// of each element of the input mask is multiplied with an LWE ciphertext of the key.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::parameters::{DecompositionLevelCount, LweDimension};
+/// use concrete_npe::estimate_keyswitch_cost;
+/// let cost = estimate_keyswitch_cost::<u64>(
+///     LweDimension(1024),
+///     LweDimension(630),
+///     DecompositionLevelCount(3),
+/// );
+/// assert_eq!(cost.scalar_operations, (1024 * 3 * 631) as f64);
+/// assert_eq!(cost.fft_count, 0.);
+/// ```
+pub fn estimate_keyswitch_cost<T>(
+    input_lwe_dimension: LweDimension,
+    output_lwe_dimension: LweDimension,
+    level: DecompositionLevelCount,
+) -> OperationCost
+where
+    T: UnsignedInteger,
+{
+    let key_ciphertexts = (input_lwe_dimension.0 * level.0) as f64;
+    let output_size = (output_lwe_dimension.0 + 1) as f64;
+    let input_size = (input_lwe_dimension.0 + 1) as f64;
+    OperationCost {
+        scalar_operations: key_ciphertexts * output_size,
+        fft_count: 0.,
+        fft_operations: 0.,
+        memory_traffic: (key_ciphertexts * output_size + input_size + output_size)
+            * size_of::<T>() as f64,
+    }
+}
+
+/// Estimates the cost of an external product between a GLWE ciphertext of type `T` and a GGSW
+/// ciphertext in the Fourier domain: the decomposition of the GLWE ciphertext is transformed,
+/// multiplied with the GGSW ciphertext, and the result is transformed back.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::parameters::{DecompositionLevelCount, GlweDimension, PolynomialSize};
+/// use concrete_npe::estimate_external_product_cost;
+/// let cost = estimate_external_product_cost::<u64>(
+///     GlweDimension(1),
+///     PolynomialSize(1024),
+///     DecompositionLevelCount(3),
+/// );
+/// // Six forward FFTs and two backward ones.
+/// assert_eq!(cost.fft_count, 8.);
+/// assert_eq!(cost.fft_operations, 8. * 1024. * 10.);
+/// ```
+pub fn estimate_external_product_cost<T>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    level: DecompositionLevelCount,
+) -> OperationCost
+where
+    T: UnsignedInteger,
+{
+    let glwe_size = (glwe_dimension.0 + 1) as f64;
+    let big_n = polynomial_size.0 as f64;
+    let level = level.0 as f64;
+    let fft_count = glwe_size * level + glwe_size;
+    OperationCost {
+        scalar_operations: glwe_size * glwe_size * level * big_n,
+        fft_count,
+        fft_operations: fft_count * big_n * big_n.log2(),
+        memory_traffic: glwe_size * glwe_size * level * big_n * FOURIER_COEFFICIENT_SIZE
+            + 2. * glwe_size * big_n * size_of::<T>() as f64,
+    }
+}
+
+/// Estimates the cost of a CMUX between two GLWE ciphertexts of type `T`, controlled by a GGSW
+/// ciphertext in the Fourier domain: the external product of the difference of the two GLWE
+/// ciphertexts is added to the first one.
+pub fn estimate_cmux_cost<T>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    level: DecompositionLevelCount,
+) -> OperationCost
+where
+    T: UnsignedInteger,
+{
+    let glwe_coefficients = ((glwe_dimension.0 + 1) * polynomial_size.0) as f64;
+    estimate_external_product_cost::<T>(glwe_dimension, polynomial_size, level)
+        + OperationCost {
+            scalar_operations: 2. * glwe_coefficients,
+            fft_count: 0.,
+            fft_operations: 0.,
+            memory_traffic: 4. * glwe_coefficients * size_of::<T>() as f64,
+        }
+}
+
+/// Estimates the cost of a programmable bootstrapping of ciphertexts of type `T` with a
+/// bootstrapping key in the Fourier domain: the blind rotation performs one CMUX per element of
+/// the input mask, and a sample is extracted from the accumulator.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::parameters::{
+///     DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+/// };
+/// use concrete_npe::{estimate_cmux_cost, estimate_pbs_cost};
+/// let (lwe_dimension, glwe_dimension, polynomial_size, level) = (
+///     LweDimension(630),
+///     GlweDimension(1),
+///     PolynomialSize(1024),
+///     DecompositionLevelCount(3),
+/// );
+/// let pbs = estimate_pbs_cost::<u64>(lwe_dimension, glwe_dimension, polynomial_size, level);
+/// let cmux = estimate_cmux_cost::<u64>(glwe_dimension, polynomial_size, level);
+/// assert_eq!(pbs.fft_count, 630. * cmux.fft_count);
+/// assert!(pbs.total_operations() > 630. * cmux.total_operations());
+/// ```
+pub fn estimate_pbs_cost<T>(
+    lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    level: DecompositionLevelCount,
+) -> OperationCost
+where
+    T: UnsignedInteger,
+{
+    let extracted_coefficients = (glwe_dimension.0 * polynomial_size.0 + 1) as f64;
+    estimate_cmux_cost::<T>(glwe_dimension, polynomial_size, level) * lwe_dimension.0 as f64
+        + OperationCost {
+            scalar_operations: extracted_coefficients,
+            fft_count: 0.,
+            fft_operations: 0.,
+            memory_traffic: 2. * extracted_coefficients * size_of::<T>() as f64,
+        }
+}
+
+/// A model of the run time of the operators on a given machine.
+///
+/// The run time of an operator is estimated as a weighted sum of its numbers of elementary
+/// operations performed outside and inside the FFTs. The memory traffic of the operators is
+/// proportional to their number of operations outside the FFTs, and is hence accounted for by
+/// the weight of these operations.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostModel {
+    /// The time of an elementary operation performed outside the FFTs, in seconds.
+    pub scalar_operation_time: f64,
+    /// The time of an elementary operation performed inside the FFTs, in seconds.
+    pub fft_operation_time: f64,
+}
+
+impl Default for CostModel {
+    /// Returns an uncalibrated model, in which every elementary operation takes a nanosecond.
+    fn default() -> Self {
+        CostModel {
+            scalar_operation_time: 1e-9,
+            fft_operation_time: 1e-9,
+        }
+    }
+}
+
+impl CostModel {
+    /// Estimates the run time of an operator.
+    pub fn estimate_time(&self, cost: &OperationCost) -> Duration {
+        Duration::from_secs_f64(
+            cost.scalar_operations * self.scalar_operation_time
+                + cost.fft_operations * self.fft_operation_time,
+        )
+    }
+
+    /// Calibrates a model from the measured run times of operators of known costs, by minimizing
+    /// the relative errors of the estimations in the least squares sense.
+    ///
+    /// The measurements with a null run time or a null cost carry no information, and are ignored.
+    /// Returns `None` if no other measurements are given.
+    ///
+    /// If the measurements do not allow to separate the two kinds of operations, e.g. if none of
+    /// the measured operators performs FFTs, both kinds of operations get the same time.
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_npe::{estimate_keyswitch_cost, estimate_pbs_cost, CostModel};
+    /// use std::time::Duration;
+    /// let keyswitch = estimate_keyswitch_cost::<u64>(
+    ///     LweDimension(1024),
+    ///     LweDimension(630),
+    ///     DecompositionLevelCount(3),
+    /// );
+    /// let pbs = estimate_pbs_cost::<u64>(
+    ///     LweDimension(630),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    ///     DecompositionLevelCount(3),
+    /// );
+    /// let model = CostModel::calibrate(&[
+    ///     (keyswitch, Duration::from_micros(800)),
+    ///     (pbs, Duration::from_millis(15)),
+    /// ])
+    /// .unwrap();
+    /// let error = model.estimate_time(&pbs).as_secs_f64() / 15e-3 - 1.;
+    /// assert!(error.abs() < 1e-6);
+    /// ```
+    pub fn calibrate(measurements: &[(OperationCost, Duration)]) -> Option<CostModel> {
+        // Every measurement is scaled by its run time, so that the estimation of the scaled
+        // measurement must be one.
+        let scaled: Vec<(f64, f64)> = measurements
+            .iter()
+            .filter(|(cost, time)| !time.is_zero() && cost.total_operations() > 0.)
+            .map(|(cost, time)| {
+                let time = time.as_secs_f64();
+                (cost.scalar_operations / time, cost.fft_operations / time)
+            })
+            .collect();
+        if scaled.is_empty() {
+            return None;
+        }
+        let (mut s11, mut s12, mut s22, mut b1, mut b2) = (0., 0., 0., 0., 0.);
+        for (x1, x2) in scaled.iter() {
+            s11 += x1 * x1;
+            s12 += x1 * x2;
+            s22 += x2 * x2;
+            b1 += x1;
+            b2 += x2;
+        }
+        let determinant = s11 * s22 - s12 * s12;
+        if determinant > 1e-9 * s11 * s22 {
+            let scalar_operation_time = (b1 * s22 - b2 * s12) / determinant;
+            let fft_operation_time = (b2 * s11 - b1 * s12) / determinant;
+            if scalar_operation_time > 0. && fft_operation_time > 0. {
+                return Some(CostModel {
+                    scalar_operation_time,
+                    fft_operation_time,
+                });
+            }
+        }
+        // Falls back to a single time for all the operations.
+        let (sum, sum_of_squares) = scaled
+            .iter()
+            .map(|(x1, x2)| x1 + x2)
+            .fold((0., 0.), |(sum, squares), x| (sum + x, squares + x * x));
+        let operation_time = sum / sum_of_squares;
+        Some(CostModel {
+            scalar_operation_time: operation_time,
+            fft_operation_time: operation_time,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests_cost {
+    use super::*;
+    use crate::tools::tests::assert_float_eq;
+
+    fn pbs(level: usize) -> OperationCost {
+        estimate_pbs_cost::<u64>(
+            LweDimension(630),
+            GlweDimension(1),
+            PolynomialSize(1024),
+            DecompositionLevelCount(level),
+        )
+    }
+
+    fn keyswitch(level: usize) -> OperationCost {
+        estimate_keyswitch_cost::<u64>(
+            LweDimension(1024),
+            LweDimension(630),
+            DecompositionLevelCount(level),
+        )
+    }
+
+    #[test]
+    fn costs_grow_with_the_levels() {
+        assert!(pbs(3).total_operations() < pbs(4).total_operations());
+        assert!(pbs(3).memory_traffic < pbs(4).memory_traffic);
+        assert!(keyswitch(3).memory_traffic < keyswitch(4).memory_traffic);
+        assert_eq!(keyswitch(3).fft_operations, 0.);
+    }
+
+    #[test]
+    fn calibration_recovers_the_model() {
+        let model = CostModel {
+            scalar_operation_time: 3e-10,
+            fft_operation_time: 7e-10,
+        };
+        let measurements: Vec<_> = [keyswitch(3), keyswitch(5), pbs(2), pbs(3)]
+            .iter()
+            .map(|cost| (*cost, model.estimate_time(cost)))
+            .collect();
+        let calibrated = CostModel::calibrate(&measurements).unwrap();
+        assert_float_eq!(
+            calibrated.scalar_operation_time / model.scalar_operation_time,
+            1.,
+            eps = 1e-6
+        );
+        assert_float_eq!(
+            calibrated.fft_operation_time / model.fft_operation_time,
+            1.,
+            eps = 1e-6
+        );
+    }
+
+    #[test]
+    fn calibration_without_fft() {
+        let measurements = [
+            (keyswitch(3), Duration::from_millis(1)),
+            (keyswitch(6), Duration::from_millis(2)),
+        ];
+        let calibrated = CostModel::calibrate(&measurements).unwrap();
+        assert_eq!(
+            calibrated.scalar_operation_time,
+            calibrated.fft_operation_time
+        );
+        assert_float_eq!(
+            calibrated.estimate_time(&keyswitch(3)).as_secs_f64() / 1e-3,
+            1.,
+            eps = 1e-6
+        );
+        assert_eq!(CostModel::calibrate(&[]), None);
+    }
+
+    #[test]
+    fn calibration_ignores_empty_measurements() {
+        let empty = OperationCost {
+            scalar_operations: 0.,
+            fft_count: 0.,
+            fft_operations: 0.,
+            memory_traffic: 0.,
+        };
+        assert_eq!(
+            CostModel::calibrate(&[(empty, Duration::from_millis(1))]),
+            None
+        );
+        assert_eq!(
+            CostModel::calibrate(&[(keyswitch(3), Duration::from_secs(0))]),
+            None
+        );
+        let calibrated = CostModel::calibrate(&[
+            (empty, Duration::from_millis(1)),
+            (keyswitch(3), Duration::from_secs(0)),
+            (keyswitch(3), Duration::from_millis(1)),
+        ])
+        .unwrap();
+        assert_float_eq!(
+            calibrated.estimate_time(&keyswitch(3)).as_secs_f64() / 1e-3,
+            1.,
+            eps = 1e-6
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! Contains material needed to relate the noise of a ciphertext to the probability of decrypting
+//! it incorrectly.
+//!
+//! A message modulo $p$ is encoded in the most significant bits of the plaintext, below $\pi$ bits
+//! of padding, i.e. it is scaled by $\Delta = q / (p \cdot 2^\pi)$. The decryption rounds the
+//! plaintext to the closest multiple of $\Delta$, and fails when the noise, which is assumed to be
+//! a centered normal distribution, is larger than $\Delta / 2$ in absolute value.
+
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::numeric::UnsignedInteger;
+
+use crate::tools::{erfc_inv, ln_erfc, square};
+#[cfg(feature = "serde_serialize")]
+use serde::{Deserialize, Serialize};
+
+/// The modulus of the encoded messages.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageModulus(pub usize);
+
+/// The number of bits of padding above the encoded messages.
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaddingBitCount(pub usize);
+
+// Returns the largest modular error for which the decryption succeeds, i.e. $\Delta / 2$.
+fn max_modular_error<T>(message_modulus: MessageModulus, padding: PaddingBitCount) -> f64
+where
+    T: UnsignedInteger,
+{
+    2_f64.powi(T::BITS as i32 - padding.0 as i32 - 1) / message_modulus.0 as f64
+}
+
+/// Computes the probability for a ciphertext with a given dispersion to be decrypted incorrectly.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_npe::{estimate_failure_probability, MessageModulus, PaddingBitCount};
+/// // The error is larger than Δ / 2 = 2^-4 with a standard deviation of 2^-6, i.e. 4 sigmas.
+/// let failure_probability = estimate_failure_probability::<u64, _>(
+///     Variance(2_f64.powi(-12)),
+///     MessageModulus(4),
+///     PaddingBitCount(1),
+/// );
+/// assert!((failure_probability - 6.334e-5).abs() < 1e-8);
+/// ```
+pub fn estimate_failure_probability<T, D>(
+    dispersion: D,
+    message_modulus: MessageModulus,
+    padding: PaddingBitCount,
+) -> f64
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+{
+    2_f64.powf(estimate_log2_failure_probability::<T, D>(
+        dispersion,
+        message_modulus,
+        padding,
+    ))
+}
+
+/// Computes the base 2 logarithm of the probability for a ciphertext with a given dispersion to
+/// be decrypted incorrectly. Contrarily to [`estimate_failure_probability`], the result is
+/// meaningful for probabilities too small to be represented by an `f64`.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_npe::{estimate_log2_failure_probability, MessageModulus, PaddingBitCount};
+/// let log2_failure_probability = estimate_log2_failure_probability::<u64, _>(
+///     Variance(2_f64.powi(-20)),
+///     MessageModulus(16),
+///     PaddingBitCount(1),
+/// );
+/// assert!(log2_failure_probability < -40.);
+/// ```
+pub fn estimate_log2_failure_probability<T, D>(
+    dispersion: D,
+    message_modulus: MessageModulus,
+    padding: PaddingBitCount,
+) -> f64
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+{
+    let max_error = max_modular_error::<T>(message_modulus, padding);
+    let x = max_error / (std::f64::consts::SQRT_2 * dispersion.get_modular_standard_dev::<T>());
+    ln_erfc(x) / std::f64::consts::LN_2
+}
+
+/// Computes the largest variance for which a ciphertext is decrypted incorrectly with a
+/// probability smaller than `failure_probability`.
+/// # Example
+/// ```rust
+/// use concrete_npe::{
+///     estimate_failure_probability, estimate_maximal_variance_for_failure_probability,
+///     MessageModulus, PaddingBitCount,
+/// };
+/// let (message_modulus, padding) = (MessageModulus(16), PaddingBitCount(1));
+/// let variance = estimate_maximal_variance_for_failure_probability::<u64>(
+///     2_f64.powi(-40),
+///     message_modulus,
+///     padding,
+/// );
+/// let failure_probability =
+///     estimate_failure_probability::<u64, _>(variance, message_modulus, padding);
+/// assert!((failure_probability.log2() + 40.).abs() < 1e-3);
+/// ```
+pub fn estimate_maximal_variance_for_failure_probability<T>(
+    failure_probability: f64,
+    message_modulus: MessageModulus,
+    padding: PaddingBitCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+{
+    let max_error = max_modular_error::<T>(message_modulus, padding);
+    Variance::from_modular_variance::<T>(square(
+        max_error / (std::f64::consts::SQRT_2 * erfc_inv(failure_probability)),
+    ))
+}
+
+/// Computes an upper bound on the probability for at least one of `operation_count` decryptions,
+/// each failing with probability `failure_probability`, to fail (union bound).
+/// # Example
+/// ```rust
+/// use concrete_npe::estimate_failure_probability_of_several_operations;
+/// let failure_probability =
+///     estimate_failure_probability_of_several_operations(2_f64.powi(-40), 1 << 20);
+/// assert_eq!(failure_probability, 2_f64.powi(-20));
+/// ```
+pub fn estimate_failure_probability_of_several_operations(
+    failure_probability: f64,
+    operation_count: usize,
+) -> f64 {
+    f64::min(1., failure_probability * operation_count as f64)
+}
+
+/// Computes the base 2 logarithm of the union bound of
+/// [`estimate_failure_probability_of_several_operations`], from the base 2 logarithm of the
+/// probability of failure of one decryption.
+/// # Example
+/// ```rust
+/// use concrete_npe::estimate_log2_failure_probability_of_several_operations;
+/// let log2_failure_probability =
+///     estimate_log2_failure_probability_of_several_operations(-2000., 1 << 20);
+/// assert_eq!(log2_failure_probability, -1980.);
+/// ```
+pub fn estimate_log2_failure_probability_of_several_operations(
+    log2_failure_probability: f64,
+    operation_count: usize,
+) -> f64 {
+    f64::min(
+        0.,
+        log2_failure_probability + (operation_count as f64).log2(),
+    )
+}
+
+#[cfg(test)]
+mod tests_estimate_failure_probability {
+    use super::*;
+    use crate::tools::tests::assert_float_eq;
+
+    #[test]
+    fn matches_the_normal_distribution() {
+        // The probabilities for a normal variable to be further than 1, 2 and 3 standard
+        // deviations from its mean.
+        for (sigmas, expected) in [
+            (1., 0.317_310_507_862_914_1),
+            (2., 0.045_500_263_896_358_4),
+            (3., 0.002_699_796_063_260_2),
+        ] {
+            let std = 2_f64.powi(-5) / sigmas;
+            let failure_probability = estimate_failure_probability::<u32, _>(
+                Variance(std * std),
+                MessageModulus(8),
+                PaddingBitCount(1),
+            );
+            assert_float_eq!(failure_probability / expected, 1., eps = 1e-6);
+        }
+    }
+
+    #[test]
+    fn does_not_underflow() {
+        let log2_failure_probability = estimate_log2_failure_probability::<u64, _>(
+            Variance(2_f64.powi(-60)),
+            MessageModulus(2),
+            PaddingBitCount(1),
+        );
+        assert!(log2_failure_probability.is_finite());
+        assert!(log2_failure_probability < -1e6);
+    }
+
+    #[test]
+    fn maximal_variance_is_the_inverse() {
+        for log2_failure_probability in [-10, -40, -128, -500] {
+            let (message_modulus, padding) = (MessageModulus(32), PaddingBitCount(2));
+            let variance = estimate_maximal_variance_for_failure_probability::<u64>(
+                2_f64.powi(log2_failure_probability),
+                message_modulus,
+                padding,
+            );
+            assert_float_eq!(
+                estimate_log2_failure_probability::<u64, _>(variance, message_modulus, padding),
+                log2_failure_probability as f64,
+                eps = 1e-3
+            );
+        }
+    }
+
+    #[test]
+    fn union_bound_saturates() {
+        assert_eq!(
+            estimate_failure_probability_of_several_operations(0.25, 8),
+            1.
+        );
+        assert_eq!(
+            estimate_log2_failure_probability_of_several_operations(-2., 8),
+            0.
+        );
+    }
+}
@@ -36,10 +36,22 @@
 
 #![allow(clippy::upper_case_acronyms)]
 
+mod circuit;
+mod cost;
+mod failure;
 mod key_dispersion;
 mod operators;
+mod optimizer;
+mod security;
+mod statistics;
 mod tools;
 
+pub use circuit::*;
+pub use cost::*;
+pub use failure::*;
 pub use key_dispersion::*;
 pub use operators::*;
+pub use optimizer::*;
+pub use security::*;
+pub use statistics::*;
 pub use tools::*;
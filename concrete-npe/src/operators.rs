@@ -3,10 +3,12 @@
 use concrete_commons::dispersion::{DispersionParameter, Variance};
 use concrete_commons::numeric::{CastInto, UnsignedInteger};
 use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount, LweDimension,
+    PolynomialSize,
 };
 
 use super::*;
+use concrete_commons::key_kinds::BinaryKeyKind;
 
 /// Computes the dispersion of an addition of two
 /// uncorrelated ciphertexts.
@@ -77,9 +79,9 @@ where
     T: UnsignedInteger,
     D: DispersionParameter,
 {
-    let sn = n.into_signed();
-    let product: f64 = (sn * sn).cast_into();
-    Variance::from_variance(variance.get_variance() * product)
+    // The square is computed in floating point, as it may not fit in `T::Signed`.
+    let sn: f64 = n.into_signed().cast_into();
+    Variance::from_variance(variance.get_variance() * sn * sn)
 }
 
 /// Computes the dispersion of a multisum between
@@ -416,14 +418,49 @@ pub fn estimate_modulus_switching_noise_with_binary_key<T, D>(
 where
     T: UnsignedInteger,
     D: DispersionParameter,
+{
+    estimate_modulus_switching_noise::<T, D, BinaryKeyKind>(lwe_mask_size, nb_msb, var_in)
+}
+
+/// Computes the dispersion of a modulus switching of an LWE encrypted with keys of kind `K`.
+///
+/// Each element of the ciphertext is rounded to the closest multiple of $q / w$, with $w$ the
+/// output modulus. The rounding errors of the mask are multiplied by the coefficients of the
+/// secret key.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::key_kinds::TernaryKeyKind;
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_npe::estimate_modulus_switching_noise;
+/// let lwe_mask_size = LweDimension(630);
+/// let number_of_most_significant_bit: usize = 4;
+/// let dispersion_input = Variance(2_f64.powi(-40));
+/// let var_out = estimate_modulus_switching_noise::<u64, _, TernaryKeyKind>(
+///     lwe_mask_size,
+///     number_of_most_significant_bit,
+///     dispersion_input,
+/// );
+/// ```
+pub fn estimate_modulus_switching_noise<T, D, K>(
+    lwe_mask_size: LweDimension,
+    nb_msb: usize,
+    var_in: D,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+    K: KeyDispersion,
 {
     let w = 2_f64.powi(nb_msb as i32);
     let n = lwe_mask_size.0 as f64;
     let q_square = 2_f64.powi((2 * T::BITS) as i32);
+    let var_rounding = (q_square / square(w) - 1.) / 12.;
+    let var_key = K::variance_key_coefficient::<T>().get_modular_variance::<T>();
     Variance::from_modular_variance::<T>(
-        var_in.get_modular_variance::<T>() + 1. / 12. * q_square / square(w) - 1. / 12.
-            + n / 24. * q_square / square(w)
-            + n / 48.,
+        var_in.get_modular_variance::<T>()
+            + var_rounding * (1. + n * (var_key + square(K::expectation_key_coefficient())))
+            + n / 4. * var_key,
     )
 }
 
@@ -478,8 +515,12 @@ where
         * (K::variance_key_coefficient::<T>().get_modular_variance::<T>()
             + square(K::expectation_key_coefficient()));
 
-    // res 3
-    let res_3 = n / 4. * K::variance_key_coefficient::<T>().get_modular_variance::<T>();
+    // res 3: the bias of the rounding, which does not exist when the decomposition is exact
+    let res_3 = if base_log.0 * level.0 >= T::BITS {
+        0.
+    } else {
+        n / 4. * K::variance_key_coefficient::<T>().get_modular_variance::<T>()
+    };
 
     // res 4
     let res_4 =
@@ -530,6 +571,142 @@ where
     Variance::from_modular_variance::<T>(res)
 }
 
+/// Computes the dispersion of an LWE after an LWE to LWE keyswitch.
+///
+/// The output noise is made of the input noise, of the error due to the rounding of the input
+/// mask to the closest value representable by the decomposition, which vanishes along with its
+/// bias when $\beta \cdot \ell$ matches the number of bits of `T`, and of the noise of the keyswitching key
+/// multiplied by the decomposition terms. An LWE to LWE keyswitch is an LWE to GLWE keyswitch
+/// with polynomials of size one, whose output only has a constant term: the formula is hence the
+/// one of [`estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms`], which this function
+/// delegates to. The key kind `K` is the one of the input secret key.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+/// };
+/// use concrete_npe::estimate_keyswitch_noise_lwe_to_lwe;
+/// let input_lwe_dimension = LweDimension(1024);
+/// let level = DecompositionLevelCount(5);
+/// let base_log = DecompositionBaseLog(3);
+/// let dispersion_lwe = Variance(2_f64.powi(-50));
+/// let dispersion_ksk = Variance(2_f64.powi(-34));
+/// let var_ks = estimate_keyswitch_noise_lwe_to_lwe::<u64, _, _, BinaryKeyKind>(
+///     input_lwe_dimension,
+///     dispersion_lwe,
+///     dispersion_ksk,
+///     base_log,
+///     level,
+/// );
+/// ```
+pub fn estimate_keyswitch_noise_lwe_to_lwe<T, D1, D2, K>(
+    input_lwe_dimension: LweDimension,
+    dispersion_lwe: D1,
+    dispersion_ksk: D2,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    K: KeyDispersion,
+{
+    estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<T, D1, D2, K>(
+        input_lwe_dimension,
+        dispersion_lwe,
+        dispersion_ksk,
+        base_log,
+        level,
+    )
+}
+
+/// Computes the dispersion of the coefficients of a GLWE encrypting messages, after the packing
+/// keyswitch of a vector of LWEs.
+///
+/// The $i$-th coefficient of the output is the constant term of the keyswitch of the $i$-th input,
+/// to which add up the non-constant terms of the keyswitches of the other inputs. The other
+/// coefficients of the output only contain the non-constant terms of the keyswitches of all the
+/// inputs. The key kind `K` is the one of the input secret key.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+/// };
+/// use concrete_npe::estimate_packing_keyswitch_noise;
+/// let input_lwe_dimension = LweDimension(630);
+/// let input_lwe_count = LweCiphertextCount(10);
+/// let level = DecompositionLevelCount(3);
+/// let base_log = DecompositionBaseLog(7);
+/// let dispersion_lwe = Variance(2_f64.powi(-30));
+/// let dispersion_pksk = Variance(2_f64.powi(-50));
+/// let var_pks = estimate_packing_keyswitch_noise::<u64, _, _, BinaryKeyKind>(
+///     input_lwe_dimension,
+///     input_lwe_count,
+///     dispersion_lwe,
+///     dispersion_pksk,
+///     base_log,
+///     level,
+/// );
+/// ```
+pub fn estimate_packing_keyswitch_noise<T, D1, D2, K>(
+    input_lwe_dimension: LweDimension,
+    input_lwe_count: LweCiphertextCount,
+    dispersion_lwe: D1,
+    dispersion_pksk: D2,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    K: KeyDispersion,
+{
+    let constant_terms = estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<T, D1, D2, K>(
+        input_lwe_dimension,
+        dispersion_lwe,
+        dispersion_pksk,
+        base_log,
+        level,
+    );
+    let non_constant_terms = estimate_keyswitch_noise_lwe_to_glwe_with_non_constant_terms::<T, D2>(
+        input_lwe_dimension,
+        dispersion_pksk,
+        base_log,
+        level,
+    );
+    Variance::from_modular_variance::<T>(
+        constant_terms.get_modular_variance::<T>()
+            + input_lwe_count.0.saturating_sub(1) as f64
+                * non_constant_terms.get_modular_variance::<T>(),
+    )
+}
+
+/// Computes the dispersion of an LWE after the extraction of a coefficient of a GLWE.
+///
+/// The sample extraction only rearranges the coefficients of the GLWE, so the output has the
+/// dispersion of the extracted coefficient.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::{DispersionParameter, Variance};
+/// use concrete_npe::estimate_sample_extraction_noise;
+/// let dispersion_glwe = Variance(2_f64.powi(-40));
+/// let var_out = estimate_sample_extraction_noise::<u64, _>(dispersion_glwe);
+/// assert_eq!(var_out.get_variance(), 2_f64.powi(-40));
+/// ```
+pub fn estimate_sample_extraction_noise<T, D>(dispersion_glwe: D) -> Variance
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+{
+    Variance::from_modular_variance::<T>(dispersion_glwe.get_modular_variance::<T>())
+}
+
 /// Computes the dispersion of the bits greater than $q$ after a modulus switching.
 /// # Example
 /// ```rust
@@ -593,6 +770,64 @@ pub fn estimate_external_product_noise_with_binary_ggsw<T, D1, D2, K>(
     base_log: DecompositionBaseLog,
     level: DecompositionLevelCount,
 ) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    K: KeyDispersion,
+{
+    estimate_external_product_noise::<T, D1, D2, K>(
+        poly_size,
+        rlwe_mask_size,
+        var_glwe,
+        var_ggsw,
+        base_log,
+        level,
+        BinaryKeyKind::expectation_key_coefficient_squared::<T>(),
+    )
+}
+
+/// Computes the dispersion of an external product between a GLWE and a GGSW encrypting a small
+/// integer $\mu$.
+///
+/// The noise of the GLWE and the errors of the decomposition are multiplied by $\mu$, so their
+/// contributions are scaled by `expectation_ggsw_message_squared`, the expectation of $\mu^2$: it
+/// is $1/2$ for a uniform bit, $2/3$ for a uniform ternary value, and $\mu^2$ for a known integer.
+/// The key kind `K` is the one of the GLWE secret key.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::key_kinds::TernaryKeyKind;
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+/// };
+/// use concrete_npe::estimate_external_product_noise;
+/// let poly_size = PolynomialSize(1024);
+/// let mask_size = GlweDimension(2);
+/// let level = DecompositionLevelCount(4);
+/// let dispersion_rlwe = Variance(2_f64.powi(-40));
+/// let dispersion_rgsw = Variance(2_f64.powi(-40));
+/// let base_log = DecompositionBaseLog(7);
+/// // The GGSW encrypts the integer 3.
+/// let var_ext = estimate_external_product_noise::<u64, _, _, TernaryKeyKind>(
+///     poly_size,
+///     mask_size,
+///     dispersion_rlwe,
+///     dispersion_rgsw,
+///     base_log,
+///     level,
+///     9.,
+/// );
+/// ```
+pub fn estimate_external_product_noise<T, D1, D2, K>(
+    poly_size: PolynomialSize,
+    rlwe_mask_size: GlweDimension,
+    var_glwe: D1,
+    var_ggsw: D2,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+    expectation_ggsw_message_squared: f64,
+) -> Variance
 where
     T: UnsignedInteger,
     D1: DispersionParameter,
@@ -605,17 +840,20 @@ where
     let b = 2_f64.powi(base_log.0 as i32);
     let b2l = 2_f64.powi((base_log.0 * 2 * level.0) as i32);
     let q_square = 2_f64.powi(2 * T::BITS as i32);
+    let mu_2 = expectation_ggsw_message_squared;
 
     let res_1 =
         l * (k + 1.) * big_n * var_ggsw.get_modular_variance::<T>() * (square(b) + 2.) / 12.;
-    let res_2 = var_glwe.get_modular_variance::<T>() / 2.;
-    let res_3 = (q_square - b2l) / (24. * b2l)
+    let res_2 = var_glwe.get_modular_variance::<T>() * mu_2;
+    let res_3 = (q_square - b2l) / (12. * b2l)
+        * mu_2
         * (1.
             + k * big_n
                 * (K::variance_key_coefficient::<T>().get_modular_variance::<T>()
                     + square(K::expectation_key_coefficient())));
-    let res_4 = k * big_n / 8. * K::variance_key_coefficient::<T>().get_modular_variance::<T>();
-    let res_5 = 1. / 16. * square(1. - k * big_n * K::expectation_key_coefficient());
+    let res_4 =
+        k * big_n / 4. * mu_2 * K::variance_key_coefficient::<T>().get_modular_variance::<T>();
+    let res_5 = mu_2 / 8. * square(1. - k * big_n * K::expectation_key_coefficient());
     Variance::from_modular_variance::<T>(res_1 + res_2 + res_3 + res_4 + res_5)
 }
 
@@ -662,13 +900,74 @@ where
     D3: DispersionParameter,
     K: KeyDispersion,
 {
-    let var_external_product = estimate_external_product_noise_with_binary_ggsw::<T, _, _, K>(
+    estimate_cmux_noise::<T, D1, D2, D3, K>(
+        dimension,
+        polynomial_size,
+        base_log,
+        l_gadget,
+        dispersion_rlwe_0,
+        dispersion_rlwe_1,
+        dispersion_rgsw,
+        BinaryKeyKind::expectation_key_coefficient_squared::<T>(),
+    )
+}
+
+/// Computes the dispersion of a CMUX $c_0 + \mu \boxdot (c_1 - c_0)$ controlled with a GGSW
+/// encrypting a small integer $\mu$, with `expectation_ggsw_message_squared` the expectation of
+/// $\mu^2$ (see [`estimate_external_product_noise`]).
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::key_kinds::TernaryKeyKind;
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+/// };
+/// use concrete_npe::estimate_cmux_noise;
+/// let dimension = GlweDimension(3);
+/// let l_gadget = DecompositionLevelCount(4);
+/// let base_log = DecompositionBaseLog(7);
+/// let polynomial_size = PolynomialSize(1024);
+/// let dispersion_rgsw = Variance::from_modular_variance::<u64>(2_f64.powi(26));
+/// let dispersion_rlwe_0 = Variance::from_modular_variance::<u64>(2_f64.powi(25));
+/// let dispersion_rlwe_1 = Variance::from_modular_variance::<u64>(2_f64.powi(25));
+/// // The GGSW encrypts a uniform ternary value.
+/// let var_cmux = estimate_cmux_noise::<u64, _, _, _, TernaryKeyKind>(
+///     dimension,
+///     polynomial_size,
+///     base_log,
+///     l_gadget,
+///     dispersion_rlwe_0,
+///     dispersion_rlwe_1,
+///     dispersion_rgsw,
+///     2. / 3.,
+/// );
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn estimate_cmux_noise<T, D1, D2, D3, K>(
+    dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    base_log: DecompositionBaseLog,
+    l_gadget: DecompositionLevelCount,
+    dispersion_rlwe_0: D1,
+    dispersion_rlwe_1: D2,
+    dispersion_rgsw: D3,
+    expectation_ggsw_message_squared: f64,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    D3: DispersionParameter,
+    K: KeyDispersion,
+{
+    let var_external_product = estimate_external_product_noise::<T, _, _, K>(
         polynomial_size,
         dimension,
         estimate_addition_noise::<T, _, _>(dispersion_rlwe_0, dispersion_rlwe_1),
         dispersion_rgsw,
         base_log,
         l_gadget,
+        expectation_ggsw_message_squared,
     );
     estimate_addition_noise::<T, _, _>(var_external_product, dispersion_rlwe_0)
 }
@@ -711,24 +1010,65 @@ where
     D: DispersionParameter,
     K: KeyDispersion,
 {
-    let n = lwe_mask_size.0 as f64;
-    let k = rlwe_mask_size.0 as f64;
-    let b = 2_f64.powi(base_log.0 as i32);
-    let b2l = 2_f64.powi((base_log.0 * 2 * level.0) as i32);
-    let l = level.0 as f64;
-    let big_n = poly_size.0 as f64;
-    let q_square = 2_f64.powi(2 * T::BITS as i32);
+    estimate_pbs_noise_with_key_kinds::<T, D, BinaryKeyKind, K>(
+        lwe_mask_size,
+        poly_size,
+        rlwe_mask_size,
+        base_log,
+        level,
+        dispersion_bsk,
+    )
+}
 
-    let res_1 = n * l * (k + 1.) * big_n * (square(b) + 2.) / 12.
-        * dispersion_bsk.get_modular_variance::<T>();
-    let res_2 = n * (q_square - b2l) / (24. * b2l)
-        * (1.
-            + k * big_n
-                * (K::variance_key_coefficient::<T>().get_modular_variance::<T>()
-                    + square(K::expectation_key_coefficient())))
-        + n * k * big_n / 8. * K::variance_key_coefficient::<T>().get_modular_variance::<T>()
-        + n / 16. * square(1. - k * big_n * K::expectation_key_coefficient());
-    Variance::from_modular_variance::<T>(res_1 + res_2)
+/// Computes the dispersion of a PBS whose bootstrapping key contains one GGSW per coefficient of
+/// an input LWE secret key of kind `KIn`, under a GLWE secret key of kind `K`. The initial noise
+/// for the RLWE is equal to zero.
+///
+/// Each step of the blind rotation is an external product with a GGSW encrypting a coefficient of
+/// the input key, whose square has the expectation given by `KIn`.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::key_kinds::TernaryKeyKind;
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+/// };
+/// use concrete_npe::estimate_pbs_noise_with_key_kinds;
+/// let var_pbs = estimate_pbs_noise_with_key_kinds::<u64, _, TernaryKeyKind, TernaryKeyKind>(
+///     LweDimension(630),
+///     PolynomialSize(1024),
+///     GlweDimension(2),
+///     DecompositionBaseLog(7),
+///     DecompositionLevelCount(4),
+///     Variance(2_f64.powi(-40)),
+/// );
+/// ```
+pub fn estimate_pbs_noise_with_key_kinds<T, D, KIn, K>(
+    lwe_mask_size: LweDimension,
+    poly_size: PolynomialSize,
+    rlwe_mask_size: GlweDimension,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+    dispersion_bsk: D,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+    KIn: KeyDispersion,
+    K: KeyDispersion,
+{
+    let var_external_product = estimate_external_product_noise::<T, _, _, K>(
+        poly_size,
+        rlwe_mask_size,
+        Variance(0.),
+        dispersion_bsk,
+        base_log,
+        level,
+        KIn::expectation_key_coefficient_squared::<T>(),
+    );
+    Variance::from_modular_variance::<T>(
+        lwe_mask_size.0 as f64 * var_external_product.get_modular_variance::<T>(),
+    )
 }
 
 #[cfg(test)]
@@ -765,3 +1105,183 @@ mod tests_estimate_weighted_sum_noise {
         assert_float_eq!(47.0, variance_out.get_variance(), eps = 0.001);
     }
 }
+
+#[cfg(test)]
+mod tests_estimate_keyswitch_noise {
+    use super::*;
+    use crate::tools::tests::assert_float_eq;
+    use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
+
+    #[test]
+    fn no_rounding_error_with_exact_decomposition() {
+        let dimension = LweDimension(512);
+        let ksk = Variance::from_modular_variance::<u32>(0.);
+        let var_out = estimate_keyswitch_noise_lwe_to_lwe::<u32, _, _, BinaryKeyKind>(
+            dimension,
+            Variance::from_modular_variance::<u32>(0.),
+            ksk,
+            DecompositionBaseLog(4),
+            DecompositionLevelCount(8),
+        );
+        assert_float_eq!(var_out.get_modular_variance::<u32>(), 0., eps = 1e-9);
+    }
+
+    #[test]
+    fn lwe_to_lwe_is_lwe_to_glwe_constant_terms() {
+        fn check<K: KeyDispersion>() {
+            let dimension = LweDimension(630);
+            let (lwe, ksk) = (Variance(2_f64.powi(-38)), Variance(2_f64.powi(-40)));
+            let (base_log, level) = (DecompositionBaseLog(4), DecompositionLevelCount(3));
+            let lwe_to_lwe = estimate_keyswitch_noise_lwe_to_lwe::<u64, _, _, K>(
+                dimension, lwe, ksk, base_log, level,
+            );
+            let constant_terms =
+                estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<u64, _, _, K>(
+                    dimension, lwe, ksk, base_log, level,
+                );
+            assert_eq!(lwe_to_lwe, constant_terms);
+        }
+        check::<BinaryKeyKind>();
+        check::<TernaryKeyKind>();
+        check::<GaussianKeyKind>();
+    }
+
+    #[test]
+    fn packing_accumulates_non_constant_terms() {
+        let dimension = LweDimension(512);
+        let (lwe, pksk) = (Variance(2_f64.powi(-30)), Variance(2_f64.powi(-40)));
+        let (base_log, level) = (DecompositionBaseLog(7), DecompositionLevelCount(3));
+        let var_pks = |count| {
+            estimate_packing_keyswitch_noise::<u64, _, _, BinaryKeyKind>(
+                dimension,
+                LweCiphertextCount(count),
+                lwe,
+                pksk,
+                base_log,
+                level,
+            )
+            .get_variance()
+        };
+        let constant = estimate_keyswitch_noise_lwe_to_lwe::<u64, _, _, BinaryKeyKind>(
+            dimension, lwe, pksk, base_log, level,
+        );
+        let non_constant = estimate_keyswitch_noise_lwe_to_glwe_with_non_constant_terms::<u64, _>(
+            dimension, pksk, base_log, level,
+        );
+        assert_float_eq!(var_pks(1), constant.get_variance(), eps = 1e-15);
+        assert_float_eq!(
+            var_pks(10),
+            constant.get_variance() + 9. * non_constant.get_variance(),
+            eps = 1e-15
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests_generic_key_kinds {
+    use super::*;
+    use crate::tools::tests::assert_float_eq;
+    use concrete_commons::key_kinds::{BinaryKeyKind, TernaryKeyKind};
+
+    // Checks the binary estimators against their values before the generalization.
+    #[test]
+    fn binary_regression() {
+        let relative_error = |given: Variance, expected: f64| (given.0 / expected - 1.).abs();
+        let ms = estimate_modulus_switching_noise_with_binary_key::<u64, _>(
+            LweDimension(630),
+            11,
+            Variance(2_f64.powi(-30)),
+        );
+        assert_float_eq!(relative_error(ms, 6.279287238915761e-6), 0., eps = 1e-12);
+        let (poly_size, glwe_dimension) = (PolynomialSize(1024), GlweDimension(2));
+        let (base_log, level) = (DecompositionBaseLog(7), DecompositionLevelCount(4));
+        let ep = estimate_external_product_noise_with_binary_ggsw::<u64, _, _, BinaryKeyKind>(
+            poly_size,
+            glwe_dimension,
+            Variance(2_f64.powi(-40)),
+            Variance(2_f64.powi(-50)),
+            base_log,
+            level,
+        );
+        assert_float_eq!(relative_error(ep, 1.4903435523299275e-8), 0., eps = 1e-12);
+        let cmux = estimate_cmux_noise_with_binary_ggsw::<u64, _, _, _, BinaryKeyKind>(
+            glwe_dimension,
+            poly_size,
+            base_log,
+            level,
+            Variance(2_f64.powi(-40)),
+            Variance(2_f64.powi(-41)),
+            Variance(2_f64.powi(-50)),
+        );
+        assert_float_eq!(relative_error(cmux, 1.4904572391676567e-8), 0., eps = 1e-12);
+        let pbs = estimate_pbs_noise::<u64, _, BinaryKeyKind>(
+            LweDimension(630),
+            poly_size,
+            glwe_dimension,
+            base_log,
+            level,
+            Variance(2_f64.powi(-50)),
+        );
+        assert_float_eq!(relative_error(pbs, 9.388877888847486e-6), 0., eps = 1e-12);
+    }
+
+    #[test]
+    fn ternary_keys_are_noisier() {
+        let var_in = Variance(2_f64.powi(-30));
+        let binary = estimate_modulus_switching_noise::<u64, _, BinaryKeyKind>(
+            LweDimension(630),
+            11,
+            var_in,
+        );
+        let ternary = estimate_modulus_switching_noise::<u64, _, TernaryKeyKind>(
+            LweDimension(630),
+            11,
+            var_in,
+        );
+        assert!(ternary.0 > binary.0);
+
+        let pbs = |input_key_is_ternary: bool| {
+            let args = (
+                LweDimension(630),
+                PolynomialSize(1024),
+                GlweDimension(1),
+                DecompositionBaseLog(15),
+                DecompositionLevelCount(2),
+                Variance(2_f64.powi(-50)),
+            );
+            if input_key_is_ternary {
+                estimate_pbs_noise_with_key_kinds::<u64, _, TernaryKeyKind, BinaryKeyKind>(
+                    args.0, args.1, args.2, args.3, args.4, args.5,
+                )
+            } else {
+                estimate_pbs_noise::<u64, _, BinaryKeyKind>(
+                    args.0, args.1, args.2, args.3, args.4, args.5,
+                )
+            }
+        };
+        assert!(pbs(true).0 > pbs(false).0);
+    }
+
+    #[test]
+    fn external_product_with_zero_keeps_the_ggsw_noise_only() {
+        let (poly_size, glwe_dimension) = (PolynomialSize(512), GlweDimension(1));
+        let (base_log, level) = (DecompositionBaseLog(10), DecompositionLevelCount(3));
+        let var_ggsw = Variance(2_f64.powi(-50));
+        let var = estimate_external_product_noise::<u64, _, _, TernaryKeyKind>(
+            poly_size,
+            glwe_dimension,
+            Variance(2_f64.powi(-20)),
+            var_ggsw,
+            base_log,
+            level,
+            0.,
+        );
+        let expected =
+            3. * 2. * 512. * var_ggsw.get_modular_variance::<u64>() * (2_f64.powi(20) + 2.) / 12.;
+        assert_float_eq!(
+            var.get_modular_variance::<u64>() / expected,
+            1.,
+            eps = 1e-12
+        );
+    }
+}
@@ -0,0 +1,382 @@
+//! Contains material needed to choose the parameters of the programmable bootstrapping.
+//!
+//! The optimizer considers the usual atomic pattern of TFHE: a ciphertext outputted by a PBS,
+//! encrypted under the GLWE secret key seen as an LWE secret key of dimension $kN$, is keyswitched
+//! to an LWE secret key of dimension $n$, and then bootstrapped again. The noise of the ciphertext
+//! entering the blind rotation, i.e. after the modulus switching, must be small enough for the
+//! message to be correctly decrypted with the targeted probability.
+use std::ops::RangeInclusive;
+
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+use crate::cost::*;
+use crate::failure::*;
+use crate::operators::*;
+use crate::security::{estimate_minimal_secure_noise, SecurityLevel};
+
+/// The constraints a set of parameters must satisfy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OptimizationTarget {
+    /// The number of bits of the message, not counting the padding bit.
+    pub precision: usize,
+    /// The maximal probability for a bootstrapped message to be decrypted incorrectly.
+    pub failure_probability: f64,
+    /// The security level of the secret keys.
+    pub security_level: SecurityLevel,
+}
+
+/// The ranges of parameters explored by the optimizer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchSpace {
+    /// The dimensions of the LWE secret key.
+    pub lwe_dimensions: RangeInclusive<usize>,
+    /// The dimensions of the GLWE secret key.
+    pub glwe_dimensions: RangeInclusive<usize>,
+    /// The base 2 logarithms of the polynomial sizes.
+    pub log2_polynomial_sizes: RangeInclusive<usize>,
+    /// The numbers of levels of the decomposition of the bootstrapping key.
+    pub pbs_level_counts: RangeInclusive<usize>,
+    /// The numbers of levels of the decomposition of the keyswitching key.
+    pub ks_level_counts: RangeInclusive<usize>,
+}
+
+impl Default for SearchSpace {
+    fn default() -> Self {
+        SearchSpace {
+            lwe_dimensions: 256..=1536,
+            glwe_dimensions: 1..=4,
+            log2_polynomial_sizes: 8..=14,
+            pbs_level_counts: 1..=10,
+            ks_level_counts: 1..=20,
+        }
+    }
+}
+
+/// A complete set of parameters for the keyswitch and the programmable bootstrapping.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParameterSet {
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    /// The noise of the encryptions under the LWE secret key, e.g. in the keyswitching key.
+    pub lwe_noise: Variance,
+    /// The noise of the encryptions under the GLWE secret key, e.g. in the bootstrapping key.
+    pub glwe_noise: Variance,
+    pub pbs_base_log: DecompositionBaseLog,
+    pub pbs_level: DecompositionLevelCount,
+    pub ks_base_log: DecompositionBaseLog,
+    pub ks_level: DecompositionLevelCount,
+    /// The noise of the ciphertexts entering the blind rotation.
+    pub variance: Variance,
+    /// The probability for a bootstrapped message to be decrypted incorrectly.
+    pub failure_probability: f64,
+    /// The number of elementary operations performed by one keyswitch followed by one PBS.
+    pub complexity: f64,
+}
+
+/// Searches for the parameters minimizing the complexity of a keyswitch followed by a
+/// programmable bootstrapping, among the parameters reaching the target. Returns `None` if no
+/// parameters of the search space reach the target.
+///
+/// The secret keys are binary, and their noises are the smallest secure ones, as given by
+/// [`estimate_minimal_secure_noise`]. The complexities of the operators are given by
+/// [`estimate_pbs_cost`] and [`estimate_keyswitch_cost`].
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::DispersionParameter;
+/// use concrete_npe::{optimize_parameters, OptimizationTarget, SearchSpace, SecurityLevel};
+/// let target = OptimizationTarget {
+///     precision: 2,
+///     failure_probability: 2_f64.powi(-40),
+///     security_level: SecurityLevel(128),
+/// };
+/// let parameters = optimize_parameters::<u64>(target, &SearchSpace::default()).unwrap();
+/// assert!(parameters.failure_probability <= target.failure_probability);
+/// println!("{:?}", parameters);
+/// ```
+pub fn optimize_parameters<T>(
+    target: OptimizationTarget,
+    search_space: &SearchSpace,
+) -> Option<ParameterSet>
+where
+    T: UnsignedInteger,
+{
+    // The messages are encoded with one bit of padding.
+    let (message_modulus, padding) = (MessageModulus(1 << target.precision), PaddingBitCount(1));
+    let max_variance = estimate_maximal_variance_for_failure_probability::<T>(
+        target.failure_probability,
+        message_modulus,
+        padding,
+    )
+    .get_variance();
+
+    let mut best: Option<ParameterSet> = None;
+    for glwe_dimension in search_space.glwe_dimensions.clone() {
+        for log2_polynomial_size in search_space.log2_polynomial_sizes.clone() {
+            let glwe_dimension = GlweDimension(glwe_dimension);
+            let polynomial_size = PolynomialSize(1 << log2_polynomial_size);
+            let glwe_key_dimension = LweDimension(glwe_dimension.0 * polynomial_size.0);
+            let glwe_noise =
+                estimate_minimal_secure_noise::<T>(glwe_key_dimension, target.security_level);
+
+            // The noise of the PBS grows linearly with the LWE dimension, so the best base for
+            // each number of levels does not depend on it.
+            let pbs_decompositions: Vec<_> = search_space
+                .pbs_level_counts
+                .clone()
+                .map(|level| {
+                    best_decomposition::<T, _>(level, |base_log, level| {
+                        estimate_pbs_noise::<T, _, BinaryKeyKind>(
+                            LweDimension(1),
+                            polynomial_size,
+                            glwe_dimension,
+                            base_log,
+                            level,
+                            glwe_noise,
+                        )
+                    })
+                })
+                .collect();
+            let cheapest_pbs_level =
+                DecompositionLevelCount(*search_space.pbs_level_counts.start());
+
+            for lwe_dimension in search_space.lwe_dimensions.clone() {
+                let lwe_dimension = LweDimension(lwe_dimension);
+                let lower_bound = estimate_pbs_cost::<T>(
+                    lwe_dimension,
+                    glwe_dimension,
+                    polynomial_size,
+                    cheapest_pbs_level,
+                )
+                .total_operations();
+                if matches!(best, Some(ref best) if best.complexity <= lower_bound) {
+                    // The complexity only grows with the LWE dimension.
+                    break;
+                }
+                let lwe_noise =
+                    estimate_minimal_secure_noise::<T>(lwe_dimension, target.security_level);
+                let ks_decompositions: Vec<_> = search_space
+                    .ks_level_counts
+                    .clone()
+                    .map(|level| {
+                        best_decomposition::<T, _>(level, |base_log, level| {
+                            estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+                                T,
+                                _,
+                                _,
+                                BinaryKeyKind,
+                            >(
+                                glwe_key_dimension, Variance(0.), lwe_noise, base_log, level
+                            )
+                        })
+                    })
+                    .collect();
+
+                for &(pbs_base_log, pbs_level, pbs_unit_variance) in pbs_decompositions.iter() {
+                    for &(ks_base_log, ks_level, ks_variance) in ks_decompositions.iter() {
+                        let complexity = (estimate_pbs_cost::<T>(
+                            lwe_dimension,
+                            glwe_dimension,
+                            polynomial_size,
+                            pbs_level,
+                        ) + estimate_keyswitch_cost::<T>(
+                            glwe_key_dimension,
+                            lwe_dimension,
+                            ks_level,
+                        ))
+                        .total_operations();
+                        if matches!(best, Some(ref best) if best.complexity <= complexity) {
+                            continue;
+                        }
+                        let variance = estimate_modulus_switching_noise_with_binary_key::<T, _>(
+                            lwe_dimension,
+                            log2_polynomial_size + 1,
+                            Variance(
+                                lwe_dimension.0 as f64 * pbs_unit_variance.get_variance()
+                                    + ks_variance.get_variance(),
+                            ),
+                        );
+                        if variance.get_variance() > max_variance {
+                            continue;
+                        }
+                        best = Some(ParameterSet {
+                            lwe_dimension,
+                            glwe_dimension,
+                            polynomial_size,
+                            lwe_noise,
+                            glwe_noise,
+                            pbs_base_log,
+                            pbs_level,
+                            ks_base_log,
+                            ks_level,
+                            variance,
+                            failure_probability: estimate_failure_probability::<T, _>(
+                                variance,
+                                message_modulus,
+                                padding,
+                            ),
+                            complexity,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+// Returns the base log minimizing the noise of a decomposition with a given number of levels,
+// along with the noise.
+fn best_decomposition<T, F>(
+    level: usize,
+    noise: F,
+) -> (DecompositionBaseLog, DecompositionLevelCount, Variance)
+where
+    T: UnsignedInteger,
+    F: Fn(DecompositionBaseLog, DecompositionLevelCount) -> Variance,
+{
+    let level = DecompositionLevelCount(level);
+    (1..=T::BITS / level.0)
+        .map(|base_log| {
+            let base_log = DecompositionBaseLog(base_log);
+            (base_log, level, noise(base_log, level))
+        })
+        .min_by(|a, b| a.2.get_variance().partial_cmp(&b.2.get_variance()).unwrap())
+        .expect("The number of levels is too large for the integer type.")
+}
+
+#[cfg(test)]
+mod tests_optimize_parameters {
+    use super::*;
+    use concrete_commons::parameter_sets::PARAMETER_SETS;
+
+    fn target(precision: usize, security_level: usize) -> OptimizationTarget {
+        OptimizationTarget {
+            precision,
+            failure_probability: 2_f64.powi(-40),
+            security_level: SecurityLevel(security_level),
+        }
+    }
+
+    #[test]
+    fn reaches_the_target() {
+        let target = target(3, 128);
+        let params = optimize_parameters::<u64>(target, &SearchSpace::default()).unwrap();
+        let glwe_key_dimension = LweDimension(params.glwe_dimension.0 * params.polynomial_size.0);
+        assert_eq!(
+            params.lwe_noise,
+            estimate_minimal_secure_noise::<u64>(params.lwe_dimension, target.security_level)
+        );
+        assert_eq!(
+            params.glwe_noise,
+            estimate_minimal_secure_noise::<u64>(glwe_key_dimension, target.security_level)
+        );
+
+        // Recomputes the noise of the pattern with the operators.
+        let pbs = estimate_pbs_noise::<u64, _, BinaryKeyKind>(
+            params.lwe_dimension,
+            params.polynomial_size,
+            params.glwe_dimension,
+            params.pbs_base_log,
+            params.pbs_level,
+            params.glwe_noise,
+        );
+        let ks = estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<u64, _, _, BinaryKeyKind>(
+            glwe_key_dimension,
+            pbs,
+            params.lwe_noise,
+            params.ks_base_log,
+            params.ks_level,
+        );
+        let variance = estimate_modulus_switching_noise_with_binary_key::<u64, _>(
+            params.lwe_dimension,
+            params.polynomial_size.log2().0 + 1,
+            ks,
+        );
+        assert!((variance.get_variance() / params.variance.get_variance() - 1.).abs() < 1e-9);
+        assert!(params.failure_probability <= target.failure_probability);
+    }
+
+    #[test]
+    fn complexity_grows_with_the_target() {
+        let search_space = SearchSpace::default();
+        let complexity = |precision, security_level| {
+            optimize_parameters::<u64>(target(precision, security_level), &search_space)
+                .unwrap()
+                .complexity
+        };
+        assert!(complexity(1, 128) <= complexity(4, 128));
+        assert!(complexity(4, 128) <= complexity(4, 192));
+    }
+
+    #[test]
+    fn registered_parameter_sets_are_optimal() {
+        let sets = PARAMETER_SETS
+            .iter()
+            .filter(|set| set.name.starts_with("MESSAGE_"));
+        for set in sets {
+            let registered = set.parameters;
+            let target = target(
+                registered.message_bits + registered.carry_bits,
+                registered.security_bits,
+            );
+            let params = optimize_parameters::<u64>(target, &SearchSpace::default()).unwrap();
+            assert!(params.failure_probability <= target.failure_probability);
+            assert_eq!(
+                params.lwe_dimension, registered.lwe_dimension,
+                "{}",
+                set.name
+            );
+            assert_eq!(
+                params.glwe_dimension, registered.glwe_dimension,
+                "{}",
+                set.name
+            );
+            assert_eq!(
+                params.polynomial_size, registered.polynomial_size,
+                "{}",
+                set.name
+            );
+            assert_eq!(params.pbs_base_log, registered.pbs_base_log, "{}", set.name);
+            assert_eq!(params.pbs_level, registered.pbs_level, "{}", set.name);
+            assert_eq!(params.ks_base_log, registered.ks_base_log, "{}", set.name);
+            assert_eq!(params.ks_level, registered.ks_level, "{}", set.name);
+            // The registered noises are rounded when written down as standard deviations.
+            let close = |a: f64, b: f64| (a / b - 1.).abs() < 1e-6;
+            assert!(
+                close(
+                    params.lwe_noise.get_standard_dev(),
+                    registered.lwe_noise.get_standard_dev()
+                ),
+                "{}",
+                set.name
+            );
+            assert!(
+                close(
+                    params.glwe_noise.get_standard_dev(),
+                    registered.glwe_noise.get_standard_dev()
+                ),
+                "{}",
+                set.name
+            );
+        }
+    }
+
+    #[test]
+    fn unreachable_target() {
+        let search_space = SearchSpace {
+            log2_polynomial_sizes: 8..=9,
+            ..SearchSpace::default()
+        };
+        assert_eq!(
+            optimize_parameters::<u64>(target(8, 128), &search_space),
+            None
+        );
+    }
+}
@@ -0,0 +1,514 @@
+//! Contains material needed to relate the parameters of an LWE instance to its security.
+//!
+//! The security of an instance is estimated locally, against the primal (uSVP) and dual lattice
+//! attacks, following the methodology of the [lattice
+//! estimator](https://github.com/malb/lattice-estimator): the cost of an attack is the cost of
+//! the BKZ reduction with the smallest block size making it succeed, in a given [`BkzCostModel`].
+//! A GLWE instance is estimated as the LWE instance of dimension $kN$.
+
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, StandardDev, Variance};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+
+use crate::key_dispersion::KeyDispersion;
+
+/// The targeted security of a set of parameters, in bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SecurityLevel(pub usize);
+
+// The fits of the minimal secure noise, of the form
+// $\log_2(\sigma) = slope \cdot n + bias$, with $\sigma$ the standard deviation over the torus and
+// $n$ the dimension of the binary secret key. They were obtained from the outputs of the lattice
+// estimator for a modulus $q = 2^{64}$, and are sorted by security level. The tests check that they
+// agree with `estimate_lwe_security` in the `Bdgl16` cost model.
+const SECURITY_CURVES: [(usize, f64, f64); 4] = [
+    (80, -0.040_492_955, 1.128_831_823),
+    (128, -0.026_361_288, 2.001_467_132),
+    (192, -0.018_460_514, 2.559_608_390),
+    (256, -0.014_209_501, 2.918_726_561),
+];
+
+/// Returns the variance of the smallest noise that can be used to encrypt with a binary secret
+/// key of a given dimension, while reaching the targeted security level.
+///
+/// The noise is interpolated from fits of the outputs of the lattice estimator, which are defined
+/// for security levels between 80 and 256 bits. The noise is never smaller than a modular standard
+/// deviation of $2^2$, which keeps the noise significant in the `T` representation.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::DispersionParameter;
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_npe::{estimate_minimal_secure_noise, SecurityLevel};
+/// let var = estimate_minimal_secure_noise::<u64>(LweDimension(630), SecurityLevel(128));
+/// assert!((var.get_log_standard_dev() + 14.6).abs() < 0.1);
+/// ```
+///
+/// # Panics
+/// Panics if the security level is not in the range of the fits.
+pub fn estimate_minimal_secure_noise<T>(
+    lwe_dimension: LweDimension,
+    security_level: SecurityLevel,
+) -> Variance
+where
+    T: UnsignedInteger,
+{
+    let SecurityLevel(level) = security_level;
+    let (first, last) = (
+        SECURITY_CURVES[0],
+        SECURITY_CURVES[SECURITY_CURVES.len() - 1],
+    );
+    assert!(
+        first.0 <= level && level <= last.0,
+        "The security level must be between {} and {} bits, got {}.",
+        first.0,
+        last.0,
+        level
+    );
+    let (slope, bias) = SECURITY_CURVES
+        .windows(2)
+        .find(|window| level <= window[1].0)
+        .map(|window| {
+            let ((low, low_slope, low_bias), (high, high_slope, high_bias)) =
+                (window[0], window[1]);
+            let t = (level - low) as f64 / (high - low) as f64;
+            (
+                low_slope + t * (high_slope - low_slope),
+                low_bias + t * (high_bias - low_bias),
+            )
+        })
+        .unwrap();
+    let log_std = f64::max(slope * lwe_dimension.0 as f64 + bias, 2. - T::BITS as f64);
+    Variance(StandardDev(2_f64.powf(log_std)).get_variance())
+}
+
+/// The cost model of the BKZ lattice reduction with block size $\beta$, on a lattice of dimension
+/// $d$.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BkzCostModel {
+    /// The classical core-SVP model, with a cost of $2^{0.292\beta}$.
+    ClassicalCoreSvp,
+    /// The quantum core-SVP model, with a cost of $2^{0.265\beta}$.
+    QuantumCoreSvp,
+    /// The classical sieve of Becker, Ducas, Gama and Laarhoven, called $8d$ times, with a cost of
+    /// $8d \cdot 2^{0.292\beta + 16.4}$. This is the default model of the lattice estimator.
+    Bdgl16,
+}
+
+impl BkzCostModel {
+    // Returns the base 2 logarithm of the cost of the reduction.
+    fn log2_cost(&self, block_size: f64, dimension: f64) -> f64 {
+        match self {
+            BkzCostModel::ClassicalCoreSvp => 0.292 * block_size,
+            BkzCostModel::QuantumCoreSvp => 0.265 * block_size,
+            BkzCostModel::Bdgl16 => 0.292 * block_size + 16.4 + f64::log2(8. * dimension),
+        }
+    }
+}
+
+/// The estimated costs of the lattice attacks against an LWE instance, in bits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SecurityEstimate {
+    /// The cost of the primal attack, solving the unique-SVP instance of Kannan's embedding.
+    pub primal: f64,
+    /// The cost of the dual attack, distinguishing the samples with short vectors of the dual
+    /// lattice.
+    pub dual: f64,
+}
+
+impl SecurityEstimate {
+    /// Returns the cost of the cheapest attack.
+    pub fn security(&self) -> f64 {
+        f64::min(self.primal, self.dual)
+    }
+}
+
+// The smallest block size considered: below it, the models of the reduction are not meaningful.
+const MIN_BLOCK_SIZE: usize = 40;
+
+// The number of samples available to the attacker, as a multiple of the dimension. It is larger
+// than the optimal number of samples of both attacks for the instances used in practice.
+const SAMPLES_PER_DIMENSION: usize = 3;
+
+// The base 2 logarithm of the number of short vectors outputted by a sieve with block size
+// $\beta$, which are reused by the dual attack.
+fn log2_sieve_vectors(block_size: f64) -> f64 {
+    0.2075 * block_size
+}
+
+// Returns the base 2 logarithm of the root Hermite factor reached by BKZ with block size $\beta$.
+fn log2_root_hermite_factor(block_size: f64) -> f64 {
+    let beta = block_size;
+    f64::log2(
+        (std::f64::consts::PI * beta).powf(1. / beta) * beta
+            / (2. * std::f64::consts::PI * std::f64::consts::E),
+    ) / (2. * (beta - 1.))
+}
+
+// An LWE instance, with the standard deviations of the secret and the error expressed in the
+// modular representation.
+struct LweInstance {
+    dimension: usize,
+    log2_modulus: f64,
+    log2_error_std: f64,
+    log2_secret_std: f64,
+}
+
+impl LweInstance {
+    fn max_samples(&self) -> usize {
+        SAMPLES_PER_DIMENSION * self.dimension
+    }
+
+    // Returns whether BKZ with a given block size recovers the error with the primal attack,
+    // using the 2016 estimate of Alkim, Ducas, Pöppelmann and Schwabe. The secret is rescaled to
+    // the size of the error. Returns the dimension of the best embedding lattice if it does.
+    fn primal_succeeds(&self, block_size: usize) -> Option<usize> {
+        let beta = block_size as f64;
+        let n = self.dimension as f64;
+        let log2_delta = log2_root_hermite_factor(beta);
+        let log2_scaling = self.log2_error_std - self.log2_secret_std;
+        let log2_error_norm = self.log2_error_std + 0.5 * beta.log2();
+        (0..=self.max_samples())
+            .map(|samples| {
+                let m = samples as f64;
+                let d = n + m + 1.;
+                let log2_projected_norm = (2. * beta - d - 1.) * log2_delta
+                    + (m * self.log2_modulus + n * log2_scaling) / d;
+                (
+                    log2_projected_norm - log2_error_norm,
+                    self.dimension + samples + 1,
+                )
+            })
+            .filter(|(margin, _)| *margin >= 0.)
+            .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap())
+            .map(|(_, dimension)| dimension)
+    }
+
+    fn primal_cost(&self, cost_model: BkzCostModel) -> f64 {
+        let max_block_size = self.dimension + self.max_samples() + 1;
+        let (mut low, mut high) = (MIN_BLOCK_SIZE, max_block_size);
+        if self.primal_succeeds(low).is_some() {
+            high = low;
+        }
+        // The success of the attack is monotonic in the block size.
+        while high - low > 1 {
+            let middle = (low + high) / 2;
+            if self.primal_succeeds(middle).is_some() {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+        let dimension = self.primal_succeeds(high).unwrap_or(max_block_size);
+        cost_model.log2_cost(high as f64, dimension as f64)
+    }
+
+    // The dual attack finds short vectors $(w, v)$ such that $w^T A = v \mod q$, with $v$
+    // rescaled to balance the contributions of the error and of the secret, and uses them to
+    // distinguish the samples from uniform. The sieve provides many short vectors at once, and the
+    // reduction is repeated when more are needed to reach a constant advantage.
+    fn dual_cost(&self, cost_model: BkzCostModel) -> f64 {
+        let n = self.dimension as f64;
+        let log2_volume = n * (self.log2_modulus + self.log2_secret_std - self.log2_error_std);
+        (MIN_BLOCK_SIZE..=self.dimension + self.max_samples())
+            .map(|block_size| {
+                let beta = block_size as f64;
+                let log2_delta = log2_root_hermite_factor(beta);
+                // The lattice dimension minimizing the norm of the short vectors.
+                let d = f64::sqrt(log2_volume / log2_delta)
+                    .max(n.max(beta))
+                    .min(n + self.max_samples() as f64)
+                    .round();
+                let log2_norm = d * log2_delta + log2_volume / d;
+                let log2_tau = log2_norm + self.log2_error_std - self.log2_modulus;
+                // The advantage is $\varepsilon = e^{-2 \pi^2 \tau^2}$.
+                let log2_inverse_square_advantage =
+                    4. * std::f64::consts::PI.powi(2) * 2_f64.powf(2. * log2_tau)
+                        / std::f64::consts::LN_2;
+                let log2_repetitions =
+                    f64::max(0., log2_inverse_square_advantage - log2_sieve_vectors(beta));
+                cost_model.log2_cost(beta, d) + log2_repetitions
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+/// Estimates the security of an LWE instance with a given dimension and noise, for a secret key of
+/// kind `K` and a modulus of $2^q$ with $q$ the number of bits of `T`.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::LogStandardDev;
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_npe::{estimate_lwe_security, BkzCostModel};
+/// let estimate = estimate_lwe_security::<u64, _, BinaryKeyKind>(
+///     LweDimension(630),
+///     LogStandardDev(-14.6),
+///     BkzCostModel::Bdgl16,
+/// );
+/// assert!(estimate.security() > 125.);
+/// ```
+pub fn estimate_lwe_security<T, D, K>(
+    lwe_dimension: LweDimension,
+    dispersion: D,
+    cost_model: BkzCostModel,
+) -> SecurityEstimate
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+    K: KeyDispersion,
+{
+    let secret_std = K::variance_key_coefficient::<T>().get_modular_standard_dev::<T>();
+    if secret_std == 0. || lwe_dimension.0 == 0 {
+        return SecurityEstimate {
+            primal: 0.,
+            dual: 0.,
+        };
+    }
+    let instance = LweInstance {
+        dimension: lwe_dimension.0,
+        log2_modulus: T::BITS as f64,
+        log2_error_std: dispersion.get_modular_log_standard_dev::<T>(),
+        log2_secret_std: secret_std.log2(),
+    };
+    SecurityEstimate {
+        primal: instance.primal_cost(cost_model),
+        dual: instance.dual_cost(cost_model),
+    }
+}
+
+/// Estimates the security of a GLWE instance with a given dimension, polynomial size and noise,
+/// for a secret key of kind `K` and a modulus of $2^q$ with $q$ the number of bits of `T`.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::LogStandardDev;
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+/// use concrete_npe::{estimate_glwe_security, BkzCostModel};
+/// let estimate = estimate_glwe_security::<u64, _, BinaryKeyKind>(
+///     GlweDimension(1),
+///     PolynomialSize(1024),
+///     LogStandardDev(-25.),
+///     BkzCostModel::Bdgl16,
+/// );
+/// assert!(estimate.security() > 125.);
+/// ```
+pub fn estimate_glwe_security<T, D, K>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    dispersion: D,
+    cost_model: BkzCostModel,
+) -> SecurityEstimate
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+    K: KeyDispersion,
+{
+    estimate_lwe_security::<T, D, K>(
+        LweDimension(glwe_dimension.0 * polynomial_size.0),
+        dispersion,
+        cost_model,
+    )
+}
+
+/// Returns the variance of the smallest noise for which an LWE instance with a given dimension
+/// reaches the targeted security level, as estimated by [`estimate_lwe_security`]. As for
+/// [`estimate_minimal_secure_noise`], the noise is never smaller than a modular standard deviation
+/// of $2^2$. Returns `None` if the targeted security can not be reached, even with a standard
+/// deviation of $2^0$.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::{DispersionParameter, LogStandardDev};
+/// use concrete_commons::key_kinds::TernaryKeyKind;
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_npe::{
+///     estimate_lwe_security, estimate_minimal_noise_for_security, BkzCostModel, SecurityLevel,
+/// };
+/// let var = estimate_minimal_noise_for_security::<u64, TernaryKeyKind>(
+///     LweDimension(1024),
+///     SecurityLevel(128),
+///     BkzCostModel::Bdgl16,
+/// )
+/// .unwrap();
+/// let estimate = estimate_lwe_security::<u64, _, TernaryKeyKind>(
+///     LweDimension(1024),
+///     LogStandardDev(var.get_log_standard_dev()),
+///     BkzCostModel::Bdgl16,
+/// );
+/// assert!(estimate.security() >= 128.);
+/// assert!(estimate_minimal_noise_for_security::<u64, TernaryKeyKind>(
+///     LweDimension(16),
+///     SecurityLevel(128),
+///     BkzCostModel::Bdgl16,
+/// )
+/// .is_none());
+/// ```
+pub fn estimate_minimal_noise_for_security<T, K>(
+    lwe_dimension: LweDimension,
+    security_level: SecurityLevel,
+    cost_model: BkzCostModel,
+) -> Option<Variance>
+where
+    T: UnsignedInteger,
+    K: KeyDispersion,
+{
+    let is_secure = |log_std: f64| {
+        estimate_lwe_security::<T, _, K>(lwe_dimension, LogStandardDev(log_std), cost_model)
+            .security()
+            >= security_level.0 as f64
+    };
+    let (mut low, mut high) = (2. - T::BITS as f64, 0.);
+    if !is_secure(high) {
+        return None;
+    }
+    if is_secure(low) {
+        high = low;
+    }
+    // The security grows with the noise, so the smallest noise is found by bisection.
+    while high - low > 1e-3 {
+        let middle = (low + high) / 2.;
+        if is_secure(middle) {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+    Some(Variance(LogStandardDev(high).get_variance()))
+}
+
+#[cfg(test)]
+mod tests_estimate_minimal_secure_noise {
+    use super::{estimate_minimal_secure_noise, SecurityLevel};
+    use crate::tools::tests::assert_float_eq;
+    use concrete_commons::dispersion::DispersionParameter;
+    use concrete_commons::parameters::LweDimension;
+
+    #[test]
+    fn decreases_with_dimension() {
+        for level in [80, 100, 128, 160, 192, 256] {
+            let small =
+                estimate_minimal_secure_noise::<u64>(LweDimension(500), SecurityLevel(level));
+            let large =
+                estimate_minimal_secure_noise::<u64>(LweDimension(900), SecurityLevel(level));
+            assert!(large.get_variance() < small.get_variance());
+        }
+    }
+
+    #[test]
+    fn increases_with_security() {
+        let mut previous = 0.;
+        for level in (80..=256).step_by(8) {
+            let var =
+                estimate_minimal_secure_noise::<u64>(LweDimension(1024), SecurityLevel(level));
+            assert!(var.get_variance() > previous);
+            previous = var.get_variance();
+        }
+    }
+
+    #[test]
+    fn matches_the_curves() {
+        let var = estimate_minimal_secure_noise::<u64>(LweDimension(1024), SecurityLevel(128));
+        assert_float_eq!(var.get_log_standard_dev(), -24.99, eps = 0.01);
+    }
+
+    #[test]
+    fn is_bounded_by_the_modulus() {
+        let var = estimate_minimal_secure_noise::<u32>(LweDimension(4096), SecurityLevel(128));
+        assert_float_eq!(var.get_modular_log_standard_dev::<u32>(), 2., eps = 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_unknown_security_level() {
+        estimate_minimal_secure_noise::<u64>(LweDimension(1024), SecurityLevel(300));
+    }
+}
+
+#[cfg(test)]
+mod tests_estimate_lwe_security {
+    use super::*;
+    use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
+
+    fn security<K: KeyDispersion>(dimension: usize, log_std: f64, model: BkzCostModel) -> f64 {
+        estimate_lwe_security::<u64, _, K>(LweDimension(dimension), LogStandardDev(log_std), model)
+            .security()
+    }
+
+    #[test]
+    fn grows_with_the_parameters() {
+        let model = BkzCostModel::ClassicalCoreSvp;
+        let reference = security::<BinaryKeyKind>(768, -18., model);
+        assert!(security::<BinaryKeyKind>(1024, -18., model) > reference);
+        assert!(security::<BinaryKeyKind>(768, -16., model) > reference);
+        assert!(security::<TernaryKeyKind>(768, -18., model) > reference);
+        assert!(
+            security::<GaussianKeyKind>(768, -18., model)
+                > security::<TernaryKeyKind>(768, -18., model)
+        );
+    }
+
+    #[test]
+    fn orders_the_cost_models() {
+        let quantum = security::<BinaryKeyKind>(768, -18., BkzCostModel::QuantumCoreSvp);
+        let classical = security::<BinaryKeyKind>(768, -18., BkzCostModel::ClassicalCoreSvp);
+        let bdgl = security::<BinaryKeyKind>(768, -18., BkzCostModel::Bdgl16);
+        assert!(quantum < classical && classical < bdgl);
+    }
+
+    #[test]
+    fn matches_the_curves() {
+        // The curves used by the optimizer must stay consistent with the estimator.
+        for (level, dimensions) in [(80, [512, 1024]), (128, [630, 2048]), (192, [1024, 2048])] {
+            for dimension in dimensions {
+                let log_std = estimate_minimal_secure_noise::<u64>(
+                    LweDimension(dimension),
+                    SecurityLevel(level),
+                )
+                .get_log_standard_dev();
+                let security = security::<BinaryKeyKind>(dimension, log_std, BkzCostModel::Bdgl16);
+                assert!(
+                    (security - level as f64).abs() < 5.,
+                    "n = {}: {} bits instead of {}",
+                    dimension,
+                    security,
+                    level
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn minimal_noise_reaches_the_security() {
+        let dimension = LweDimension(1024);
+        let model = BkzCostModel::ClassicalCoreSvp;
+        let var = estimate_minimal_noise_for_security::<u64, BinaryKeyKind>(
+            dimension,
+            SecurityLevel(128),
+            model,
+        )
+        .unwrap();
+        let security = |log_std| {
+            estimate_lwe_security::<u64, _, BinaryKeyKind>(
+                dimension,
+                LogStandardDev(log_std),
+                model,
+            )
+            .security()
+        };
+        assert!(security(var.get_log_standard_dev()) >= 128.);
+        assert!(security(var.get_log_standard_dev() - 0.01) < 128.);
+    }
+
+    #[test]
+    fn minimal_noise_is_none_when_unreachable() {
+        let var = estimate_minimal_noise_for_security::<u64, BinaryKeyKind>(
+            LweDimension(16),
+            SecurityLevel(128),
+            BkzCostModel::ClassicalCoreSvp,
+        );
+        assert_eq!(var, None);
+    }
+}
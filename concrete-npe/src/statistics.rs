@@ -0,0 +1,163 @@
+//! Contains material needed to compare measured noise samples with the estimations of this crate.
+//!
+//! The noise samples are expected to be torus values centered around zero, as returned by the
+//! noise measurement engines of `concrete-core`. The normality of the samples is assessed with the
+//! Jarque-Bera test, which only relies on the skewness and the kurtosis of the sample.
+
+use concrete_commons::dispersion::Variance;
+
+/// The statistics of a sample of noise values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SampleStatistics {
+    /// The number of values in the sample.
+    pub sample_count: usize,
+    /// The sample mean.
+    pub mean: f64,
+    /// The unbiased sample variance.
+    pub variance: Variance,
+    /// The sample skewness, which is zero for a normal distribution.
+    pub skewness: f64,
+    /// The sample excess kurtosis, which is zero for a normal distribution.
+    pub excess_kurtosis: f64,
+}
+
+impl SampleStatistics {
+    /// Computes the statistics of a sample of noise values.
+    ///
+    /// # Panics
+    /// Panics if the sample contains less than two values.
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete_commons::dispersion::DispersionParameter;
+    /// use concrete_npe::SampleStatistics;
+    /// let statistics = SampleStatistics::from_samples(&[-0.5, 0.5, -0.5, 0.5]);
+    /// assert_eq!(statistics.sample_count, 4);
+    /// assert_eq!(statistics.mean, 0.);
+    /// assert!((statistics.variance.get_variance() - 1. / 3.).abs() < 1e-15);
+    /// ```
+    pub fn from_samples(samples: &[f64]) -> SampleStatistics {
+        assert!(
+            samples.len() >= 2,
+            "At least two samples are needed to compute statistics."
+        );
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let central_moment = |k: i32| samples.iter().map(|x| (x - mean).powi(k)).sum::<f64>() / n;
+        let (m2, m3, m4) = (central_moment(2), central_moment(3), central_moment(4));
+        let (skewness, excess_kurtosis) = if m2 > 0. {
+            (m3 / m2.powf(1.5), m4 / (m2 * m2) - 3.)
+        } else {
+            (0., 0.)
+        };
+        SampleStatistics {
+            sample_count: samples.len(),
+            mean,
+            variance: Variance(m2 * n / (n - 1.)),
+            skewness,
+            excess_kurtosis,
+        }
+    }
+
+    /// Returns the Jarque-Bera statistic of the sample.
+    pub fn jarque_bera_statistic(&self) -> f64 {
+        self.sample_count as f64 / 6.
+            * (self.skewness * self.skewness + self.excess_kurtosis * self.excess_kurtosis / 4.)
+    }
+
+    /// Returns the p-value of the Jarque-Bera normality test.
+    ///
+    /// Under the hypothesis that the sample is drawn from a normal distribution, the Jarque-Bera
+    /// statistic asymptotically follows a chi-squared distribution with two degrees of freedom.
+    /// A small p-value hence indicates that the sample is unlikely to be normally distributed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete_npe::SampleStatistics;
+    /// // A sample which only takes two values is far from normal.
+    /// let samples: Vec<f64> = (0..1000).map(|i| if i % 2 == 0 { -1. } else { 1. }).collect();
+    /// let statistics = SampleStatistics::from_samples(&samples);
+    /// assert!(statistics.normality_p_value() < 1e-10);
+    /// ```
+    pub fn normality_p_value(&self) -> f64 {
+        (-self.jarque_bera_statistic() / 2.).exp()
+    }
+
+    /// Returns whether the sample passes the Jarque-Bera normality test at a given significance
+    /// level.
+    pub fn is_normal(&self, significance_level: f64) -> bool {
+        self.normality_p_value() >= significance_level
+    }
+}
+
+#[cfg(test)]
+mod tests_sample_statistics {
+    use super::*;
+    use crate::tools::tests::assert_float_eq;
+    use concrete_commons::dispersion::DispersionParameter;
+
+    // Returns the quantiles of a standard normal distribution at regularly spaced probabilities,
+    // which form a deterministic sample with the moments of the distribution. The sample is
+    // symmetric, the negative quantiles being mirrored to obtain the positive ones.
+    fn normal_quantiles(half_count: usize) -> Vec<f64> {
+        let negative: Vec<f64> = (1..=half_count)
+            .map(|i| {
+                let p = i as f64 / (2 * half_count + 1) as f64;
+                -std::f64::consts::SQRT_2 * crate::tools::erfc_inv(2. * p)
+            })
+            .collect();
+        negative
+            .iter()
+            .copied()
+            .chain(negative.iter().map(|x| -x))
+            .collect()
+    }
+
+    #[test]
+    fn known_moments() {
+        let statistics = SampleStatistics::from_samples(&[1., 2., 3., 4., 10.]);
+        assert_eq!(statistics.sample_count, 5);
+        assert_float_eq!(statistics.mean, 4., eps = 1e-12);
+        assert_float_eq!(statistics.variance.get_variance(), 12.5, eps = 1e-12);
+        assert_float_eq!(statistics.skewness, 1.138_419_957_660_616_7, eps = 1e-12);
+        assert_float_eq!(statistics.excess_kurtosis, -0.212, eps = 1e-12);
+    }
+
+    #[test]
+    fn constant_sample() {
+        let statistics = SampleStatistics::from_samples(&[0.25; 10]);
+        assert_float_eq!(statistics.mean, 0.25, eps = 1e-15);
+        assert_eq!(statistics.variance.get_variance(), 0.);
+        assert_eq!(statistics.jarque_bera_statistic(), 0.);
+    }
+
+    #[test]
+    fn normal_sample_passes_the_test() {
+        let samples: Vec<f64> = normal_quantiles(5_000)
+            .into_iter()
+            .map(|x| x * 2_f64.powi(-20))
+            .collect();
+        let statistics = SampleStatistics::from_samples(&samples);
+        assert_float_eq!(statistics.mean, 0., eps = 1e-15);
+        assert_float_eq!(
+            statistics.variance.get_variance() / 2_f64.powi(-40),
+            1.,
+            eps = 1e-2
+        );
+        assert!(statistics.is_normal(0.05));
+    }
+
+    #[test]
+    fn uniform_sample_fails_the_test() {
+        let samples: Vec<f64> = (0..10_000).map(|i| i as f64 / 10_000. - 0.5).collect();
+        let statistics = SampleStatistics::from_samples(&samples);
+        assert_float_eq!(statistics.excess_kurtosis, -1.2, eps = 1e-3);
+        assert!(!statistics.is_normal(0.05));
+    }
+
+    #[test]
+    #[should_panic]
+    fn single_sample_panics() {
+        SampleStatistics::from_samples(&[1.]);
+    }
+}
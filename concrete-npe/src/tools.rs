@@ -4,6 +4,9 @@ use std::ops::Mul;
 
 /// Computes the number of bits affected by the noise with a dispersion
 /// describing a normal distribution.
+///
+/// The noise is bounded by four standard deviations. To relate the noise to a precise error rate,
+/// see [`estimate_failure_probability`](crate::estimate_failure_probability).
 pub fn estimate_number_of_noise_bits<T, D>(dispersion: D) -> usize
 where
     D: DispersionParameter,
@@ -24,6 +27,42 @@ where
     }
 }
 
+/// Computes the natural logarithm of the complementary error function on $[0, +\infty[$, with a
+/// relative error on the complementary error function smaller than $1.2 \cdot 10^{-7}$ (Numerical
+/// Recipes, `erfcc`). Contrarily to the function itself, it does not underflow for large inputs.
+pub(crate) fn ln_erfc(x: f64) -> f64 {
+    debug_assert!(x >= 0.);
+    let t = 1. / (1. + 0.5 * x);
+    t.ln() - x * x - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))))
+}
+
+/// Computes the inverse of the complementary error function on $]0, 1]$, by bisection.
+pub(crate) fn erfc_inv(y: f64) -> f64 {
+    assert!(
+        0. < y && y <= 1.,
+        "erfc_inv is only defined on ]0, 1], got {}",
+        y
+    );
+    let ln_y = y.ln();
+    let (mut low, mut high) = (0_f64, 40_f64);
+    for _ in 0..100 {
+        let middle = (low + high) / 2.;
+        if ln_erfc(middle) > ln_y {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) / 2.
+}
+
 /// Computes the square of the input value.
 pub(super) fn square<T>(x: T) -> T
 where
@@ -47,4 +86,24 @@ pub mod tests {
         };
     }
     pub(crate) use assert_float_eq;
+
+    #[test]
+    fn erfc_known_values() {
+        use super::{erfc_inv, ln_erfc};
+        // Reference values computed with an arbitrary precision library.
+        for (x, expected) in [
+            (0., 1.),
+            (0.5, 0.479_500_122_186_953_5),
+            (1., 0.157_299_207_050_285_13),
+            (2., 0.004_677_734_981_047_265),
+            (5., 1.537_459_794_428_034_8e-12),
+        ] {
+            assert!((ln_erfc(x).exp() / expected - 1.).abs() < 1.2e-7);
+            if x > 0. {
+                assert_float_eq!(erfc_inv(expected), x, eps = 1e-5);
+            }
+        }
+        // The logarithm stays accurate where the function underflows.
+        assert_float_eq!(ln_erfc(30.), -903.974_117_110_644, eps = 1e-6);
+    }
 }
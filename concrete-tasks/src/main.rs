@@ -74,9 +74,21 @@ fn main() -> Result<(), std::io::Error> {
             App::new("test_commons").about("Tests the `concrete-commons` crate in native mode"),
         )
         .subcommand(App::new("test_core").about("Tests the `concrete-core` crate in native mode"))
+        .subcommand(
+            App::new("test_constant_time")
+                .about("Audits the secret-dependent routines for timing leakage in native mode"),
+        )
+        .subcommand(
+            App::new("test_noise_validation")
+                .about("Validates the `concrete-npe` noise formulas against the core backend"),
+        )
         .subcommand(
             App::new("test_csprng").about("Tests the `concrete-csprng` crate in native mode"),
         )
+        .subcommand(
+            App::new("test_csprng_big_endian")
+                .about("Tests the `concrete-csprng` crate on an emulated big-endian target"),
+        )
         .subcommand(App::new("test_npe").about("Tests the `concrete-npe` crate in native mode"))
         .subcommand(App::new("test_crates").about("Tests all the crates in native mode"))
         .subcommand(
@@ -145,9 +157,24 @@ fn main() -> Result<(), std::io::Error> {
     if matches.subcommand_matches("test_core").is_some() {
         test::core()?;
     }
+    if matches.subcommand_matches("test_constant_time").is_some() {
+        test::core_constant_time()?;
+    }
+    if matches
+        .subcommand_matches("test_noise_validation")
+        .is_some()
+    {
+        test::core_noise_validation()?;
+    }
     if matches.subcommand_matches("test_csprng").is_some() {
         test::csprng()?;
     }
+    if matches
+        .subcommand_matches("test_csprng_big_endian")
+        .is_some()
+    {
+        test::csprng_big_endian()?;
+    }
     if matches.subcommand_matches("test_npe").is_some() {
         test::npe()?;
     }
@@ -25,10 +25,22 @@ pub fn core_test() -> Result<(), Error> {
     cmd!(<ENV_TARGET_NATIVE> "cargo test --release --no-fail-fast --all-features -p concrete-core-test")
 }
 
+pub fn core_constant_time() -> Result<(), Error> {
+    cmd!(<ENV_TARGET_NATIVE> "cargo test --release --no-fail-fast --features=constant_time_audit -p concrete-core-test constant_time -- --ignored --test-threads=1 --nocapture")
+}
+
+pub fn core_noise_validation() -> Result<(), Error> {
+    cmd!(<ENV_TARGET_NATIVE> "cargo test --release --no-fail-fast --features=noise_validation -p concrete-core-test noise_validation -- --nocapture")
+}
+
 pub fn csprng() -> Result<(), Error> {
     cmd!(<ENV_TARGET_NATIVE> "cargo test --release --no-fail-fast --all-features -p concrete-csprng")
 }
 
+pub fn csprng_big_endian() -> Result<(), Error> {
+    cmd!("cross test --release --no-fail-fast --target=powerpc64-unknown-linux-gnu --features=generator_soft,generator_chacha,seeder_deterministic,seeder_read,parallel -p concrete-csprng")
+}
+
 pub fn npe() -> Result<(), Error> {
     cmd!(<ENV_TARGET_NATIVE> "cargo test --release --no-fail-fast --all-features -p concrete-npe")
 }